@@ -24,6 +24,11 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!   - `dutch collateral auction`: sell collateral assets at a price starts above the oracle price
+//!     and descends over blocks, any taker can buy part or all of the collateral instantly
+//!   - `surplus auction`: sell excessive surplus for getting native coin to burn
+//!   - `debit auction`: sell native coin for getting stable currency to eliminate the system's bad
+//!     debit
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,7 +39,7 @@ use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
-use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult};
+use orml_traits::{Auction, AuctionHandler, AuctionInfo, Change, MultiCurrency, OnNewBidResult};
 use orml_utilities::OffchainErr;
 use primitives::{AuctionId, Balance, CurrencyId};
 use scale_info::TypeInfo;
@@ -44,14 +49,17 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{CheckedDiv, One, Saturating, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, PriceProvider, Rate};
+use support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, CollateralFreeze, DEXManager, EmergencyShutdown, Price,
+	PriceProvider, Rate,
+};
 
 mod mock;
 mod tests;
@@ -124,6 +132,117 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of an dutch collateral auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct DutchCollateralAuctionItem<AccountId, BlockNumber> {
+	/// Refund recipient for may receive refund
+	refund_recipient: AccountId,
+	/// Collateral type for sale
+	currency_id: CurrencyId,
+	/// Initial collateral amount for sale
+	#[codec(compact)]
+	initial_amount: Balance,
+	/// Current collateral amount for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Remain target sales amount of this auction
+	/// if zero, dutch collateral auction will sell all the collateral,
+	/// otherwise, the remain collateral will be refunded once the target
+	/// is reached
+	#[codec(compact)]
+	target: Balance,
+	/// The price of collateral in stable currency when the price starts to
+	/// descend
+	start_price: Price,
+	/// The block number when the price starts to descend
+	start_time: BlockNumber,
+	/// The frozen blocks of the collateral type when the price starts to
+	/// descend, the price doesn't descend while the collateral is frozen
+	frozen_blocks: u32,
+}
+
+impl<AccountId, BlockNumber> DutchCollateralAuctionItem<AccountId, BlockNumber>
+where
+	BlockNumber: Saturating + UniqueSaturatedInto<u32> + Copy,
+{
+	/// Return the dutch collateral auction will sell all the collateral
+	fn always_forward(&self) -> bool {
+		self.target.is_zero()
+	}
+
+	/// Return the blocks the price has descended for at specific block,
+	/// excluding the blocks the collateral has been frozen for
+	fn elapsed(&self, now: BlockNumber, frozen_blocks: u32) -> u32 {
+		let elapsed: u32 = now.saturating_sub(self.start_time).unique_saturated_into();
+		elapsed.saturating_sub(frozen_blocks.saturating_sub(self.frozen_blocks))
+	}
+
+	/// Return the price of collateral in stable currency at specific block
+	fn current_price(&self, now: BlockNumber, frozen_blocks: u32, price_decay: Rate) -> Price {
+		let elapsed = self.elapsed(now, frozen_blocks);
+		Rate::one()
+			.saturating_sub(price_decay)
+			.saturating_pow(elapsed as usize)
+			.saturating_mul(self.start_price)
+	}
+
+	/// Convert to collateral auction without bid, which is used to cancel
+	/// dutch collateral auction after system shutdown
+	fn into_collateral_auction_item(self) -> CollateralAuctionItem<AccountId, BlockNumber> {
+		CollateralAuctionItem {
+			refund_recipient: self.refund_recipient,
+			currency_id: self.currency_id,
+			initial_amount: self.initial_amount,
+			amount: self.amount,
+			target: self.target,
+			start_time: self.start_time,
+		}
+	}
+}
+
+/// Information of an debit auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct DebitAuctionItem<BlockNumber> {
+	/// Initial amount of native currency for sale
+	#[codec(compact)]
+	initial_amount: Balance,
+	/// Current amount of native currency for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Fix amount of debit value(stable currency) which want to get by this
+	/// auction
+	#[codec(compact)]
+	fix: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
+impl<BlockNumber> DebitAuctionItem<BlockNumber> {
+	/// Return amount for sale at specific last bid price and new bid price
+	fn amount_for_sale(&self, last_bid_price: Balance, new_bid_price: Balance) -> Balance {
+		if new_bid_price > last_bid_price && new_bid_price > self.fix {
+			Rate::checked_from_rational(sp_std::cmp::max(last_bid_price, self.fix), new_bid_price)
+				.and_then(|n| n.checked_mul_int(self.amount))
+				.unwrap_or(self.amount)
+		} else {
+			self.amount
+		}
+	}
+}
+
+/// Information of an surplus auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SurplusAuctionItem<BlockNumber> {
+	/// Fixed amount of surplus(stable currency) for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -149,6 +268,11 @@ pub mod module {
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
 
+		/// The native currency id, which is burned by surplus auction and
+		/// minted by debit auction
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
 		/// Currency to transfer assets
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
@@ -174,12 +298,31 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The freeze state of collateral types, the auctions of frozen
+		/// collateral are paused.
+		type CollateralFreeze: CollateralFreeze<CurrencyId>;
+
 		/// The default parital path list for DEX to directly take auction,
 		/// Note: the path is parital, the whole swap path is collateral currency id concat
 		/// the partial path. And the list is sorted, DEX try to take auction by order.
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The premium on the oracle price as the start price of dutch
+		/// collateral auction
+		#[pallet::constant]
+		type DutchAuctionStartingPremium: Get<Rate>;
+
+		/// The price of dutch collateral auction descends by this rate every
+		/// block
+		#[pallet::constant]
+		type DutchAuctionPriceDecay: Get<Rate>;
+
+		/// When the price of dutch collateral auction has descended for this
+		/// duration, restart it with the latest oracle price on the next take
+		#[pallet::constant]
+		type DutchAuctionResetDuration: Get<Self::BlockNumber>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -198,6 +341,13 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// The price of dutch collateral auction is higher than the max price
+		/// of taker
+		PriceTooHigh,
+		/// System has already been shutdown
+		AlreadyShutdown,
+		/// The collateral is frozen
+		CollateralFrozen,
 	}
 
 	#[pallet::event]
@@ -211,9 +361,32 @@ pub mod module {
 		/// Collateral auction dealt. \[auction_id, collateral_type,
 		/// collateral_amount, winner, payment_amount\]
 		CollateralAuctionDealt(AuctionId, CurrencyId, Balance, T::AccountId, Balance),
+		/// Collateral auction of frozen collateral is extended with a new
+		/// auction id. \[auction_id, new_auction_id, end_time\]
+		CollateralAuctionExtended(AuctionId, AuctionId, T::BlockNumber),
 		/// Dex take collateral auction. \[auction_id, collateral_type,
 		/// collateral_amount, turnover\]
 		DEXTakeCollateralAuction(AuctionId, CurrencyId, Balance, Balance),
+		/// Dutch collateral auction created. \[auction_id, collateral_type,
+		/// collateral_amount, target_bid_price, start_price\]
+		NewDutchCollateralAuction(AuctionId, CurrencyId, Balance, Balance, Price),
+		/// The price of dutch collateral auction restarted.
+		/// \[auction_id, start_price\]
+		DutchCollateralAuctionReset(AuctionId, Price),
+		/// Dutch collateral auction taken. \[auction_id, collateral_type,
+		/// taker, collateral_amount, payment_amount\]
+		DutchCollateralAuctionTaken(AuctionId, CurrencyId, T::AccountId, Balance, Balance),
+		/// Surplus auction created. \[auction_id, fix_surplus_amount\]
+		NewSurplusAuction(AuctionId, Balance),
+		/// Debit auction created. \[auction_id, initial_supply_amount,
+		/// fix_payment_amount\]
+		NewDebitAuction(AuctionId, Balance, Balance),
+		/// Surplus auction dealt. \[auction_id, surplus_amount, winner,
+		/// payment_amount\]
+		SurplusAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
+		/// Debit auction dealt. \[auction_id, debit_currency_amount, winner,
+		/// payment_amount\]
+		DebitAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -239,6 +412,44 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to dutch collateral auction info
+	///
+	/// DutchCollateralAuctions: map AuctionId => Option<DutchCollateralAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_collateral_auctions)]
+	pub type DutchCollateralAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DutchCollateralAuctionItem<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from auction id to debit auction info
+	///
+	/// DebitAuctions: map AuctionId => Option<DebitAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auctions)]
+	pub type DebitAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DebitAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from auction id to surplus auction info
+	///
+	/// SurplusAuctions: map AuctionId => Option<SurplusAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auctions)]
+	pub type SurplusAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, SurplusAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Record of the total fix amount of all active debit auctions
+	///
+	/// TotalDebitInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_debit_in_auction)]
+	pub type TotalDebitInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Record of total surplus amount of all active surplus auctions
+	///
+	/// TotalSurplusInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_surplus_in_auction)]
+	pub type TotalSurplusInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -285,6 +496,26 @@ pub mod module {
 			Self::deposit_event(Event::CancelAuction(id));
 			Ok(())
 		}
+
+		/// Take the collateral of dutch collateral auction at current price
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `id`: the dutch collateral auction id.
+		/// - `max_amount`: the max amount of collateral to take.
+		/// - `max_price`: the max price of collateral in stable currency the taker accepts.
+		#[pallet::weight(T::WeightInfo::take_collateral())]
+		#[transactional]
+		pub fn take_collateral(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			#[pallet::compact] max_amount: Balance,
+			max_price: Price,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::take_dutch_collateral_auction(&who, id, max_amount, max_price)
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -303,7 +534,10 @@ pub mod module {
 							return InvalidTransaction::Stale.into();
 						}
 					}
-				} else {
+				} else if !DutchCollateralAuctions::<T>::contains_key(auction_id)
+					&& !DebitAuctions::<T>::contains_key(auction_id)
+					&& !SurplusAuctions::<T>::contains_key(auction_id)
+				{
 					return InvalidTransaction::Stale.into();
 				}
 
@@ -398,6 +632,18 @@ impl<T: Config> Pallet<T> {
 
 		if finished {
 			to_be_continue.clear();
+
+			// dutch collateral auctions, debit auctions and surplus auctions can always be
+			// cancelled, all of them should be cancelled
+			let remain_iterations = max_iterations.saturating_sub(iteration_count);
+			let remain_auction_ids = <DutchCollateralAuctions<T>>::iter()
+				.map(|(auction_id, _)| auction_id)
+				.chain(<DebitAuctions<T>>::iter().map(|(auction_id, _)| auction_id))
+				.chain(<SurplusAuctions<T>>::iter().map(|(auction_id, _)| auction_id));
+			for auction_id in remain_auction_ids.take(remain_iterations as usize) {
+				Self::submit_cancel_auction_tx(auction_id);
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
 		} else {
 			to_be_continue.set(&iterator.last_raw_key());
 		}
@@ -464,6 +710,38 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn cancel_surplus_auction(id: AuctionId, surplus_auction: SurplusAuctionItem<T::BlockNumber>) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, bid_price)) = Self::get_last_bid(id) {
+			// refund the burned native token to the bidder
+			T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, bid_price)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// decrease total surplus in auction
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+
+		Ok(())
+	}
+
+	fn cancel_debit_auction(id: AuctionId, debit_auction: DebitAuctionItem<T::BlockNumber>) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, _)) = Self::get_last_bid(id) {
+			// refund stable token to the bidder, the debit is issued back to CDP treasury
+			T::CDPTreasury::issue_debit(&bidder, debit_auction.fix, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		} else {
+			// decrease total debit in auction, it has been decreased when the first bid offsets the debit
+			TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+		}
+
+		Ok(())
+	}
+
 	/// Return `true` if price increment rate is greater than or equal to
 	/// minimum.
 	///
@@ -523,6 +801,10 @@ impl<T: Config> Pallet<T> {
 			id,
 			|collateral_auction| -> sp_std::result::Result<T::BlockNumber, DispatchError> {
 				let mut collateral_auction = collateral_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				ensure!(
+					!T::CollateralFreeze::is_frozen(collateral_auction.currency_id),
+					Error::<T>::CollateralFrozen
+				);
 				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
 
 				// ensure new bid price is valid
@@ -583,6 +865,232 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Handles debit auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn debit_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		<DebitAuctions<T>>::try_mutate_exists(
+			id,
+			|debit_auction| -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+				let mut debit_auction = debit_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+				// ensure new bid price is valid
+				ensure!(
+					new_bid_price >= debit_auction.fix
+						&& Self::check_minimum_increment(
+							new_bid_price,
+							last_bid_price,
+							debit_auction.fix,
+							Self::get_minimum_increment_size(now, debit_auction.start_time),
+						),
+					Error::<T>::InvalidBidPrice
+				);
+
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					// there's bid before, transfer the fix payment from new bidder to last bidder
+					T::Currency::transfer(
+						T::GetStableCurrencyId::get(),
+						&new_bidder,
+						last_bidder,
+						debit_auction.fix,
+					)?;
+				} else {
+					// there's no bid before, transfer the fix payment to CDP treasury
+					T::CDPTreasury::deposit_surplus(&new_bidder, debit_auction.fix)?;
+
+					// the fix payment offsets the debit of CDP treasury, it's no longer in auction
+					TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+				}
+
+				Self::swap_bidders(&new_bidder, last_bidder);
+
+				// the higher bid price, the less native token for sale
+				debit_auction.amount = debit_auction.amount_for_sale(last_bid_price, new_bid_price);
+
+				Ok(now + Self::get_auction_time_to_close(now, debit_auction.start_time))
+			},
+		)
+	}
+
+	/// Handles surplus auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn surplus_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		let surplus_auction = Self::surplus_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+		// ensure new bid price is valid
+		ensure!(
+			Self::check_minimum_increment(
+				new_bid_price,
+				last_bid_price,
+				Zero::zero(),
+				Self::get_minimum_increment_size(now, surplus_auction.start_time),
+			),
+			Error::<T>::InvalidBidPrice
+		);
+
+		let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+		let native_currency_id = T::GetNativeCurrencyId::get();
+
+		let burn_amount = if let Some(last_bidder) = last_bidder {
+			// there's bid before, refund the last bid price from new bidder to last bidder
+			T::Currency::transfer(native_currency_id, &new_bidder, last_bidder, last_bid_price)?;
+
+			new_bid_price.saturating_sub(last_bid_price)
+		} else {
+			new_bid_price
+		};
+
+		// burn remain native token from new bidder
+		T::Currency::withdraw(native_currency_id, &new_bidder, burn_amount)?;
+
+		Self::swap_bidders(&new_bidder, last_bidder);
+
+		Ok(now + Self::get_auction_time_to_close(now, surplus_auction.start_time))
+	}
+
+	/// Get the start price of dutch collateral auction, which is the oracle
+	/// price of collateral in stable currency with a premium.
+	fn get_dutch_auction_start_price(currency_id: CurrencyId) -> sp_std::result::Result<Price, DispatchError> {
+		let oracle_price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		Ok(oracle_price.saturating_mul(Rate::one().saturating_add(T::DutchAuctionStartingPremium::get())))
+	}
+
+	/// Take the collateral of dutch collateral auction at current price, the
+	/// taken amount is limited by the remain target.
+	fn take_dutch_collateral_auction(
+		who: &T::AccountId,
+		id: AuctionId,
+		max_amount: Balance,
+		max_price: Price,
+	) -> DispatchResult {
+		ensure!(!max_amount.is_zero(), Error::<T>::InvalidAmount);
+
+		<DutchCollateralAuctions<T>>::try_mutate_exists(id, |maybe_dutch_auction| -> DispatchResult {
+			let mut dutch_auction = maybe_dutch_auction.take().ok_or(Error::<T>::AuctionNotExists)?;
+			ensure!(
+				!T::CollateralFreeze::is_frozen(dutch_auction.currency_id),
+				Error::<T>::CollateralFrozen
+			);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let frozen_blocks = T::CollateralFreeze::frozen_blocks(dutch_auction.currency_id);
+
+			// restart the price if it has descended for too long
+			if dutch_auction.elapsed(now, frozen_blocks) >= T::DutchAuctionResetDuration::get().unique_saturated_into()
+			{
+				dutch_auction.start_price = Self::get_dutch_auction_start_price(dutch_auction.currency_id)?;
+				dutch_auction.start_time = now;
+				dutch_auction.frozen_blocks = frozen_blocks;
+				Self::deposit_event(Event::DutchCollateralAuctionReset(id, dutch_auction.start_price));
+			}
+
+			let price = dutch_auction.current_price(now, frozen_blocks, T::DutchAuctionPriceDecay::get());
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+			let always_forward = dutch_auction.always_forward();
+			let mut take_amount = sp_std::cmp::min(max_amount, dutch_auction.amount);
+			let mut payment = price.saturating_mul_int(take_amount);
+			if !always_forward && payment > dutch_auction.target {
+				// only take the collateral needed to reach the remain target
+				take_amount = price
+					.reciprocal()
+					.and_then(|n| n.checked_mul_int(dutch_auction.target))
+					.map_or(take_amount, |n| sp_std::cmp::min(n, take_amount));
+				payment = dutch_auction.target;
+			}
+			ensure!(!take_amount.is_zero() && !payment.is_zero(), Error::<T>::InvalidAmount);
+
+			T::CDPTreasury::deposit_surplus(who, payment)?;
+			T::CDPTreasury::withdraw_collateral(who, dutch_auction.currency_id, take_amount)?;
+
+			dutch_auction.amount = dutch_auction.amount.saturating_sub(take_amount);
+			TotalCollateralInAuction::<T>::mutate(dutch_auction.currency_id, |balance| {
+				*balance = balance.saturating_sub(take_amount)
+			});
+			if !always_forward {
+				dutch_auction.target = dutch_auction.target.saturating_sub(payment);
+				TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(payment));
+			}
+
+			Self::deposit_event(Event::DutchCollateralAuctionTaken(
+				id,
+				dutch_auction.currency_id,
+				who.clone(),
+				take_amount,
+				payment,
+			));
+
+			if dutch_auction.amount.is_zero() || (!always_forward && dutch_auction.target.is_zero()) {
+				// the target is reached, refund the remain collateral to refund recipient
+				T::CDPTreasury::withdraw_collateral(
+					&dutch_auction.refund_recipient,
+					dutch_auction.currency_id,
+					dutch_auction.amount,
+				)?;
+
+				frame_system::Pallet::<T>::dec_consumers(&dutch_auction.refund_recipient);
+				TotalCollateralInAuction::<T>::mutate(dutch_auction.currency_id, |balance| {
+					*balance = balance.saturating_sub(dutch_auction.amount)
+				});
+				TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(dutch_auction.target));
+				T::Auction::remove_auction(id);
+			} else {
+				*maybe_dutch_auction = Some(dutch_auction);
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Relist the collateral auction of frozen collateral as a new auction which
+	/// ends after `AuctionTimeToClose`, the last bid is kept.
+	fn extend_frozen_collateral_auction(
+		id: AuctionId,
+		collateral_auction: CollateralAuctionItem<T::AccountId, T::BlockNumber>,
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let end_time = now.saturating_add(T::AuctionTimeToClose::get());
+		let new_id = T::Auction::new_auction(now, Some(end_time))?;
+		T::Auction::update_auction(
+			new_id,
+			AuctionInfo {
+				bid: last_bid,
+				start: now,
+				end: Some(end_time),
+			},
+		)?;
+		<CollateralAuctions<T>>::insert(new_id, collateral_auction);
+
+		Self::deposit_event(Event::CollateralAuctionExtended(id, new_id, end_time));
+		Ok(())
+	}
+
 	fn collateral_auction_end_handler(
 		auction_id: AuctionId,
 		collateral_auction: CollateralAuctionItem<T::AccountId, T::BlockNumber>,
@@ -717,6 +1225,69 @@ impl<T: Config> Pallet<T> {
 		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
 	}
 
+	fn debit_auction_end_handler(
+		auction_id: AuctionId,
+		debit_auction: DebitAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, _)) = winner {
+			// issue native token to winner, it shouldn't fail and affect the process.
+			// but even it failed, just the winner did not get the amount. it can be fixed
+			// by treasury council.
+			let res = T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, debit_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"deposit: failed to issue native {:?} to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					debit_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::DebitAuctionDealt(
+				auction_id,
+				debit_auction.amount,
+				bidder,
+				debit_auction.fix,
+			));
+		} else {
+			// decrease total debit in auction, it has been decreased when the first bid offsets the debit
+			TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+		}
+	}
+
+	fn surplus_auction_end_handler(
+		auction_id: AuctionId,
+		surplus_auction: SurplusAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, bid_price)) = winner {
+			// transfer stable currency to winner from CDP treasury, it shouldn't fail and
+			// affect the process. but even it failed, just the winner did not get the
+			// amount. it can be fixed by treasury council.
+			let res = T::CDPTreasury::withdraw_surplus(&bidder, surplus_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"withdraw_surplus: failed to withdraw {:?} stable from CDP treasury to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					surplus_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::SurplusAuctionDealt(
+				auction_id,
+				surplus_auction.amount,
+				bidder,
+				bid_price,
+			));
+		}
+
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+	}
+
 	/// increment `new_bidder` reference and decrement `last_bidder`
 	/// reference if any
 	fn swap_bidders(new_bidder: &T::AccountId, last_bidder: Option<&T::AccountId>) {
@@ -745,7 +1316,13 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 		new_bid: (T::AccountId, Balance),
 		last_bid: Option<(T::AccountId, Balance)>,
 	) -> OnNewBidResult<T::BlockNumber> {
-		let bid_result = Self::collateral_auction_bid_handler(now, id, new_bid, last_bid);
+		let bid_result = if <DebitAuctions<T>>::contains_key(id) {
+			Self::debit_auction_bid_handler(now, id, new_bid, last_bid)
+		} else if <SurplusAuctions<T>>::contains_key(id) {
+			Self::surplus_auction_bid_handler(now, id, new_bid, last_bid)
+		} else {
+			Self::collateral_auction_bid_handler(now, id, new_bid, last_bid)
+		};
 
 		match bid_result {
 			Ok(new_auction_end_time) => OnNewBidResult {
@@ -761,7 +1338,26 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			if T::CollateralFreeze::is_frozen(collateral_auction.currency_id) {
+				// the collateral auction can not end while the collateral is frozen, extend it
+				// with the last bid kept and the winner keeps the account reference.
+				match Self::extend_frozen_collateral_auction(id, collateral_auction.clone(), winner.clone()) {
+					Ok(_) => return,
+					Err(e) => {
+						log::warn!(
+							target: "auction-manager",
+							"extend_frozen_collateral_auction: failed to extend collateral auction {:?}: {:?}, \
+							this is unexpected but the auction will end as usual",
+							id, e
+						);
+					}
+				}
+			}
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::debit_auction_end_handler(id, debit_auction, winner.clone());
+		} else if let Some(surplus_auction) = <SurplusAuctions<T>>::take(id) {
+			Self::surplus_auction_end_handler(id, surplus_auction, winner.clone());
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -783,6 +1379,10 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		target: Self::Balance,
 	) -> DispatchResult {
 		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(
+			!T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
 		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
 			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
 			Ok(())
@@ -830,9 +1430,127 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		refund_recipient: &T::AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(
+			!T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
+		let start_price = Self::get_dutch_auction_start_price(currency_id)?;
+		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+		TotalTargetInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(target).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		// dutch collateral auction doesn't accept bids, the auction is created to
+		// allocate the auction id and will be removed once it's finished.
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let auction_id = T::Auction::new_auction(start_time, None)?;
+
+		<DutchCollateralAuctions<T>>::insert(
+			auction_id,
+			DutchCollateralAuctionItem {
+				refund_recipient: refund_recipient.clone(),
+				currency_id,
+				initial_amount: amount,
+				amount,
+				target,
+				start_price,
+				start_time,
+				frozen_blocks: T::CollateralFreeze::frozen_blocks(currency_id),
+			},
+		);
+
+		// increment recipient account reference
+		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
+			// No providers for the locks. This is impossible under normal circumstances
+			// since the funds that are under the lock will themselves be stored in the
+			// account and therefore will need a reference.
+			log::warn!(
+				target: "auction-manager",
+				"Attempt to `inc_consumers` for {:?} failed. \
+				This is unexpected but should be safe.",
+				refund_recipient.clone()
+			);
+		}
+
+		Self::deposit_event(Event::NewDutchCollateralAuction(
+			auction_id,
+			currency_id,
+			amount,
+			target,
+			start_price,
+		));
+		Ok(())
+	}
+
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		let total_surplus_in_auction = Self::total_surplus_in_auction()
+			.checked_add(amount)
+			.ok_or(Error::<T>::InvalidAmount)?;
+
+		// surplus auction will not end until the first bid
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let auction_id = T::Auction::new_auction(start_time, None)?;
+
+		<SurplusAuctions<T>>::insert(auction_id, SurplusAuctionItem { amount, start_time });
+		TotalSurplusInAuction::<T>::put(total_surplus_in_auction);
+
+		Self::deposit_event(Event::NewSurplusAuction(auction_id, amount));
+		Ok(())
+	}
+
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult {
+		ensure!(
+			!initial_amount.is_zero() && !fix_debit.is_zero(),
+			Error::<T>::InvalidAmount,
+		);
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		let total_debit_in_auction = Self::total_debit_in_auction()
+			.checked_add(fix_debit)
+			.ok_or(Error::<T>::InvalidAmount)?;
+
+		// debit auction will not end until the first bid
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let auction_id = T::Auction::new_auction(start_time, None)?;
+
+		<DebitAuctions<T>>::insert(
+			auction_id,
+			DebitAuctionItem {
+				initial_amount,
+				amount: initial_amount,
+				fix: fix_debit,
+				start_time,
+			},
+		);
+		TotalDebitInAuction::<T>::put(total_debit_in_auction);
+
+		Self::deposit_event(Event::NewDebitAuction(auction_id, initial_amount, fix_debit));
+		Ok(())
+	}
+
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
-		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
-		Self::cancel_collateral_auction(id, collateral_auction)?;
+		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			Self::cancel_collateral_auction(id, collateral_auction)?;
+		} else if let Some(dutch_auction) = <DutchCollateralAuctions<T>>::take(id) {
+			Self::cancel_collateral_auction(id, dutch_auction.into_collateral_auction_item())?;
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::cancel_debit_auction(id, debit_auction)?;
+		} else {
+			let surplus_auction = <SurplusAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
+			Self::cancel_surplus_auction(id, surplus_auction)?;
+		}
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
@@ -844,4 +1562,12 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	fn get_total_target_in_auction() -> Self::Balance {
 		Self::total_target_in_auction()
 	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Self::total_surplus_in_auction()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Self::total_debit_in_auction()
+	}
 }
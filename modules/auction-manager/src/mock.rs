@@ -45,6 +45,7 @@ pub type Amount = i64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CAROL: AccountId = 3;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -115,6 +116,7 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub const MaxAuctionsCount: u32 = 10_000;
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account();
@@ -157,6 +159,14 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 4;
+	pub const DexUnsignedPriority: u64 = 1 << 20;
+	pub const SwapSplitParts: u32 = 10;
+	pub const MaxSearchedTradingPairs: u32 = 10;
+	pub const MaxSearchedTradingPaths: u32 = 10;
+	pub const LimitOrderDeposit: Balance = 100;
+	pub const MaxLimitOrdersPerAccount: u32 = 10;
+	pub const TwapWindow: BlockNumber = 10;
+	pub const TwapMaxAge: BlockNumber = 20;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(DOT, BTC).unwrap(),
@@ -169,21 +179,46 @@ impl module_dex::Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type UnsignedPriority = DexUnsignedPriority;
+	type ProtocolFeeReceiver = TreasuryAccount;
+	type Call = Call;
 }
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static FROZEN: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
+	static FROZEN_BLOCKS: RefCell<u32> = RefCell::new(0);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_freeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().push(currency_id))
+}
+
+pub fn mock_unfreeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().retain(|id| *id != currency_id))
+}
+
+pub fn mock_frozen_blocks(blocks: u32) {
+	FROZEN_BLOCKS.with(|v| *v.borrow_mut() = blocks)
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
@@ -191,6 +226,17 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockCollateralFreeze;
+impl CollateralFreeze<CurrencyId> for MockCollateralFreeze {
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		FROZEN.with(|v| v.borrow().contains(&currency_id))
+	}
+
+	fn frozen_blocks(_currency_id: CurrencyId) -> u32 {
+		FROZEN_BLOCKS.with(|v| *v.borrow())
+	}
+}
+
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(1, 20);
 	pub const AuctionTimeToClose: u64 = 100;
@@ -200,6 +246,9 @@ parameter_types! {
 		vec![AUSD],
 		vec![DOT, AUSD],
 	];
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(1, 5);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 10);
+	pub const DutchAuctionResetDuration: u64 = 10;
 }
 
 impl Config for Runtime {
@@ -210,12 +259,17 @@ impl Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type DEX = DEXModule;
 	type PriceSource = MockPriceSource;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type CollateralFreeze = MockCollateralFreeze;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type DutchAuctionResetDuration = DutchAuctionResetDuration;
 	type WeightInfo = ();
 }
 
@@ -264,6 +318,9 @@ impl Default for ExtBuilder {
 				(ALICE, DOT, 1000),
 				(BOB, DOT, 1000),
 				(CAROL, DOT, 1000),
+				(ALICE, ACA, 1000),
+				(BOB, ACA, 1000),
+				(CAROL, ACA, 1000),
 			],
 		}
 	}
@@ -172,6 +172,113 @@ fn bid_when_soft_cap_for_collateral_auction_work() {
 	});
 }
 
+#[test]
+fn auctions_of_frozen_collateral_paused() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 50, 60));
+
+		mock_freeze_collateral(BTC);
+		assert_noop!(
+			AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert_noop!(
+			AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 10, 100),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert_noop!(
+			AuctionManagerModule::collateral_auction_bid_handler(1, 0, (BOB, 5), None),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert!(!AuctionManagerModule::on_new_bid(1, 0, (BOB, 5), None).accept_bid);
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 1, 20, Price::saturating_from_integer(2)),
+			Error::<Runtime>::CollateralFrozen,
+		);
+
+		mock_unfreeze_collateral(BTC);
+		assert!(AuctionManagerModule::on_new_bid(1, 0, (BOB, 5), None).accept_bid);
+		assert_ok!(AuctionManagerModule::take_collateral(
+			Origin::signed(BOB),
+			1,
+			20,
+			Price::saturating_from_integer(2)
+		));
+	});
+}
+
+#[test]
+fn collateral_auction_of_frozen_collateral_extended_when_end() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 100));
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_freeze_collateral(BTC);
+		System::set_block_number(2);
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 100)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionExtended(
+			0, 1, 102,
+		)));
+		assert!(AuctionManagerModule::collateral_auctions(0).is_none());
+		assert_eq!(AuctionManagerModule::collateral_auctions(1).unwrap().amount, 100);
+		assert_eq!(
+			AuctionModule::auctions(1),
+			Some(orml_traits::AuctionInfo {
+				bid: Some((BOB, 100)),
+				start: 2,
+				end: Some(102)
+			})
+		);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0);
+
+		mock_unfreeze_collateral(BTC);
+		AuctionManagerModule::on_auction_ended(1, Some((BOB, 100)));
+		assert!(AuctionManagerModule::collateral_auctions(1).is_none());
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1100);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn dutch_collateral_auction_price_not_descend_while_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 60));
+		let dutch_auction = AuctionManagerModule::dutch_collateral_auctions(0).unwrap();
+		assert_eq!(
+			dutch_auction.current_price(3, 2, DutchAuctionPriceDecay::get()),
+			Price::saturating_from_rational(12, 10)
+		);
+		assert_eq!(
+			dutch_auction.current_price(4, 2, DutchAuctionPriceDecay::get()),
+			Price::saturating_from_rational(108, 100)
+		);
+
+		// BTC has been frozen for 2 blocks, the price descends for 1 block only
+		System::set_block_number(4);
+		mock_frozen_blocks(2);
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 0, 10, Price::one()),
+			Error::<Runtime>::PriceTooHigh,
+		);
+		assert_ok!(AuctionManagerModule::take_collateral(
+			Origin::signed(BOB),
+			0,
+			10,
+			Price::saturating_from_rational(108, 100)
+		));
+	});
+}
+
 #[test]
 fn collateral_auction_end_handler_without_bid() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -517,6 +624,34 @@ fn offchain_worker_cancels_auction_in_shutdown() {
 	});
 }
 
+#[test]
+fn offchain_worker_cancels_dutch_auction_in_shutdown() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 10, 100));
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_some());
+		mock_shutdown();
+
+		run_to_block_offchain(2);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		if let MockCall::AuctionManagerModule(crate::Call::cancel { id: auction_id }) = tx.call {
+			assert_ok!(AuctionManagerModule::cancel(Origin::none(), auction_id));
+		}
+
+		// dutch auction is canceled
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+		assert!(pool_state.write().transactions.pop().is_none());
+	});
+}
+
 #[test]
 fn offchain_worker_max_iterations_check() {
 	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -594,3 +729,384 @@ fn offchain_default_max_iterator_works() {
 		assert_eq!(pool_state.write().transactions.len(), 1001);
 	});
 }
+
+#[test]
+fn new_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let ref_count_0 = System::consumers(&ALICE);
+		assert_noop!(
+			AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 0, 60),
+			Error::<Runtime>::InvalidAmount,
+		);
+		MockPriceSource::set_relative_price(None);
+		assert_noop!(
+			AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 60),
+			Error::<Runtime>::InvalidFeedPrice,
+		);
+		MockPriceSource::set_relative_price(Some(Price::one()));
+
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 60));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewDutchCollateralAuction(
+			0,
+			BTC,
+			100,
+			60,
+			Price::saturating_from_rational(6, 5),
+		)));
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: None
+			})
+		);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 60);
+		assert_eq!(System::consumers(&ALICE), ref_count_0 + 1);
+
+		let dutch_auction = AuctionManagerModule::dutch_collateral_auctions(0).unwrap();
+		assert!(!dutch_auction.always_forward());
+		assert_eq!(
+			dutch_auction.current_price(1, 0, DutchAuctionPriceDecay::get()),
+			Price::saturating_from_rational(12, 10)
+		);
+		assert_eq!(
+			dutch_auction.current_price(2, 0, DutchAuctionPriceDecay::get()),
+			Price::saturating_from_rational(108, 100)
+		);
+		assert_eq!(
+			dutch_auction.current_price(3, 0, DutchAuctionPriceDecay::get()),
+			Price::saturating_from_rational(972, 1000)
+		);
+
+		// dutch collateral auction doesn't accept bids
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(BOB), 0, 100),
+			orml_auction::Error::<Runtime>::BidNotAccepted,
+		);
+	});
+}
+
+#[test]
+fn take_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 60));
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 1, 20, Price::one()),
+			Error::<Runtime>::AuctionNotExists,
+		);
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 0, 0, Price::one()),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		System::set_block_number(2);
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 0, 20, Price::one()),
+			Error::<Runtime>::PriceTooHigh,
+		);
+		assert_ok!(AuctionManagerModule::take_collateral(
+			Origin::signed(BOB),
+			0,
+			20,
+			Price::saturating_from_rational(11, 10)
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionTaken(
+			0, BTC, BOB, 20, 21,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 979);
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1020);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 21);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 80);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 80);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 39);
+
+		// only take the collateral needed to reach the remain target
+		System::set_block_number(3);
+		assert_ok!(AuctionManagerModule::take_collateral(
+			Origin::signed(CAROL),
+			0,
+			80,
+			Price::one()
+		));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionTaken(
+			0, BTC, CAROL, 40, 39,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 961);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 940);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1040);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 60);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn take_dutch_collateral_auction_after_reset_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 10, 0));
+
+		System::set_block_number(11);
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_integer(2)));
+		assert_ok!(AuctionManagerModule::take_collateral(
+			Origin::signed(BOB),
+			0,
+			20,
+			Price::saturating_from_integer(3)
+		));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionReset(
+			0,
+			Price::saturating_from_rational(12, 5),
+		)));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::DutchCollateralAuctionTaken(
+			0, BTC, BOB, 10, 24,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 976);
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1010);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 24);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+	});
+}
+
+#[test]
+fn cancel_dutch_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_dutch_collateral_auction(&ALICE, BTC, 100, 60));
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::take_collateral(Origin::signed(BOB), 0, 20, Price::saturating_from_integer(2)),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1040);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 60);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert!(AuctionManagerModule::dutch_collateral_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn new_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewSurplusAuction(0, 100)));
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 100);
+		assert_eq!(AuctionManagerModule::surplus_auctions(0).unwrap().amount, 100);
+		assert_eq!(AuctionModule::auctions_index(), 1);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn new_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(0, 100),
+			Error::<Runtime>::InvalidAmount,
+		);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(300, 0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewDebitAuction(0, 300, 100)));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 300);
+		assert_eq!(AuctionModule::auctions_index(), 1);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(300, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn surplus_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_eq!(Tokens::total_issuance(ACA), 3000);
+
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None).is_ok());
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		assert_eq!(Tokens::total_issuance(ACA), 2950);
+
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(2, 0, (CAROL, 51), Some((BOB, 50))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionManagerModule::surplus_auction_bid_handler(2, 0, (CAROL, 100), Some((BOB, 50))).is_ok());
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(ACA, &CAROL), 900);
+		assert_eq!(Tokens::total_issuance(ACA), 2900);
+	});
+}
+
+#[test]
+fn debit_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 99), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None).is_ok());
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 300);
+
+		assert!(AuctionManagerModule::debit_auction_bid_handler(2, 0, (CAROL, 200), Some((BOB, 100))).is_ok());
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 150);
+	});
+}
+
+#[test]
+fn surplus_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 50));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 50)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::SurplusAuctionDealt(
+			0, 100, BOB, 50,
+		)));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert!(AuctionManagerModule::surplus_auctions(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn debit_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 200));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 150);
+
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 200)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DebitAuctionDealt(
+			0, 150, BOB, 100,
+		)));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1150);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 50));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert!(AuctionManagerModule::surplus_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(300, 100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 100));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
@@ -23,40 +23,48 @@
 //! The core module of Honzon protocol. CDP engine is responsible for handle
 //! internal processes about CDPs, including liquidation, settlement and risk
 //! management.
+//!
+//! The global interest rate can optionally be adjusted by an interest rate
+//! controller every configured period of blocks. It's a PID controller on the
+//! deviation of the stable currency market price from its peg, bounded by the
+//! governance-set range and maximum step size.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional};
+use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional, PalletId};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
-use loans::Position;
+use loans::{BasketPosition, Position};
 use orml_traits::Change;
 use orml_utilities::OffchainErr;
-use primitives::{Amount, Balance, CurrencyId};
+use primitives::{evm::EvmAddress, Amount, Balance, CurrencyId};
 use rand_chacha::{
 	rand_core::{RngCore, SeedableRng},
 	ChaChaRng,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	offchain::{
 		storage::StorageValueRef,
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{Bounded, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	traits::{AccountIdConversion, Bounded, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
-	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+	DispatchError, DispatchResult, FixedI128, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{marker::PhantomData, mem, prelude::*};
 use support::{
-	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
+	AddressMapping, CDPSettlement, CDPTreasury, CDPTreasuryExtended, CollateralFreeze, EmergencyShutdown,
+	Erc20InfoMapping, ExchangeRate, InvokeContext, LiquidationEvmBridge, LiquidationStrategy as _, Price,
+	PriceProvider, Rate, Ratio, RiskManager,
 };
 
 mod debit_exchange_rate_convertor;
@@ -71,6 +79,7 @@ pub use weights::WeightInfo;
 pub const OFFCHAIN_WORKER_DATA: &[u8] = b"acala/cdp-engine/data/";
 pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/cdp-engine/lock/";
 pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/cdp-engine/max-iterations/";
+pub const OFFCHAIN_WORKER_BASKET_DATA: &[u8] = b"acala/cdp-engine/basket-data/";
 pub const LOCK_DURATION: u64 = 100;
 pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
 
@@ -101,6 +110,58 @@ pub struct RiskManagementParams {
 	/// of CDP so that the current collateral ratio is lower than the
 	/// required collateral ratio. `None` value means not set
 	pub required_collateral_ratio: Option<Ratio>,
+
+	/// Liquidation close factor, the maximum proportion of the debit value
+	/// that can be liquidated in one liquidation. If it's set, unsafe CDP
+	/// will be partially liquidated to bring it back above the required
+	/// collateral ratio. `None` value means liquidate the whole CDP
+	pub liquidation_close_factor: Option<Ratio>,
+}
+
+/// Risk management params of basket CDPs, the collateral ratio of basket CDP
+/// is risk-weighted by the liquidation ratio (or the required collateral
+/// ratio) of every collateral type in the basket.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo)]
+pub struct BasketRiskManagementParams {
+	/// Maximum total debit value generated from basket CDPs, when reach the
+	/// hard cap, CDP's owner cannot issue more stablecoin by basket CDP.
+	pub maximum_total_debit_value: Balance,
+
+	/// Extra interest rate per sec, `None` value means not set
+	pub interest_rate_per_sec: Option<Rate>,
+
+	/// Liquidation penalty rate, when liquidation occurs, basket CDP will be
+	/// deducted an additional penalty base on the product of penalty rate
+	/// and debit value. `None` value means not set
+	pub liquidation_penalty: Option<Rate>,
+}
+
+/// Params of the interest rate controller, which adjusts the global interest
+/// rate per sec by the deviation of the stable currency market price from its
+/// peg.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct InterestRateControllerParams<BlockNumber> {
+	/// Adjust the global interest rate per sec every `period` blocks
+	pub period: BlockNumber,
+
+	/// Proportional gain, applied to the change of the peg deviation
+	pub proportional_gain: Rate,
+
+	/// Integral gain, applied to the peg deviation
+	pub integral_gain: Rate,
+
+	/// Derivative gain, applied to the change of the peg deviation's trend
+	pub derivative_gain: Rate,
+
+	/// The lower bound of the global interest rate per sec
+	pub min_interest_rate_per_sec: Rate,
+
+	/// The upper bound of the global interest rate per sec
+	pub max_interest_rate_per_sec: Rate,
+
+	/// The maximum change of the global interest rate per sec in one
+	/// adjustment
+	pub max_step: Rate,
 }
 
 // typedef to help polkadot.js disambiguate Change with different generic
@@ -117,6 +178,24 @@ pub enum LiquidationStrategy {
 	Auction { auction_count: u32 },
 	/// Liquidation CDP's collateral by swap with DEX
 	Exchange,
+	/// Liquidation CDP's collateral by the liquidation contract in EVM
+	EvmContract { contract: EvmAddress },
+	/// Liquidation CDP's collateral by absorbed by the stability pool
+	StabilityPool,
+}
+
+/// Kind of liquidation strategy, CDP engine tries the liquidation strategies
+/// of the collateral type by order until one of them succeed.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum LiquidationStrategyKind {
+	/// Swap collateral to stable with DEX by the default partial path list
+	Exchange,
+	/// Create collateral auctions by CDP treasury
+	Auction,
+	/// Call the liquidation contract in EVM
+	EvmContract(EvmAddress),
+	/// Absorbed by the stability pool
+	StabilityPool,
 }
 
 /// Status of CDP
@@ -173,6 +252,16 @@ pub mod module {
 		/// The price source of all types of currencies related to CDP
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The price source to fall back to when `PriceSource` can not provide
+		/// the price or its price is stale, such as the TWAP of DEX.
+		/// `PriceSource` should treat the stale prices as unavailable.
+		type FallbackPriceSource: PriceProvider<CurrencyId>;
+
+		/// The market price source of stable currency, the interest rate
+		/// controller compares it with the peg price of stable currency
+		/// provided by `PriceSource`.
+		type StablePriceSource: PriceProvider<CurrencyId>;
+
 		/// A configuration for base priority of unsigned transactions.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
@@ -183,6 +272,10 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The freeze state of collateral types, the frozen collateral can not
+		/// issue new debit or be liquidated.
+		type CollateralFreeze: CollateralFreeze<CurrencyId>;
+
 		/// Time used for computing era duration.
 		///
 		/// It is guaranteed to start being called from the first `on_finalize`.
@@ -195,6 +288,36 @@ pub mod module {
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The CDP engine's module id, the caller of liquidation contracts in EVM.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The bridge to call liquidation contracts in EVM.
+		type LiquidationEvmBridge: LiquidationEvmBridge<Balance>;
+
+		/// The gas limit to call liquidation contracts in EVM.
+		#[pallet::constant]
+		type LiquidationContractGasLimit: Get<u64>;
+
+		/// Convert gas to weight, the weight of liquidation contract calls
+		/// is charged by `LiquidationContractGasLimit`.
+		type GasToWeight: Convert<u64, Weight>;
+
+		/// Mapping between `AccountId` and `EvmAddress`.
+		type EvmAddressMapping: AddressMapping<Self::AccountId>;
+
+		/// Mapping between `CurrencyId` and ERC20 address.
+		type Erc20InfoMapping: Erc20InfoMapping;
+
+		/// The stability pool to absorb the collateral of unsafe CDP.
+		type StabilityPool: support::LiquidationStrategy<
+			Self::AccountId,
+			CurrencyId,
+			Balance,
+			Params = (),
+			Outcome = (),
+		>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -226,6 +349,16 @@ pub mod module {
 		MustAfterShutdown,
 		/// Failed to swap debit by default path list
 		SwapDebitFailed,
+		/// Invalid liquidation close factor
+		InvalidLiquidationCloseFactor,
+		/// Invalid liquidation strategies, empty or duplicated kinds
+		InvalidLiquidationStrategies,
+		/// All liquidation strategies failed
+		LiquidationFailed,
+		/// Invalid interest rate controller params
+		InvalidInterestRateControllerParams,
+		/// The collateral is frozen
+		CollateralFrozen,
 	}
 
 	#[pallet::event]
@@ -258,6 +391,37 @@ pub mod module {
 		/// The global interest rate per sec for all types of collateral
 		/// updated. \[new_global_interest_rate_per_sec\]
 		GlobalInterestRatePerSecUpdated(Rate),
+		/// The liquidation close factor for specific collateral type updated.
+		/// \[collateral_type, new_liquidation_close_factor\]
+		LiquidationCloseFactorUpdated(CurrencyId, Option<Ratio>),
+		/// The liquidation strategies for specific collateral type updated.
+		/// \[collateral_type, new_liquidation_strategies\]
+		LiquidationStrategiesUpdated(CurrencyId, Vec<LiquidationStrategyKind>),
+		/// Liquidate the unsafe basket CDP. \[owner, bad_debt_value,
+		/// liquidated_collaterals\]
+		LiquidateUnsafeBasketCDP(T::AccountId, Balance, Vec<(CurrencyId, Balance, LiquidationStrategy)>),
+		/// Settle the basket CDP has debit. \[owner\]
+		SettleBasketCDPInDebit(T::AccountId),
+		/// The interest rate per sec for basket CDPs updated.
+		/// \[new_interest_rate_per_sec\]
+		BasketInterestRatePerSecUpdated(Option<Rate>),
+		/// The liquidation penalty rate for basket CDPs updated.
+		/// \[new_liquidation_panelty\]
+		BasketLiquidationPenaltyUpdated(Option<Rate>),
+		/// The hard cap of total debit value for basket CDPs updated.
+		/// \[new_total_debit_value\]
+		BasketMaximumTotalDebitValueUpdated(Balance),
+		/// The params of interest rate controller updated.
+		/// \[new_interest_rate_controller_params\]
+		InterestRateControllerUpdated(Option<InterestRateControllerParams<T::BlockNumber>>),
+		/// The global interest rate per sec adjusted by interest rate
+		/// controller. \[peg_deviation, old_global_interest_rate_per_sec,
+		/// new_global_interest_rate_per_sec\]
+		GlobalInterestRatePerSecAdjusted(FixedI128, Rate, Rate),
+		/// Deleverage the CDP by swapping part of collateral to stable on DEX
+		/// to repay debit. \[collateral_type, owner, sold_collateral_amount,
+		/// repaid_debit_value\]
+		DeleverageCDPByDEX(CurrencyId, T::AccountId, Balance, Balance),
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -282,6 +446,28 @@ pub mod module {
 	#[pallet::getter(fn collateral_params)]
 	pub type CollateralParams<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, RiskManagementParams, ValueQuery>;
 
+	/// Mapping from collateral type to its ordered liquidation strategies
+	///
+	/// LiquidationStrategies: CurrencyId => Option<Vec<LiquidationStrategyKind>>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_strategies)]
+	pub type LiquidationStrategies<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Vec<LiquidationStrategyKind>, OptionQuery>;
+
+	/// The exchange rate of debit units and debit value for basket CDPs
+	///
+	/// BasketDebitExchangeRate: Option<ExchangeRate>
+	#[pallet::storage]
+	#[pallet::getter(fn basket_debit_exchange_rate)]
+	pub type BasketDebitExchangeRate<T: Config> = StorageValue<_, ExchangeRate, OptionQuery>;
+
+	/// The risk management params of basket CDPs
+	///
+	/// BasketParams: BasketRiskManagementParams
+	#[pallet::storage]
+	#[pallet::getter(fn basket_params)]
+	pub type BasketParams<T: Config> = StorageValue<_, BasketRiskManagementParams, ValueQuery>;
+
 	/// Timestamp in seconds of the last interest accumulation
 	///
 	/// LastAccumulationSecs: u64
@@ -289,6 +475,23 @@ pub mod module {
 	#[pallet::getter(fn last_accumulation_secs)]
 	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The params of interest rate controller, `None` means the global
+	/// interest rate per sec is not adjusted automatically
+	///
+	/// InterestRateController: Option<InterestRateControllerParams>
+	#[pallet::storage]
+	#[pallet::getter(fn interest_rate_controller)]
+	pub type InterestRateController<T: Config> =
+		StorageValue<_, InterestRateControllerParams<T::BlockNumber>, OptionQuery>;
+
+	/// The last two peg deviations of stable currency observed by interest
+	/// rate controller, the latest one comes first
+	///
+	/// PegDeviations: (FixedI128, FixedI128)
+	#[pallet::storage]
+	#[pallet::getter(fn peg_deviations)]
+	pub type PegDeviations<T: Config> = StorageValue<_, (FixedI128, FixedI128), ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[cfg_attr(feature = "std", derive(Default))]
 	pub struct GenesisConfig {
@@ -324,6 +527,7 @@ pub mod module {
 							liquidation_ratio: *liquidation_ratio,
 							liquidation_penalty: *liquidation_penalty,
 							required_collateral_ratio: *required_collateral_ratio,
+							liquidation_close_factor: None,
 						},
 					);
 				},
@@ -352,6 +556,7 @@ pub mod module {
 				now_as_secs,
 				Self::last_accumulation_secs(),
 			))
+			.saturating_add(Self::adjust_global_interest_rate(now))
 		}
 
 		/// Runs after every block. Start offchain worker to check CDP and
@@ -388,7 +593,7 @@ pub mod module {
 		///
 		/// - `currency_id`: CDP's collateral type.
 		/// - `who`: CDP's owner.
-		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_auction(<T as Config>::CDPTreasury::max_auction()))]
+		#[pallet::weight(Pallet::<T>::max_liquidation_weight())]
 		#[transactional]
 		pub fn liquidate(
 			origin: OriginFor<T>,
@@ -451,6 +656,8 @@ pub mod module {
 		/// - `required_collateral_ratio`: required collateral ratio, `None` means do not update,
 		///   `Some(None)` means update it to `None`.
 		/// - `maximum_total_debit_value`: maximum total debit value.
+		/// - `liquidation_close_factor`: liquidation close factor, `None` means do not update,
+		///   `Some(None)` means update it to `None`.
 		#[pallet::weight((<T as Config>::WeightInfo::set_collateral_params(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn set_collateral_params(
@@ -461,6 +668,7 @@ pub mod module {
 			liquidation_penalty: ChangeOptionRate,
 			required_collateral_ratio: ChangeOptionRatio,
 			maximum_total_debit_value: ChangeBalance,
+			liquidation_close_factor: ChangeOptionRatio,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(
@@ -489,9 +697,147 @@ pub mod module {
 				collateral_params.maximum_total_debit_value = val;
 				Self::deposit_event(Event::MaximumTotalDebitValueUpdated(currency_id, val));
 			}
+			if let Change::NewValue(update) = liquidation_close_factor {
+				if let Some(close_factor) = update {
+					ensure!(
+						!close_factor.is_zero() && close_factor <= Ratio::one(),
+						Error::<T>::InvalidLiquidationCloseFactor
+					);
+				}
+				collateral_params.liquidation_close_factor = update;
+				Self::deposit_event(Event::LiquidationCloseFactorUpdated(currency_id, update));
+			}
 			CollateralParams::<T>::insert(currency_id, collateral_params);
 			Ok(())
 		}
+
+		/// Update the ordered liquidation strategies of specific collateral
+		/// type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `strategies`: the liquidation strategies, CDP engine tries them by order. Every kind
+		///   of strategy can be set at most once.
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidation_strategies(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_liquidation_strategies(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			strategies: Vec<LiquidationStrategyKind>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+			ensure!(!strategies.is_empty(), Error::<T>::InvalidLiquidationStrategies);
+			// every kind of strategy can be set at most once, to bound the weight of liquidation
+			ensure!(
+				strategies.iter().enumerate().all(|(i, strategy)| strategies[..i]
+					.iter()
+					.all(|prev| mem::discriminant(prev) != mem::discriminant(strategy))),
+				Error::<T>::InvalidLiquidationStrategies
+			);
+
+			LiquidationStrategies::<T>::insert(currency_id, strategies.clone());
+			Self::deposit_event(Event::LiquidationStrategiesUpdated(currency_id, strategies));
+			Ok(())
+		}
+
+		/// Liquidate unsafe basket CDP
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `who`: CDP's owner.
+		#[pallet::weight(Pallet::<T>::max_liquidation_weight().saturating_mul(T::CollateralCurrencyIds::get().len() as Weight))]
+		#[transactional]
+		pub fn liquidate_basket(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			let consumed_weight: Weight = Self::liquidate_unsafe_basket_cdp(who)?;
+			Ok(Some(consumed_weight).into())
+		}
+
+		/// Settle basket CDP has debit after system shutdown
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `who`: CDP's owner.
+		#[pallet::weight(<T as Config>::WeightInfo::settle_basket())]
+		#[transactional]
+		pub fn settle_basket(origin: OriginFor<T>, who: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+			Self::settle_basket_cdp_has_debit(who)?;
+			Ok(())
+		}
+
+		/// Update parameters related to risk management of basket CDPs
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `interest_rate_per_sec`: extra interest rate per sec, `None` means do not update,
+		///   `Some(None)` means update it to `None`.
+		/// - `liquidation_penalty`: liquidation penalty, `None` means do not update, `Some(None)`
+		///   means update it to `None`.
+		/// - `maximum_total_debit_value`: maximum total debit value.
+		#[pallet::weight((<T as Config>::WeightInfo::set_basket_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_basket_params(
+			origin: OriginFor<T>,
+			interest_rate_per_sec: ChangeOptionRate,
+			liquidation_penalty: ChangeOptionRate,
+			maximum_total_debit_value: ChangeBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let mut basket_params = Self::basket_params();
+			if let Change::NewValue(update) = interest_rate_per_sec {
+				basket_params.interest_rate_per_sec = update;
+				Self::deposit_event(Event::BasketInterestRatePerSecUpdated(update));
+			}
+			if let Change::NewValue(update) = liquidation_penalty {
+				basket_params.liquidation_penalty = update;
+				Self::deposit_event(Event::BasketLiquidationPenaltyUpdated(update));
+			}
+			if let Change::NewValue(val) = maximum_total_debit_value {
+				basket_params.maximum_total_debit_value = val;
+				Self::deposit_event(Event::BasketMaximumTotalDebitValueUpdated(val));
+			}
+			BasketParams::<T>::put(basket_params);
+			Ok(())
+		}
+
+		/// Update the params of interest rate controller
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the params of interest rate controller, `None` means disable the controller.
+		#[pallet::weight((<T as Config>::WeightInfo::set_interest_rate_controller(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_interest_rate_controller(
+			origin: OriginFor<T>,
+			params: Option<InterestRateControllerParams<T::BlockNumber>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(params) = &params {
+				ensure!(
+					!params.period.is_zero() && params.min_interest_rate_per_sec <= params.max_interest_rate_per_sec,
+					Error::<T>::InvalidInterestRateControllerParams
+				);
+			}
+
+			InterestRateController::<T>::set(params.clone());
+			PegDeviations::<T>::kill();
+			Self::deposit_event(Event::InterestRateControllerUpdated(params));
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -507,6 +853,7 @@ pub mod module {
 						Self::check_cdp_status(*currency_id, collateral, debit),
 						CDPStatus::Unsafe
 					) || T::EmergencyShutdown::is_shutdown()
+						|| T::CollateralFreeze::is_frozen(*currency_id)
 					{
 						return InvalidTransaction::Stale.into();
 					}
@@ -532,6 +879,39 @@ pub mod module {
 						.propagate(true)
 						.build()
 				}
+				Call::liquidate_basket { who } => {
+					let account = T::Lookup::lookup(who.clone())?;
+					let position = <LoansOf<T>>::basket_positions(&account);
+					if !matches!(
+						Self::check_basket_cdp_status(&position.collateral_amounts(), position.debit),
+						CDPStatus::Unsafe
+					) || T::EmergencyShutdown::is_shutdown()
+						|| Self::contains_frozen_collateral(&position.collateral_amounts())
+					{
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides((<frame_system::Pallet<T>>::block_number(), who))
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				Call::settle_basket { who } => {
+					let account = T::Lookup::lookup(who.clone())?;
+					let BasketPosition { debit, .. } = <LoansOf<T>>::basket_positions(account);
+					if debit.is_zero() || !T::EmergencyShutdown::is_shutdown() {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(who)
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
 				_ => InvalidTransaction::Call.into(),
 			}
 		}
@@ -539,6 +919,10 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
 	fn accumulate_interest(now_secs: u64, last_accumulation_secs: u64) -> u32 {
 		let mut count: u32 = 0;
 
@@ -550,32 +934,26 @@ impl<T: Config> Pallet<T> {
 					Self::compound_interest_rate(Self::get_interest_rate_per_sec(currency_id), interval_secs);
 				let total_debits = <LoansOf<T>>::total_positions(currency_id).debit;
 
-				if !rate_to_accumulate.is_zero() && !total_debits.is_zero() {
-					let debit_exchange_rate = Self::get_debit_exchange_rate(currency_id);
-					let debit_exchange_rate_increment = debit_exchange_rate.saturating_mul(rate_to_accumulate);
-					let issued_stable_coin_balance = debit_exchange_rate_increment.saturating_mul_int(total_debits);
-
-					// issue stablecoin to surplus pool
-					let res = <T as Config>::CDPTreasury::on_system_surplus(issued_stable_coin_balance);
-					match res {
-						Ok(_) => {
-							// update exchange rate when issue success
-							let new_debit_exchange_rate =
-								debit_exchange_rate.saturating_add(debit_exchange_rate_increment);
-							DebitExchangeRate::<T>::insert(currency_id, new_debit_exchange_rate);
-						}
-						Err(e) => {
-							log::warn!(
-								target: "cdp-engine",
-								"on_system_surplus: failed to on system surplus {:?}: {:?}. \
-								This is unexpected but should be safe",
-								issued_stable_coin_balance, e
-							);
-						}
-					}
+				if let Some(new_debit_exchange_rate) = Self::issue_interest(
+					Self::get_debit_exchange_rate(currency_id),
+					rate_to_accumulate,
+					total_debits,
+				) {
+					DebitExchangeRate::<T>::insert(currency_id, new_debit_exchange_rate);
 				}
 				count += 1;
 			}
+
+			// accumulate interest for basket CDPs
+			let rate_to_accumulate =
+				Self::compound_interest_rate(Self::get_basket_interest_rate_per_sec(), interval_secs);
+			let total_debits = <LoansOf<T>>::total_basket_positions().debit;
+			if let Some(new_debit_exchange_rate) =
+				Self::issue_interest(Self::get_basket_debit_exchange_rate(), rate_to_accumulate, total_debits)
+			{
+				BasketDebitExchangeRate::<T>::put(new_debit_exchange_rate);
+			}
+			count += 1;
 		}
 
 		// update last accumulation timestamp
@@ -583,6 +961,39 @@ impl<T: Config> Pallet<T> {
 		count
 	}
 
+	// issue the interest of `total_debits` in stable currency to surplus pool, return the new debit
+	// exchange rate if issue success
+	fn issue_interest(
+		debit_exchange_rate: ExchangeRate,
+		rate_to_accumulate: Rate,
+		total_debits: Balance,
+	) -> Option<ExchangeRate> {
+		if rate_to_accumulate.is_zero() || total_debits.is_zero() {
+			return None;
+		}
+
+		let debit_exchange_rate_increment = debit_exchange_rate.saturating_mul(rate_to_accumulate);
+		let issued_stable_coin_balance = debit_exchange_rate_increment.saturating_mul_int(total_debits);
+
+		// issue stablecoin to surplus pool
+		let res = <T as Config>::CDPTreasury::on_system_surplus(issued_stable_coin_balance);
+		match res {
+			Ok(_) => {
+				// update exchange rate when issue success
+				Some(debit_exchange_rate.saturating_add(debit_exchange_rate_increment))
+			}
+			Err(e) => {
+				log::warn!(
+					target: "cdp-engine",
+					"on_system_surplus: failed to on system surplus {:?}: {:?}. \
+					This is unexpected but should be safe",
+					issued_stable_coin_balance, e
+				);
+				None
+			}
+		}
+	}
+
 	fn submit_unsigned_liquidation_tx(currency_id: CurrencyId, who: T::AccountId) {
 		let who = T::Lookup::unlookup(who);
 		let call = Call::<T>::liquidate {
@@ -613,6 +1024,30 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	fn submit_unsigned_basket_liquidation_tx(who: T::AccountId) {
+		let who = T::Lookup::unlookup(who);
+		let call = Call::<T>::liquidate_basket { who: who.clone() };
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "cdp-engine offchain worker",
+				"submit unsigned basket liquidation tx for \nCDP - AccountId {:?} \nfailed!",
+				who,
+			);
+		}
+	}
+
+	fn submit_unsigned_basket_settlement_tx(who: T::AccountId) {
+		let who = T::Lookup::unlookup(who);
+		let call = Call::<T>::settle_basket { who: who.clone() };
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "cdp-engine offchain worker",
+				"submit unsigned basket settlement tx for \nCDP - AccountId {:?} \nfailed!",
+				who,
+			);
+		}
+	}
+
 	fn _offchain_worker() -> Result<(), OffchainErr> {
 		let collateral_currency_ids = T::CollateralCurrencyIds::get();
 		if collateral_currency_ids.len().is_zero() {
@@ -651,6 +1086,7 @@ impl<T: Config> Pallet<T> {
 
 		let currency_id = collateral_currency_ids[collateral_position as usize];
 		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+		let is_frozen = T::CollateralFreeze::is_frozen(currency_id);
 
 		// If start key is Some(value) continue iterating from that point in storage otherwise start
 		// iterating from the beginning of <loans::Positons<T>>
@@ -666,11 +1102,12 @@ impl<T: Config> Pallet<T> {
 		#[allow(clippy::while_let_on_iterator)]
 		while let Some((who, Position { collateral, debit })) = map_iterator.next() {
 			if !is_shutdown
+				&& !is_frozen
 				&& matches!(
 					Self::check_cdp_status(currency_id, collateral, debit),
 					CDPStatus::Unsafe
 				) {
-				// liquidate unsafe CDPs before emergency shutdown occurs
+				// liquidate unsafe CDPs before emergency shutdown occurs, unless the collateral is frozen
 				Self::submit_unsigned_liquidation_tx(currency_id, who);
 			} else if is_shutdown && !debit.is_zero() {
 				// settle CDPs with debit after emergency shutdown occurs.
@@ -712,15 +1149,63 @@ impl<T: Config> Pallet<T> {
 			to_be_continue.set(&(collateral_position, Some(map_iterator.last_raw_key())));
 		}
 
+		// iterate basket CDPs with the remaining iterations
+		if iteration_count < max_iterations {
+			let to_be_continue_basket = StorageValueRef::persistent(OFFCHAIN_WORKER_BASKET_DATA);
+			let mut basket_iterator = match to_be_continue_basket.get::<Vec<u8>>() {
+				Ok(Some(key)) => <loans::BasketPositions<T>>::iter_from(key),
+				_ => <loans::BasketPositions<T>>::iter(),
+			};
+
+			let mut basket_finished = true;
+			#[allow(clippy::while_let_on_iterator)]
+			while let Some((who, position)) = basket_iterator.next() {
+				let collaterals = position.collateral_amounts();
+				if !is_shutdown
+					&& !Self::contains_frozen_collateral(&collaterals)
+					&& matches!(
+						Self::check_basket_cdp_status(&collaterals, position.debit),
+						CDPStatus::Unsafe
+					) {
+					// liquidate unsafe basket CDPs before emergency shutdown occurs
+					Self::submit_unsigned_basket_liquidation_tx(who);
+				} else if is_shutdown && !position.debit.is_zero() {
+					// settle basket CDPs with debit after emergency shutdown occurs.
+					Self::submit_unsigned_basket_settlement_tx(who);
+				}
+
+				iteration_count += 1;
+				if iteration_count == max_iterations {
+					basket_finished = false;
+					break;
+				}
+				// extend offchain worker lock
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
+
+			if basket_finished {
+				to_be_continue_basket.clear();
+			} else {
+				to_be_continue_basket.set(&basket_iterator.last_raw_key());
+			}
+		}
+
 		// Consume the guard but **do not** unlock the underlying lock.
 		guard.forget();
 
 		Ok(())
 	}
 
+	/// Get the relative price from `PriceSource`, fall back to
+	/// `FallbackPriceSource` if it's unavailable.
+	pub fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
+		T::PriceSource::get_relative_price(base, quote)
+			.or_else(|| T::FallbackPriceSource::get_relative_price(base, quote))
+	}
+
 	pub fn check_cdp_status(currency_id: CurrencyId, collateral_amount: Balance, debit_amount: Balance) -> CDPStatus {
 		let stable_currency_id = T::GetStableCurrencyId::get();
-		if let Some(feed_price) = T::PriceSource::get_relative_price(currency_id, stable_currency_id) {
+		if let Some(feed_price) = Self::get_relative_price(currency_id, stable_currency_id) {
 			let collateral_ratio =
 				Self::calculate_collateral_ratio(currency_id, collateral_amount, debit_amount, feed_price);
 			if collateral_ratio < Self::get_liquidation_ratio(currency_id) {
@@ -733,6 +1218,16 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Check the status of basket CDP, it's unsafe if the risk-weighted
+	/// collateral ratio is below one.
+	pub fn check_basket_cdp_status(collaterals: &[(CurrencyId, Balance)], debit_amount: Balance) -> CDPStatus {
+		match Self::calculate_basket_collateral_ratio(collaterals, debit_amount) {
+			Some(collateral_ratio) if collateral_ratio < Ratio::one() => CDPStatus::Unsafe,
+			Some(_) => CDPStatus::Safe,
+			None => CDPStatus::ChecksFailed(Error::<T>::InvalidFeedPrice.into()),
+		}
+	}
+
 	pub fn maximum_total_debit_value(currency_id: CurrencyId) -> Balance {
 		Self::collateral_params(currency_id).maximum_total_debit_value
 	}
@@ -741,6 +1236,23 @@ impl<T: Config> Pallet<T> {
 		Self::collateral_params(currency_id).required_collateral_ratio
 	}
 
+	pub fn liquidation_close_factor(currency_id: CurrencyId) -> Option<Ratio> {
+		Self::collateral_params(currency_id).liquidation_close_factor
+	}
+
+	/// Get the liquidation strategies of the collateral type, absorbed by the
+	/// stability pool first, then swap with DEX and then create collateral
+	/// auctions if they are not set.
+	pub fn get_liquidation_strategies(currency_id: CurrencyId) -> Vec<LiquidationStrategyKind> {
+		Self::liquidation_strategies(currency_id).unwrap_or_else(|| {
+			vec![
+				LiquidationStrategyKind::StabilityPool,
+				LiquidationStrategyKind::Exchange,
+				LiquidationStrategyKind::Auction,
+			]
+		})
+	}
+
 	pub fn get_interest_rate_per_sec(currency_id: CurrencyId) -> Rate {
 		Self::collateral_params(currency_id)
 			.interest_rate_per_sec
@@ -748,6 +1260,73 @@ impl<T: Config> Pallet<T> {
 			.saturating_add(Self::global_interest_rate_per_sec())
 	}
 
+	/// Adjust the global interest rate per sec by interest rate controller
+	/// if it's enabled and the adjustment period is reached, return the
+	/// consumed weight.
+	fn adjust_global_interest_rate(now: T::BlockNumber) -> Weight {
+		let params = match Self::interest_rate_controller() {
+			Some(params) if (now % params.period).is_zero() && !T::EmergencyShutdown::is_shutdown() => params,
+			_ => return T::DbWeight::get().reads(2),
+		};
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let (market_price, peg_price) = match (
+			T::StablePriceSource::get_price(stable_currency_id),
+			T::PriceSource::get_price(stable_currency_id),
+		) {
+			(Some(market_price), Some(peg_price)) if !peg_price.is_zero() => (market_price, peg_price),
+			_ => {
+				log::warn!(
+					target: "cdp-engine",
+					"adjust_global_interest_rate: failed to get the price of stable currency at block {:?}",
+					now,
+				);
+				return <T as Config>::WeightInfo::adjust_global_interest_rate();
+			}
+		};
+
+		// the deviation is positive when stable currency is below the peg, then the
+		// interest rate should be raised to contract the supply of stable currency.
+		let deviation = FixedI128::one().saturating_sub(Self::to_signed(
+			market_price.checked_div(&peg_price).unwrap_or_else(Ratio::max_value),
+		));
+		let (last_deviation, second_last_deviation) = Self::peg_deviations();
+
+		// the incremental form of PID controller
+		let adjustment = Self::to_signed(params.proportional_gain)
+			.saturating_mul(deviation.saturating_sub(last_deviation))
+			.saturating_add(Self::to_signed(params.integral_gain).saturating_mul(deviation))
+			.saturating_add(
+				Self::to_signed(params.derivative_gain).saturating_mul(
+					deviation
+						.saturating_sub(last_deviation.saturating_add(last_deviation))
+						.saturating_add(second_last_deviation),
+				),
+			);
+		let max_step = Self::to_signed(params.max_step);
+		let adjustment = adjustment.max(FixedI128::zero().saturating_sub(max_step)).min(max_step);
+
+		let old_rate = Self::global_interest_rate_per_sec();
+		let step = Rate::from_inner(adjustment.into_inner().unsigned_abs());
+		let new_rate = if adjustment.is_negative() {
+			old_rate.saturating_sub(step)
+		} else {
+			old_rate.saturating_add(step)
+		}
+		.max(params.min_interest_rate_per_sec)
+		.min(params.max_interest_rate_per_sec);
+
+		GlobalInterestRatePerSec::<T>::put(new_rate);
+		PegDeviations::<T>::put((deviation, last_deviation));
+		Self::deposit_event(Event::GlobalInterestRatePerSecAdjusted(deviation, old_rate, new_rate));
+
+		<T as Config>::WeightInfo::adjust_global_interest_rate()
+	}
+
+	fn to_signed(rate: Rate) -> FixedI128 {
+		FixedI128::from_inner(rate.into_inner().unique_saturated_into())
+	}
+
 	pub fn compound_interest_rate(rate_per_sec: Rate, secs: u64) -> Rate {
 		rate_per_sec
 			.saturating_add(Rate::one())
@@ -775,32 +1354,140 @@ impl<T: Config> Pallet<T> {
 		crate::DebitExchangeRateConvertor::<T>::convert((currency_id, debit_balance))
 	}
 
-	pub fn calculate_collateral_ratio(
-		currency_id: CurrencyId,
-		collateral_balance: Balance,
-		debit_balance: Balance,
-		price: Price,
-	) -> Ratio {
-		let locked_collateral_value = price.saturating_mul_int(collateral_balance);
-		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+	pub fn get_basket_interest_rate_per_sec() -> Rate {
+		Self::basket_params()
+			.interest_rate_per_sec
+			.unwrap_or_default()
+			.saturating_add(Self::global_interest_rate_per_sec())
+	}
 
-		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
+	pub fn get_basket_liquidation_penalty() -> Rate {
+		Self::basket_params()
+			.liquidation_penalty
+			.unwrap_or_else(T::DefaultLiquidationPenalty::get)
 	}
 
-	pub fn adjust_position(
-		who: &T::AccountId,
-		currency_id: CurrencyId,
-		collateral_adjustment: Amount,
+	pub fn get_basket_debit_exchange_rate() -> ExchangeRate {
+		Self::basket_debit_exchange_rate().unwrap_or_else(T::DefaultDebitExchangeRate::get)
+	}
+
+	pub fn get_basket_debit_value(debit_balance: Balance) -> Balance {
+		Self::get_basket_debit_exchange_rate().saturating_mul_int(debit_balance)
+	}
+
+	/// Get the risk-weighted value of the collaterals in basket, the value of
+	/// every collateral is weighted by the reciprocal of the liquidation ratio
+	/// of its collateral type, or the larger one of the required collateral
+	/// ratio and the liquidation ratio if `use_required_ratio`. Return `None`
+	/// if the price of any collateral is unavailable.
+	pub fn get_risk_weighted_collateral_value(
+		collaterals: &[(CurrencyId, Balance)],
+		use_required_ratio: bool,
+	) -> Option<Balance> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let mut risk_weighted_value: Balance = Zero::zero();
+
+		for (currency_id, collateral_balance) in collaterals {
+			let price = Self::get_relative_price(*currency_id, stable_currency_id)?;
+			let collateral_ratio = if use_required_ratio {
+				Self::required_collateral_ratio(*currency_id)
+					.unwrap_or_default()
+					.max(Self::get_liquidation_ratio(*currency_id))
+			} else {
+				Self::get_liquidation_ratio(*currency_id)
+			};
+			let collateral_value = collateral_ratio
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(price.saturating_mul_int(*collateral_balance));
+			risk_weighted_value = risk_weighted_value.saturating_add(collateral_value);
+		}
+
+		Some(risk_weighted_value)
+	}
+
+	/// Calculate the risk-weighted collateral ratio of basket CDP. Return
+	/// `None` if the price of any collateral is unavailable.
+	pub fn calculate_basket_collateral_ratio(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: Balance,
+	) -> Option<Ratio> {
+		let risk_weighted_value = Self::get_risk_weighted_collateral_value(collaterals, false)?;
+		let debit_value = Self::get_basket_debit_value(debit_balance);
+
+		Some(Ratio::checked_from_rational(risk_weighted_value, debit_value).unwrap_or_else(Ratio::max_value))
+	}
+
+	pub fn calculate_collateral_ratio(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+		price: Price,
+	) -> Ratio {
+		let locked_collateral_value = price.saturating_mul_int(collateral_balance);
+		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+
+		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
+	}
+
+	/// Check if any of the collaterals is frozen.
+	pub fn contains_frozen_collateral<B>(collaterals: &[(CurrencyId, B)]) -> bool {
+		collaterals
+			.iter()
+			.any(|(currency_id, _)| T::CollateralFreeze::is_frozen(*currency_id))
+	}
+
+	pub fn adjust_position(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
 		debit_adjustment: Amount,
 	) -> DispatchResult {
 		ensure!(
 			T::CollateralCurrencyIds::get().contains(&currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		// the frozen collateral can not issue new debit or be withdrawn, as the
+		// collateral ratio is checked with the locked price
+		ensure!(
+			(debit_adjustment <= 0 && collateral_adjustment >= 0) || !T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen,
+		);
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
 		Ok(())
 	}
 
+	pub fn adjust_basket_position(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		let collateral_currency_ids = T::CollateralCurrencyIds::get();
+		ensure!(
+			collateral_adjustments
+				.iter()
+				.all(|(currency_id, _)| collateral_currency_ids.contains(currency_id)),
+			Error::<T>::InvalidCollateralType,
+		);
+		// the basket CDP can not issue new debit if any of its collaterals is frozen
+		if debit_adjustment > 0 {
+			ensure!(
+				!Self::contains_frozen_collateral(collateral_adjustments)
+					&& !Self::contains_frozen_collateral(&<LoansOf<T>>::basket_positions(who).collateral_amounts()),
+				Error::<T>::CollateralFrozen,
+			);
+		}
+		// the frozen collateral can not be withdrawn from the basket CDP
+		ensure!(
+			!collateral_adjustments
+				.iter()
+				.any(|(currency_id, adjustment)| *adjustment < 0 && T::CollateralFreeze::is_frozen(*currency_id)),
+			Error::<T>::CollateralFrozen,
+		);
+		<LoansOf<T>>::adjust_basket_position(who, collateral_adjustments, debit_adjustment)?;
+		Ok(())
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
@@ -808,8 +1495,8 @@ impl<T: Config> Pallet<T> {
 
 		// confiscate collateral in cdp to cdp treasury
 		// and decrease CDP's debit to zero
-		let settle_price: Price = T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
-			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let settle_price: Price =
+			Self::get_relative_price(T::GetStableCurrencyId::get(), currency_id).ok_or(Error::<T>::InvalidFeedPrice)?;
 		let bad_debt_value = Self::get_debit_value(currency_id, debit);
 		let confiscate_collateral_amount =
 			sp_std::cmp::min(settle_price.saturating_mul_int(bad_debt_value), collateral);
@@ -821,6 +1508,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	// settle basket cdp has debit when emergency shutdown, confiscate the collaterals in basket by
+	// order until the debit value is covered
+	pub fn settle_basket_cdp_has_debit(who: T::AccountId) -> DispatchResult {
+		let position = <LoansOf<T>>::basket_positions(&who);
+		ensure!(!position.debit.is_zero(), Error::<T>::NoDebitValue);
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let mut remain_debit_value = Self::get_basket_debit_value(position.debit);
+		let mut confiscate_collaterals: Vec<(CurrencyId, Balance)> = vec![];
+		for (currency_id, collateral) in position.collateral_amounts() {
+			if remain_debit_value.is_zero() {
+				break;
+			}
+
+			let settle_price: Price =
+				Self::get_relative_price(stable_currency_id, currency_id).ok_or(Error::<T>::InvalidFeedPrice)?;
+			let required_collateral_amount = settle_price.saturating_mul_int(remain_debit_value);
+			if required_collateral_amount <= collateral {
+				confiscate_collaterals.push((currency_id, required_collateral_amount));
+				remain_debit_value = Zero::zero();
+			} else {
+				confiscate_collaterals.push((currency_id, collateral));
+				remain_debit_value = remain_debit_value.saturating_sub(
+					multiply_by_rational(remain_debit_value, collateral, required_collateral_amount)
+						.unwrap_or(remain_debit_value),
+				);
+			}
+		}
+
+		// confiscate collaterals and all debit
+		<LoansOf<T>>::confiscate_basket_collaterals_and_debit(&who, &confiscate_collaterals, position.debit)?;
+
+		Self::deposit_event(Event::SettleBasketCDPInDebit(who));
+		Ok(())
+	}
+
 	// close cdp has debit by swap collateral to exact debit
 	#[transactional]
 	pub fn close_cdp_has_debit_by_dex(
@@ -843,44 +1566,8 @@ impl<T: Config> Pallet<T> {
 		let debit_value = Self::get_debit_value(currency_id, debit);
 		let collateral_supply = collateral.min(max_collateral_amount);
 
-		// if specify swap path
-		let actual_supply_collateral = (|| -> Result<Balance, DispatchError> {
-			if let Some(path) = maybe_path {
-				<T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
-					currency_id,
-					collateral_supply,
-					debit_value,
-					path,
-					false,
-				)
-			} else {
-				let default_swap_parital_path_list: Vec<Vec<CurrencyId>> = T::DefaultSwapParitalPathList::get();
-
-				// iterator default_swap_parital_path_list to try swap until swap succeed.
-				for partial_path in default_swap_parital_path_list {
-					let partial_path_len = partial_path.len();
-
-					// check collateral currency_id and partial_path can form a valid swap path.
-					if partial_path_len > 0 && currency_id != partial_path[0] {
-						let mut swap_path = vec![currency_id];
-						swap_path.extend(partial_path);
-
-						if let Ok(actual_supply_collateral) =
-							<T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
-								currency_id,
-								collateral_supply,
-								debit_value,
-								&swap_path,
-								false,
-							) {
-							return Ok(actual_supply_collateral);
-						}
-					}
-				}
-
-				Err(Error::<T>::SwapDebitFailed.into())
-			}
-		})()?;
+		let actual_supply_collateral =
+			Self::swap_collateral_to_exact_stable(currency_id, collateral_supply, debit_value, maybe_path)?;
 
 		// refund remain collateral to CDP owner
 		let refund_collateral_amount = collateral
@@ -898,38 +1585,98 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	// liquidate unsafe cdp
-	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Weight, DispatchError> {
+	/// Deleverage the safe CDP by swapping part of its collateral to stable on DEX to repay
+	/// debit, until the collateral ratio back to `target_ratio`. The collateral supplied to
+	/// DEX is limited by the slippage compare to oracle price, and the remain collateral
+	/// stays in the CDP. Repay the whole debit if the remain debit would be dust.
+	#[transactional]
+	pub fn deleverage_cdp_by_dex(who: T::AccountId, currency_id: CurrencyId, target_ratio: Ratio) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
-
-		// ensure the cdp is unsafe
+		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
 		ensure!(
-			matches!(
-				Self::check_cdp_status(currency_id, collateral, debit),
-				CDPStatus::Unsafe
-			),
-			Error::<T>::MustBeUnsafe
+			matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe),
+			Error::<T>::MustBeSafe
 		);
+		let collateral_price =
+			Self::get_relative_price(currency_id, T::GetStableCurrencyId::get()).ok_or(Error::<T>::InvalidFeedPrice)?;
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
-		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
-
-		let bad_debt_value = Self::get_debit_value(currency_id, debit);
-		let target_stable_amount = Self::get_liquidation_penalty(currency_id).saturating_mul_acc_int(bad_debt_value);
-		let liquidation_strategy = (|| -> Result<LiquidationStrategy, DispatchError> {
-			let default_swap_parital_path_list: Vec<Vec<CurrencyId>> = T::DefaultSwapParitalPathList::get();
-
-			// calculate the supply limit by slippage limit for the price of oracle,
-			let max_supply_limit = Ratio::one()
-				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		let collateral_value = collateral_price.saturating_mul_int(collateral);
+
+		// repaying debit value `x` removes `x / (1 - slippage)` collateral value at most, so
+		// the CDP is back to target ratio when
+		// `(collateral_value - x * swap_factor) / (debit_value - x) >= target_ratio`.
+		let swap_factor = Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value);
+		let repay_debit = if target_ratio <= swap_factor {
+			debit
+		} else {
+			let required_debit_value = target_ratio
+				.saturating_sub(swap_factor)
 				.reciprocal()
 				.unwrap_or_else(Ratio::max_value)
 				.saturating_mul_int(
-					T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
-						.expect("the oracle price should be avalible because liquidation are triggered by it.")
-						.saturating_mul_int(target_stable_amount),
+					target_ratio
+						.saturating_mul_int(debit_value)
+						.saturating_sub(collateral_value),
 				);
-			let collateral_supply = collateral.min(max_supply_limit);
+			if required_debit_value >= debit_value
+				|| debit_value.saturating_sub(required_debit_value) < T::MinimumDebitValue::get()
+			{
+				debit
+			} else {
+				multiply_by_rational(debit, required_debit_value, debit_value).unwrap_or(debit)
+			}
+		};
+		ensure!(!repay_debit.is_zero(), Error::<T>::NoDebitValue);
+
+		let repay_debit_value = Self::get_debit_value(currency_id, repay_debit);
+		let collateral_supply = Self::get_max_collateral_supply(currency_id, repay_debit_value).min(collateral);
+
+		// confiscate the collateral to be supplied and the debit to be repaid to cdp treasury
+		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral_supply, repay_debit)?;
+
+		let actual_supply_collateral =
+			Self::swap_collateral_to_exact_stable(currency_id, collateral_supply, repay_debit_value, None)?;
+
+		// put the remain collateral back to the CDP
+		let refund_collateral_amount = collateral_supply
+			.checked_sub(actual_supply_collateral)
+			.expect("swap succecced means collateral_supply >= actual_supply_collateral; qed");
+		if !refund_collateral_amount.is_zero() {
+			<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
+			<LoansOf<T>>::adjust_position(&who, currency_id, refund_collateral_amount.unique_saturated_into(), 0)?;
+		}
+
+		Self::deposit_event(Event::DeleverageCDPByDEX(
+			currency_id,
+			who,
+			actual_supply_collateral,
+			repay_debit_value,
+		));
+		Ok(())
+	}
+
+	// swap collateral in CDP treasury to exact stable, use the default swap parital path
+	// list in order if the swap path is not specified
+	fn swap_collateral_to_exact_stable(
+		currency_id: CurrencyId,
+		collateral_supply: Balance,
+		target_stable_amount: Balance,
+		maybe_path: Option<&[CurrencyId]>,
+	) -> Result<Balance, DispatchError> {
+		if let Some(path) = maybe_path {
+			<T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
+				currency_id,
+				collateral_supply,
+				target_stable_amount,
+				path,
+				false,
+			)
+		} else {
+			let default_swap_parital_path_list: Vec<Vec<CurrencyId>> = T::DefaultSwapParitalPathList::get();
 
 			// iterator default_swap_parital_path_list to try swap until swap succeed.
 			for partial_path in default_swap_parital_path_list {
@@ -947,43 +1694,395 @@ impl<T: Config> Pallet<T> {
 						&swap_path,
 						false,
 					) {
-						// refund remain collateral to CDP owner
-						let refund_collateral_amount = collateral
-							.checked_sub(actual_supply_collateral)
-							.expect("swap succecced means collateral >= actual_supply_collateral; qed");
-
-						<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
-
-						return Ok(LiquidationStrategy::Exchange);
+						return Ok(actual_supply_collateral);
 					}
 				}
 			}
 
-			// if cannot liquidate by swap, create collateral auctions by cdp treasury
-			let created_auctions = <T as Config>::CDPTreasury::create_collateral_auctions(
-				currency_id,
-				collateral,
-				target_stable_amount,
-				who.clone(),
-				true,
-			)?;
+			Err(Error::<T>::SwapDebitFailed.into())
+		}
+	}
 
-			Ok(LiquidationStrategy::Auction {
-				auction_count: created_auctions,
-			})
-		})()?;
+	// the max collateral amount can be supplied to swap `target_stable_amount`, limited by slippage
+	// for the price of oracle
+	fn get_max_collateral_supply(currency_id: CurrencyId, target_stable_amount: Balance) -> Balance {
+		Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(
+				Self::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
+					.expect("the oracle price should be avalible because liquidation are triggered by it.")
+					.saturating_mul_int(target_stable_amount),
+			)
+	}
+
+	/// Calculate the collateral amount and debit amount to be liquidated for
+	/// the unsafe CDP.
+	///
+	/// If the liquidation close factor of the collateral type is set, only
+	/// liquidate the debit that brings the CDP back above the required
+	/// collateral ratio (or the liquidation ratio if it's not set), capped by
+	/// the close factor. The collateral to be liquidated covers the debit and
+	/// the liquidation penalty as liquidation bonus, within the slippage limit.
+	/// Otherwise, or the remain CDP would be dust, liquidate the whole CDP.
+	pub fn get_liquidation_amounts(currency_id: CurrencyId, collateral: Balance, debit: Balance) -> (Balance, Balance) {
+		let close_factor = match Self::liquidation_close_factor(currency_id) {
+			Some(close_factor) => close_factor,
+			None => return (collateral, debit),
+		};
+		let collateral_price = match Self::get_relative_price(currency_id, T::GetStableCurrencyId::get()) {
+			Some(price) => price,
+			None => return (collateral, debit),
+		};
+
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		let collateral_value = collateral_price.saturating_mul_int(collateral);
+		let target_ratio = Self::required_collateral_ratio(currency_id)
+			.unwrap_or_default()
+			.max(Self::get_liquidation_ratio(currency_id));
+
+		// liquidating debit value `x` removes `x * (1 + penalty) / (1 - slippage)` collateral value at
+		// most, so the CDP is back to target ratio when
+		// `(collateral_value - x * liquidation_factor) / (debit_value - x) >= target_ratio`.
+		let liquidation_factor = Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul(Ratio::one().saturating_add(Self::get_liquidation_penalty(currency_id)));
+		if target_ratio <= liquidation_factor {
+			// partial liquidation can not bring the CDP back to target ratio
+			return (collateral, debit);
+		}
+
+		let required_debit_value = target_ratio
+			.saturating_sub(liquidation_factor)
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(
+				target_ratio
+					.saturating_mul_int(debit_value)
+					.saturating_sub(collateral_value),
+			);
+		let liquidate_debit_value = required_debit_value.min(close_factor.saturating_mul_int(debit_value));
+		if liquidate_debit_value >= debit_value
+			|| debit_value.saturating_sub(liquidate_debit_value) < T::MinimumDebitValue::get()
+		{
+			return (collateral, debit);
+		}
+
+		let liquidate_debit = multiply_by_rational(debit, liquidate_debit_value, debit_value).unwrap_or(debit);
+		let target_stable_amount = Self::get_liquidation_penalty(currency_id)
+			.saturating_mul_acc_int(Self::get_debit_value(currency_id, liquidate_debit));
+		let liquidate_collateral = Self::get_max_collateral_supply(currency_id, target_stable_amount);
+		if liquidate_debit.is_zero() || liquidate_collateral >= collateral {
+			return (collateral, debit);
+		}
+
+		(liquidate_collateral, liquidate_debit)
+	}
+
+	// liquidate unsafe cdp
+	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Weight, DispatchError> {
+		ensure!(
+			!T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
+
+		// ensure the cdp is unsafe
+		ensure!(
+			matches!(
+				Self::check_cdp_status(currency_id, collateral, debit),
+				CDPStatus::Unsafe
+			),
+			Error::<T>::MustBeUnsafe
+		);
+
+		let (collateral, debit) = Self::get_liquidation_amounts(currency_id, collateral, debit);
+
+		// confiscate the collateral and debit to be liquidated of unsafe cdp to cdp treasury
+		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
+
+		let bad_debt_value = Self::get_debit_value(currency_id, debit);
+		let target_stable_amount = Self::get_liquidation_penalty(currency_id).saturating_mul_acc_int(bad_debt_value);
+
+		// try the liquidation strategies by order until one of them succeed
+		let (liquidation_strategy, weight) =
+			Self::liquidate_collateral(&who, currency_id, collateral, target_stable_amount)?;
 
 		Self::deposit_event(Event::LiquidateUnsafeCDP(
 			currency_id,
 			who,
 			collateral,
 			bad_debt_value,
-			liquidation_strategy.clone(),
+			liquidation_strategy,
+		));
+		Ok(weight)
+	}
+
+	// liquidate unsafe basket cdp, the whole basket cdp is liquidated and the target stable amount
+	// is split to the collaterals by their value
+	pub fn liquidate_unsafe_basket_cdp(who: T::AccountId) -> Result<Weight, DispatchError> {
+		let position = <LoansOf<T>>::basket_positions(&who);
+		let collaterals = position.collateral_amounts();
+		ensure!(
+			!Self::contains_frozen_collateral(&collaterals),
+			Error::<T>::CollateralFrozen
+		);
+
+		// ensure the basket cdp is unsafe
+		ensure!(
+			matches!(
+				Self::check_basket_cdp_status(&collaterals, position.debit),
+				CDPStatus::Unsafe
+			),
+			Error::<T>::MustBeUnsafe
+		);
+
+		// confiscate all collaterals and debit of unsafe basket cdp to cdp treasury
+		<LoansOf<T>>::confiscate_basket_collaterals_and_debit(&who, &collaterals, position.debit)?;
+
+		let bad_debt_value = Self::get_basket_debit_value(position.debit);
+		let target_stable_amount = Self::get_basket_liquidation_penalty().saturating_mul_acc_int(bad_debt_value);
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let collateral_values: Vec<Balance> = collaterals
+			.iter()
+			.map(|(currency_id, collateral)| {
+				Self::get_relative_price(*currency_id, stable_currency_id)
+					.map(|price| price.saturating_mul_int(*collateral))
+					.unwrap_or_default()
+			})
+			.collect();
+		let total_collateral_value = collateral_values
+			.iter()
+			.fold(Balance::zero(), |acc, value| acc.saturating_add(*value));
+
+		let mut weight: Weight = 0;
+		let mut remain_target_stable_amount = target_stable_amount;
+		let mut liquidated_collaterals: Vec<(CurrencyId, Balance, LiquidationStrategy)> = vec![];
+		for (index, ((currency_id, collateral), collateral_value)) in
+			collaterals.iter().zip(collateral_values).enumerate()
+		{
+			// the last collateral takes the remain target to avoid the loss of rounding
+			let collateral_target_stable_amount = if index == collaterals.len() - 1 {
+				remain_target_stable_amount
+			} else {
+				multiply_by_rational(target_stable_amount, collateral_value, total_collateral_value)
+					.unwrap_or(remain_target_stable_amount)
+					.min(remain_target_stable_amount)
+			};
+			remain_target_stable_amount = remain_target_stable_amount.saturating_sub(collateral_target_stable_amount);
+
+			let (liquidation_strategy, liquidation_weight) =
+				Self::liquidate_collateral(&who, *currency_id, *collateral, collateral_target_stable_amount)?;
+			weight = weight.saturating_add(liquidation_weight);
+			liquidated_collaterals.push((*currency_id, *collateral, liquidation_strategy));
+		}
+
+		Self::deposit_event(Event::LiquidateUnsafeBasketCDP(
+			who,
+			bad_debt_value,
+			liquidated_collaterals,
 		));
-		match liquidation_strategy {
-			LiquidationStrategy::Auction { auction_count } => Ok(T::WeightInfo::liquidate_by_auction(auction_count)),
-			LiquidationStrategy::Exchange => Ok(T::WeightInfo::liquidate_by_dex()),
+		Ok(weight)
+	}
+
+	// try the liquidation strategies of the collateral type by order until one of them succeed,
+	// return the strategy succeed and the weight of all tried strategies
+	fn liquidate_collateral(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral: Balance,
+		target_stable_amount: Balance,
+	) -> Result<(LiquidationStrategy, Weight), DispatchError> {
+		let mut weight: Weight = 0;
+		let mut liquidation_error: DispatchError = Error::<T>::LiquidationFailed.into();
+		for strategy in Self::get_liquidation_strategies(currency_id) {
+			let liquidation_result = match strategy {
+				LiquidationStrategyKind::Exchange => {
+					DexLiquidation::<T>::liquidate((), who, currency_id, collateral, target_stable_amount)
+						.map(|_| LiquidationStrategy::Exchange)
+				}
+				LiquidationStrategyKind::Auction => {
+					AuctionLiquidation::<T>::liquidate((), who, currency_id, collateral, target_stable_amount)
+						.map(|auction_count| LiquidationStrategy::Auction { auction_count })
+				}
+				LiquidationStrategyKind::EvmContract(contract) => {
+					EvmContractLiquidation::<T>::liquidate(contract, who, currency_id, collateral, target_stable_amount)
+						.map(|_| LiquidationStrategy::EvmContract { contract })
+				}
+				LiquidationStrategyKind::StabilityPool => {
+					T::StabilityPool::liquidate((), who, currency_id, collateral, target_stable_amount)
+						.map(|_| LiquidationStrategy::StabilityPool)
+				}
+			};
+			weight = weight.saturating_add(Self::liquidation_weight(&strategy));
+
+			match liquidation_result {
+				Ok(liquidation_strategy) => return Ok((liquidation_strategy, weight)),
+				Err(e) => liquidation_error = e,
+			}
+		}
+		Err(liquidation_error)
+	}
+
+	fn liquidation_weight(strategy: &LiquidationStrategyKind) -> Weight {
+		match strategy {
+			LiquidationStrategyKind::Exchange => T::WeightInfo::liquidate_by_dex(),
+			LiquidationStrategyKind::Auction => {
+				T::WeightInfo::liquidate_by_auction(<T as Config>::CDPTreasury::max_auction())
+			}
+			LiquidationStrategyKind::EvmContract(_) => T::WeightInfo::liquidate_by_evm_contract()
+				.saturating_add(T::GasToWeight::convert(T::LiquidationContractGasLimit::get())),
+			LiquidationStrategyKind::StabilityPool => T::WeightInfo::liquidate_by_stability_pool(),
+		}
+	}
+
+	/// The weight to try all kinds of liquidation strategies, every kind can
+	/// be set at most once for a collateral type.
+	pub fn max_liquidation_weight() -> Weight {
+		[
+			LiquidationStrategyKind::StabilityPool,
+			LiquidationStrategyKind::Exchange,
+			LiquidationStrategyKind::EvmContract(Default::default()),
+			LiquidationStrategyKind::Auction,
+		]
+		.iter()
+		.fold(0, |acc: Weight, strategy| {
+			acc.saturating_add(Self::liquidation_weight(strategy))
+		})
+	}
+}
+
+/// Liquidate collateral by swap with DEX, try the default swap partial path
+/// list by order.
+pub struct DexLiquidation<T>(PhantomData<T>);
+
+impl<T: Config> support::LiquidationStrategy<T::AccountId, CurrencyId, Balance> for DexLiquidation<T> {
+	type Params = ();
+	type Outcome = ();
+
+	#[transactional]
+	fn liquidate(
+		_params: (),
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		// calculate the supply limit by slippage limit for the price of oracle,
+		let max_supply_limit = Pallet::<T>::get_max_collateral_supply(currency_id, target_stable_amount);
+		let collateral_supply = collateral.min(max_supply_limit);
+
+		// iterator default_swap_parital_path_list to try swap until swap succeed.
+		for partial_path in T::DefaultSwapParitalPathList::get() {
+			let partial_path_len = partial_path.len();
+
+			// check collateral currency_id and partial_path can form a valid swap path.
+			if partial_path_len > 0 && currency_id != partial_path[0] {
+				let mut swap_path = vec![currency_id];
+				swap_path.extend(partial_path);
+
+				if let Ok(actual_supply_collateral) = <T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
+					currency_id,
+					collateral_supply,
+					target_stable_amount,
+					&swap_path,
+					false,
+				) {
+					// refund remain collateral to CDP owner
+					let refund_collateral_amount = collateral
+						.checked_sub(actual_supply_collateral)
+						.expect("swap succecced means collateral >= actual_supply_collateral; qed");
+
+					<T as Config>::CDPTreasury::withdraw_collateral(who, currency_id, refund_collateral_amount)?;
+
+					return Ok(());
+				}
+			}
+		}
+
+		Err(Error::<T>::SwapDebitFailed.into())
+	}
+}
+
+/// Liquidate collateral by create collateral auctions by CDP treasury, the
+/// outcome is the count of created auctions.
+pub struct AuctionLiquidation<T>(PhantomData<T>);
+
+impl<T: Config> support::LiquidationStrategy<T::AccountId, CurrencyId, Balance> for AuctionLiquidation<T> {
+	type Params = ();
+	type Outcome = u32;
+
+	#[transactional]
+	fn liquidate(
+		_params: (),
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral: Balance,
+		target_stable_amount: Balance,
+	) -> Result<u32, DispatchError> {
+		<T as Config>::CDPTreasury::create_collateral_auctions(
+			currency_id,
+			collateral,
+			target_stable_amount,
+			who.clone(),
+			true,
+		)
+	}
+}
+
+/// Liquidate collateral by the liquidation contract in EVM, the param is the
+/// address of the contract. The contract receives the collateral worth
+/// `target_stable_amount` within the slippage limit for the price of oracle,
+/// and must repay `target_stable_amount` to CDP treasury, the remain
+/// collateral is refunded to CDP owner.
+pub struct EvmContractLiquidation<T>(PhantomData<T>);
+
+impl<T: Config> support::LiquidationStrategy<T::AccountId, CurrencyId, Balance> for EvmContractLiquidation<T> {
+	type Params = EvmAddress;
+	type Outcome = ();
+
+	#[transactional]
+	fn liquidate(
+		contract: EvmAddress,
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		let collateral_address =
+			T::Erc20InfoMapping::encode_evm_address(currency_id).ok_or(Error::<T>::InvalidCollateralType)?;
+		let contract_account = T::EvmAddressMapping::get_account_id(&contract);
+		// CDP engine calls the contract, and pays the storage deposit of the call
+		let caller = T::EvmAddressMapping::get_or_create_evm_address(&Pallet::<T>::account_id());
+
+		let max_supply_limit = Pallet::<T>::get_max_collateral_supply(currency_id, target_stable_amount);
+		let collateral_supply = collateral.min(max_supply_limit);
+
+		<T as Config>::CDPTreasury::withdraw_collateral(&contract_account, currency_id, collateral_supply)?;
+		T::LiquidationEvmBridge::liquidate(
+			InvokeContext {
+				contract,
+				sender: caller,
+				origin: caller,
+			},
+			collateral_address,
+			collateral_supply,
+			target_stable_amount,
+			T::LiquidationContractGasLimit::get(),
+		)?;
+		<T as Config>::CDPTreasury::deposit_surplus(&contract_account, target_stable_amount)?;
+
+		// refund remain collateral to CDP owner
+		let refund_collateral_amount = collateral.saturating_sub(collateral_supply);
+		if !refund_collateral_amount.is_zero() {
+			<T as Config>::CDPTreasury::withdraw_collateral(who, currency_id, refund_collateral_amount)?;
 		}
+
+		Ok(())
 	}
 }
 
@@ -992,6 +2091,10 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 		Self::get_debit_value(currency_id, debit_balance)
 	}
 
+	fn get_liquidation_penalty(currency_id: CurrencyId) -> Rate {
+		Self::get_liquidation_penalty(currency_id)
+	}
+
 	fn check_position_valid(
 		currency_id: CurrencyId,
 		collateral_balance: Balance,
@@ -1000,7 +2103,7 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 	) -> DispatchResult {
 		if !debit_balance.is_zero() {
 			let debit_value = Self::get_debit_value(currency_id, debit_balance);
-			let feed_price = <T as Config>::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			let feed_price = Self::get_relative_price(currency_id, T::GetStableCurrencyId::get())
 				.ok_or(Error::<T>::InvalidFeedPrice)?;
 			let collateral_ratio =
 				Self::calculate_collateral_ratio(currency_id, collateral_balance, debit_balance, feed_price);
@@ -1039,6 +2142,59 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 
 		Ok(())
 	}
+
+	fn get_basket_debit_value(debit_balance: Balance) -> Balance {
+		Self::get_basket_debit_value(debit_balance)
+	}
+
+	fn check_basket_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: Balance,
+		check_required_ratio: bool,
+	) -> DispatchResult {
+		if !debit_balance.is_zero() {
+			let debit_value = Self::get_basket_debit_value(debit_balance);
+
+			// check the required collateral ratio
+			if check_required_ratio {
+				let required_value =
+					Self::get_risk_weighted_collateral_value(collaterals, true).ok_or(Error::<T>::InvalidFeedPrice)?;
+				ensure!(required_value >= debit_value, Error::<T>::BelowRequiredCollateralRatio);
+			}
+
+			// check the liquidation ratio
+			let risk_weighted_value =
+				Self::get_risk_weighted_collateral_value(collaterals, false).ok_or(Error::<T>::InvalidFeedPrice)?;
+			ensure!(risk_weighted_value >= debit_value, Error::<T>::BelowLiquidationRatio);
+
+			// check the minimum_debit_value
+			ensure!(
+				debit_value >= T::MinimumDebitValue::get(),
+				Error::<T>::RemainDebitValueTooSmall,
+			);
+		}
+
+		Ok(())
+	}
+
+	fn check_basket_debit_cap(total_debit_balance: Balance) -> DispatchResult {
+		let hard_cap = Self::basket_params().maximum_total_debit_value;
+		let total_debit_value = Self::get_basket_debit_value(total_debit_balance);
+
+		ensure!(total_debit_value <= hard_cap, Error::<T>::ExceedDebitValueHardCap);
+
+		Ok(())
+	}
+}
+
+impl<T: Config> CDPSettlement<T::AccountId, CurrencyId> for Pallet<T> {
+	fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		Self::settle_cdp_has_debit(who, currency_id)
+	}
+
+	fn settle_basket_cdp_has_debit(who: T::AccountId) -> DispatchResult {
+		Self::settle_basket_cdp_has_debit(who)
+	}
 }
 
 /// Pick a new PRN, in the range [0, `max`) (exclusive).
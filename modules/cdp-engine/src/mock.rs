@@ -27,15 +27,15 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use primitives::{Moment, TokenSymbol, TradingPair};
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::{Header, TestXt},
-	traits::{AccountIdConversion, IdentityLookup, One as OneT},
+	traits::{AccountIdConversion, Identity, IdentityLookup, One as OneT},
 };
 use sp_std::cell::RefCell;
-use support::{AuctionManager, EmergencyShutdown};
+use support::{mocks::MockErc20InfoMapping, AuctionManager, CollateralFreeze, EmergencyShutdown};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -49,6 +49,7 @@ pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+pub const STABILITY_POOL: AccountId = 100;
 
 mod cdp_engine {
 	pub use super::super::*;
@@ -147,6 +148,8 @@ impl loans::Config for Runtime {
 
 thread_local! {
 	static RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::one()));
+	static DOT_RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(None);
+	static STABLE_MARKET_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::one()));
 }
 
 pub struct MockPriceSource;
@@ -154,18 +157,42 @@ impl MockPriceSource {
 	pub fn set_relative_price(price: Option<Price>) {
 		RELATIVE_PRICE.with(|v| *v.borrow_mut() = price);
 	}
+
+	pub fn set_dot_relative_price(price: Option<Price>) {
+		DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut() = price);
+	}
 }
 impl PriceProvider<CurrencyId> for MockPriceSource {
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
 		match (base, quote) {
 			(AUSD, BTC) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			(BTC, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(AUSD, DOT) => DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(DOT, AUSD) => DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			_ => None,
 		}
 	}
 
-	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
-		unimplemented!()
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			_ => unimplemented!(),
+		}
+	}
+}
+
+pub struct MockStablePriceSource;
+impl MockStablePriceSource {
+	pub fn set_price(price: Option<Price>) {
+		STABLE_MARKET_PRICE.with(|v| *v.borrow_mut() = price);
+	}
+}
+impl PriceProvider<CurrencyId> for MockStablePriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => STABLE_MARKET_PRICE.with(|v| *v.borrow_mut()),
+			_ => None,
+		}
 	}
 }
 
@@ -184,6 +211,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -195,6 +239,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 parameter_types! {
@@ -221,6 +273,14 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 4;
+	pub const DexUnsignedPriority: u64 = 1 << 20;
+	pub const SwapSplitParts: u32 = 10;
+	pub const MaxSearchedTradingPairs: u32 = 10;
+	pub const MaxSearchedTradingPaths: u32 = 10;
+	pub const LimitOrderDeposit: Balance = 100;
+	pub const MaxLimitOrdersPerAccount: u32 = 10;
+	pub const TwapWindow: BlockNumber = 10;
+	pub const TwapMaxAge: BlockNumber = 20;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -235,11 +295,22 @@ impl dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type UnsignedPriority = DexUnsignedPriority;
+	type ProtocolFeeReceiver = TreasuryAccount;
+	type Call = Call;
 }
 
 parameter_types! {
@@ -254,12 +325,21 @@ impl pallet_timestamp::Config for Runtime {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static FROZEN: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_freeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().push(currency_id))
+}
+
+pub fn mock_unfreeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().retain(|id| *id != currency_id))
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
@@ -267,6 +347,98 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockCollateralFreeze;
+impl CollateralFreeze<CurrencyId> for MockCollateralFreeze {
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		FROZEN.with(|v| v.borrow().contains(&currency_id))
+	}
+}
+
+pub struct MockAddressMapping;
+impl AddressMapping<AccountId> for MockAddressMapping {
+	fn get_account_id(evm: &EvmAddress) -> AccountId {
+		evm.to_low_u64_be() as AccountId
+	}
+
+	fn get_evm_address(account_id: &AccountId) -> Option<EvmAddress> {
+		Some(H160::from_low_u64_be(*account_id as u64))
+	}
+
+	fn get_or_create_evm_address(account_id: &AccountId) -> EvmAddress {
+		H160::from_low_u64_be(*account_id as u64)
+	}
+
+	fn get_default_evm_address(account_id: &AccountId) -> EvmAddress {
+		H160::from_low_u64_be(*account_id as u64)
+	}
+
+	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool {
+		Self::get_account_id(evm) == *account_id
+	}
+}
+
+thread_local! {
+	static LIQUIDATION_CONTRACT_ENABLED: RefCell<bool> = RefCell::new(false);
+	static STABILITY_POOL_ENABLED: RefCell<bool> = RefCell::new(false);
+}
+
+pub struct MockLiquidationEvmBridge;
+impl MockLiquidationEvmBridge {
+	pub fn set_enabled(enabled: bool) {
+		LIQUIDATION_CONTRACT_ENABLED.with(|v| *v.borrow_mut() = enabled);
+	}
+}
+impl LiquidationEvmBridge<Balance> for MockLiquidationEvmBridge {
+	fn liquidate(
+		context: InvokeContext,
+		_collateral: EvmAddress,
+		_amount: Balance,
+		min_repayment: Balance,
+		gas_limit: u64,
+	) -> DispatchResult {
+		if !LIQUIDATION_CONTRACT_ENABLED.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("liquidation contract reverted"));
+		}
+		if gas_limit != LiquidationContractGasLimit::get() {
+			return Err(DispatchError::Other("invalid gas limit"));
+		}
+		// CDP engine is the caller of the contract
+		if context.sender != MockAddressMapping::get_or_create_evm_address(&CDPEngineModule::account_id())
+			|| context.origin != context.sender
+		{
+			return Err(DispatchError::Other("invalid caller"));
+		}
+		// the contract gets the stable currency to repay
+		let contract_account = MockAddressMapping::get_account_id(&context.contract);
+		Currencies::deposit(AUSD, &contract_account, min_repayment)
+	}
+}
+
+pub struct MockStabilityPool;
+impl MockStabilityPool {
+	pub fn set_enabled(enabled: bool) {
+		STABILITY_POOL_ENABLED.with(|v| *v.borrow_mut() = enabled);
+	}
+}
+impl support::LiquidationStrategy<AccountId, CurrencyId, Balance> for MockStabilityPool {
+	type Params = ();
+	type Outcome = ();
+
+	fn liquidate(
+		_params: (),
+		_who: &AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		if !STABILITY_POOL_ENABLED.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("stability pool is not enough"));
+		}
+		CDPTreasuryModule::withdraw_collateral(&STABILITY_POOL, currency_id, amount)?;
+		CDPTreasuryModule::deposit_surplus(&STABILITY_POOL, target_stable_amount)
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -278,6 +450,9 @@ parameter_types! {
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
+	pub const LiquidationContractGasLimit: u64 = 1_000_000;
+	pub StableCurrencyFixedPrice: Price = Price::one();
 	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
@@ -288,6 +463,8 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type FallbackPriceSource = dex::DexTwapPriceProvider<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>;
+	type StablePriceSource = MockStablePriceSource;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -299,8 +476,16 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type CollateralFreeze = MockCollateralFreeze;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type PalletId = CDPEnginePalletId;
+	type LiquidationEvmBridge = MockLiquidationEvmBridge;
+	type LiquidationContractGasLimit = LiquidationContractGasLimit;
+	type GasToWeight = Identity;
+	type EvmAddressMapping = MockAddressMapping;
+	type Erc20InfoMapping = MockErc20InfoMapping;
+	type StabilityPool = MockStabilityPool;
 	type WeightInfo = ();
 }
 
@@ -351,6 +536,7 @@ impl Default for ExtBuilder {
 				(BOB, DOT, 1000),
 				(CAROL, DOT, 10000),
 				(CAROL, AUSD, 10000),
+				(STABILITY_POOL, AUSD, 10000),
 			],
 		}
 	}
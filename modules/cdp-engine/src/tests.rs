@@ -24,7 +24,11 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Call as MockCall, Event, *};
 use orml_traits::MultiCurrency;
-use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+use primitives::TradingPair;
+use sp_core::{
+	offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt},
+	H160,
+};
 use sp_io::offchain;
 use sp_runtime::{
 	offchain::{DbExternalities, StorageKind},
@@ -57,6 +61,7 @@ fn check_cdp_status_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Safe);
 
@@ -68,6 +73,7 @@ fn check_cdp_status_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Unsafe);
 
@@ -79,6 +85,44 @@ fn check_cdp_status_work() {
 	});
 }
 
+#[test]
+fn check_cdp_status_fall_back_to_dex_twap_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Safe);
+
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(CDPEngineModule::get_relative_price(BTC, AUSD), None);
+		assert_eq!(
+			CDPEngineModule::check_cdp_status(BTC, 100, 500),
+			CDPStatus::ChecksFailed(Error::<Runtime>::InvalidFeedPrice.into())
+		);
+
+		dex::TwapPrices::<Runtime>::insert(
+			TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
+			(
+				ExchangeRate::saturating_from_integer(10),
+				ExchangeRate::saturating_from_rational(1, 10),
+				System::block_number(),
+			),
+		);
+		assert_eq!(
+			CDPEngineModule::get_relative_price(BTC, AUSD),
+			Some(Price::saturating_from_rational(1, 10))
+		);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Unsafe);
+	});
+}
+
 #[test]
 fn get_debit_exchange_rate_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -109,6 +153,7 @@ fn get_liquidation_penalty_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_penalty(BTC),
@@ -132,6 +177,7 @@ fn get_liquidation_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_ratio(BTC),
@@ -174,6 +220,7 @@ fn set_collateral_params_work() {
 				Change::NoChange,
 				Change::NoChange,
 				Change::NoChange,
+				Change::NoChange,
 			),
 			Error::<Runtime>::InvalidCollateralType
 		);
@@ -188,6 +235,7 @@ fn set_collateral_params_work() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10000),
+				Change::NoChange,
 			),
 			BadOrigin
 		);
@@ -199,6 +247,7 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		System::assert_has_event(Event::CDPEngineModule(crate::Event::InterestRatePerSecUpdated(
 			BTC,
@@ -228,7 +277,39 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 2))),
 		));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::LiquidationCloseFactorUpdated(
+			BTC,
+			Some(Ratio::saturating_from_rational(1, 2)),
+		)));
+
+		assert_noop!(
+			CDPEngineModule::set_collateral_params(
+				Origin::signed(1),
+				BTC,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::zero())),
+			),
+			Error::<Runtime>::InvalidLiquidationCloseFactor
+		);
+		assert_noop!(
+			CDPEngineModule::set_collateral_params(
+				Origin::signed(1),
+				BTC,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(11, 10))),
+			),
+			Error::<Runtime>::InvalidLiquidationCloseFactor
+		);
 
 		let new_collateral_params = CDPEngineModule::collateral_params(BTC);
 
@@ -249,6 +330,10 @@ fn set_collateral_params_work() {
 			Some(Ratio::saturating_from_rational(9, 5))
 		);
 		assert_eq!(new_collateral_params.maximum_total_debit_value, 10000);
+		assert_eq!(
+			new_collateral_params.liquidation_close_factor,
+			Some(Ratio::saturating_from_rational(1, 2))
+		);
 	});
 }
 
@@ -263,6 +348,7 @@ fn calculate_collateral_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::calculate_collateral_ratio(BTC, 100, 500, Price::saturating_from_rational(1, 1)),
@@ -282,6 +368,7 @@ fn check_debit_cap_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::check_debit_cap(BTC, 100000));
 		assert_noop!(
@@ -302,6 +389,7 @@ fn check_position_valid_failed_when_invalid_feed_price() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		MockPriceSource::set_relative_price(None);
@@ -326,6 +414,7 @@ fn check_position_valid_failed_when_remain_debit_value_too_small() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 2, 10, true),
@@ -345,6 +434,7 @@ fn check_position_valid_ratio_below_liquidate_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 91, 500, true),
@@ -364,6 +454,7 @@ fn check_position_valid_ratio_below_required_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::check_position_valid(BTC, 89, 500, false));
 		assert_noop!(
@@ -384,6 +475,7 @@ fn adjust_position_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::adjust_position(&ALICE, ACA, 100, 500),
@@ -418,6 +510,7 @@ fn remain_debit_value_too_small_check() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert!(!CDPEngineModule::adjust_position(&ALICE, BTC, 0, -490).is_ok());
@@ -425,6 +518,55 @@ fn remain_debit_value_too_small_check() {
 	});
 }
 
+#[test]
+fn frozen_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		mock_freeze_collateral(BTC);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 100, 100),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, -10, -100),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 10, -100));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 110);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
+			Error::<Runtime>::CollateralFrozen,
+		);
+
+		mock_unfreeze_collateral(BTC);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+	});
+}
+
 #[test]
 fn liquidate_unsafe_cdp_by_collateral_auction() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -437,6 +579,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -455,6 +598,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
@@ -490,6 +634,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
@@ -516,6 +661,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		// pool is enough, but slippage limit the swap
@@ -552,6 +698,7 @@ fn liquidate_unsafe_cdp_by_swap() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
@@ -578,6 +725,7 @@ fn liquidate_unsafe_cdp_by_swap() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
@@ -599,114 +747,357 @@ fn liquidate_unsafe_cdp_by_swap() {
 }
 
 #[test]
-fn get_interest_rate_per_sec_work() {
+fn liquidate_unsafe_cdp_partially() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Rate::zero());
-		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(DOT), Rate::zero());
-
-		assert_ok!(CDPEngineModule::set_global_params(
-			Origin::signed(1),
-			Rate::saturating_from_rational(1, 10000),
-		));
-		assert_eq!(
-			CDPEngineModule::get_interest_rate_per_sec(BTC),
-			Rate::saturating_from_rational(1, 10000)
-		);
-		assert_eq!(
-			CDPEngineModule::get_interest_rate_per_sec(DOT),
-			Rate::saturating_from_rational(1, 10000)
-		);
-
+		System::set_block_number(1);
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
-			Change::NewValue(Some(Rate::saturating_from_rational(2, 100000))),
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 2))),
 		));
-		assert_eq!(
-			CDPEngineModule::get_interest_rate_per_sec(BTC),
-			Rate::saturating_from_rational(12, 100000)
-		);
-		assert_eq!(
-			CDPEngineModule::get_interest_rate_per_sec(DOT),
-			Rate::saturating_from_rational(1, 10000)
-		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 1000, 2000));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 200);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_integer(6))),
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(32, 5))),
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 1000, 2000), CDPStatus::Unsafe);
+
+		// liquidate 70 debit value to bring the CDP back to the required collateral ratio,
+		// collateral to liquidate is (1 + penalty) / (1 - slippage) * 70
+		assert_eq!(CDPEngineModule::get_liquidation_amounts(BTC, 1000, 2000), (168, 700));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			168,
+			70,
+			LiquidationStrategy::Auction { auction_count: 1 },
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 70);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 1300);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 832);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 832, 1300), CDPStatus::Safe);
+
+		// liquidate the whole CDP when the remain debit would be below the minimum debit value
+		assert_eq!(CDPEngineModule::get_liquidation_amounts(BTC, 5, 20), (5, 20));
+
+		// liquidate the whole CDP when close factor is not set
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(None),
+		));
+		assert_eq!(CDPEngineModule::get_liquidation_amounts(BTC, 1000, 2000), (1000, 2000));
 	});
 }
 
 #[test]
-fn compound_interest_rate_work() {
+fn set_liquidation_strategies_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(CDPEngineModule::compound_interest_rate(Rate::zero(), 10), Rate::zero());
+		System::set_block_number(1);
 		assert_eq!(
-			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 0),
-			Rate::zero()
+			CDPEngineModule::get_liquidation_strategies(BTC),
+			vec![
+				LiquidationStrategyKind::StabilityPool,
+				LiquidationStrategyKind::Exchange,
+				LiquidationStrategyKind::Auction
+			]
 		);
-		assert_eq!(
-			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 1),
-			Rate::saturating_from_rational(1, 10000)
+
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(Origin::signed(5), BTC, vec![LiquidationStrategyKind::Auction]),
+			BadOrigin
 		);
-		assert_eq!(
-			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 2),
-			Rate::saturating_from_rational(20001, 100000000)
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(
+				Origin::signed(1),
+				LDOT,
+				vec![LiquidationStrategyKind::Auction]
+			),
+			Error::<Runtime>::InvalidCollateralType
 		);
-
-		// 1% APY
-		assert_eq!(
-			CDPEngineModule::compound_interest_rate(
-				Rate::saturating_from_rational(315_523_000u128, 1_000_000_000_000_000_000u128),
-				6
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(Origin::signed(1), BTC, vec![]),
+			Error::<Runtime>::InvalidLiquidationStrategies
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(
+				Origin::signed(1),
+				BTC,
+				vec![
+					LiquidationStrategyKind::EvmContract(H160::from_low_u64_be(200)),
+					LiquidationStrategyKind::EvmContract(H160::from_low_u64_be(201)),
+				]
 			),
-			Rate::saturating_from_rational(1_893_138_000u128, 1_000_000_000_000_000_000u128)
+			Error::<Runtime>::InvalidLiquidationStrategies
 		);
+
+		let strategies = vec![
+			LiquidationStrategyKind::EvmContract(H160::from_low_u64_be(200)),
+			LiquidationStrategyKind::StabilityPool,
+			LiquidationStrategyKind::Auction,
+		];
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			strategies.clone()
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationStrategiesUpdated(
+			BTC,
+			strategies.clone(),
+		)));
+		assert_eq!(CDPEngineModule::get_liquidation_strategies(BTC), strategies);
 		assert_eq!(
-			CDPEngineModule::compound_interest_rate(
-				Rate::saturating_from_rational(315_523_000u128, 1_000_000_000_000_000_000u128),
-				12
-			),
-			Rate::saturating_from_rational(3_786_276_004u128, 1_000_000_000_000_000_000u128)
+			CDPEngineModule::get_liquidation_strategies(DOT),
+			vec![
+				LiquidationStrategyKind::StabilityPool,
+				LiquidationStrategyKind::Exchange,
+				LiquidationStrategyKind::Auction
+			]
 		);
 	});
 }
 
 #[test]
-fn accumulate_interest_work() {
+fn liquidate_unsafe_cdp_by_liquidation_strategies() {
 	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = H160::from_low_u64_be(200);
+		let contract_account: AccountId = 200;
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
-			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			vec![
+				LiquidationStrategyKind::EvmContract(contract),
+				LiquidationStrategyKind::StabilityPool,
+				LiquidationStrategyKind::Auction,
+			]
 		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 200, 500));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
-			DOT,
-			Change::NewValue(Some(Rate::saturating_from_rational(2, 100))),
-			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
-			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
-			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
-			Change::NewValue(10000),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
 		));
 
-		CDPEngineModule::accumulate_interest(1, 0);
-		assert_eq!(CDPEngineModule::last_accumulation_secs(), 1);
-		assert_eq!(
-			CDPEngineModule::get_debit_exchange_rate(BTC),
-			ExchangeRate::saturating_from_rational(1, 10)
-		);
-		assert_eq!(CDPEngineModule::debit_exchange_rate(BTC), None);
-		assert_eq!(
-			CDPEngineModule::get_debit_exchange_rate(DOT),
-			ExchangeRate::saturating_from_rational(1, 10)
-		);
-		assert_eq!(CDPEngineModule::debit_exchange_rate(DOT), None);
-		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
+		// the liquidation contract and stability pool failed, fall back to auction
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction { auction_count: 1 },
+		)));
+		assert_eq!(Currencies::free_balance(BTC, &contract_account), 0);
+		assert_eq!(Currencies::free_balance(BTC, &STABILITY_POOL), 0);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+
+		// liquidated by the stability pool
+		MockStabilityPool::set_enabled(true);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			BOB,
+			100,
+			50,
+			LiquidationStrategy::StabilityPool,
+		)));
+		assert_eq!(Currencies::free_balance(BTC, &STABILITY_POOL), 100);
+		assert_eq!(Currencies::free_balance(AUSD, &STABILITY_POOL), 9940);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 60);
+
+		// liquidated by the liquidation contract, the contract only receives the collateral
+		// worth the target within the slippage limit, the remain is refunded to CAROL
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		MockLiquidationEvmBridge::set_enabled(true);
+		let carol_btc_balance = Currencies::free_balance(BTC, &CAROL);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(CAROL, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			CAROL,
+			200,
+			50,
+			LiquidationStrategy::EvmContract { contract },
+		)));
+		assert_eq!(Currencies::free_balance(BTC, &contract_account), 120);
+		assert_eq!(Currencies::free_balance(BTC, &CAROL), carol_btc_balance + 80);
+		assert_eq!(Currencies::free_balance(AUSD, &contract_account), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 120);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 150);
+	});
+}
+
+#[test]
+fn liquidation_weight_charges_contract_gas_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_contract_weight =
+			CDPEngineModule::liquidation_weight(&LiquidationStrategyKind::EvmContract(H160::from_low_u64_be(200)));
+		assert_eq!(
+			evm_contract_weight,
+			<() as WeightInfo>::liquidate_by_evm_contract() + LiquidationContractGasLimit::get()
+		);
+		assert!(CDPEngineModule::max_liquidation_weight() > evm_contract_weight);
+	});
+}
+
+#[test]
+fn get_interest_rate_per_sec_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Rate::zero());
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(DOT), Rate::zero());
+
+		assert_ok!(CDPEngineModule::set_global_params(
+			Origin::signed(1),
+			Rate::saturating_from_rational(1, 10000),
+		));
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(1, 10000)
+		);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(DOT),
+			Rate::saturating_from_rational(1, 10000)
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(12, 100000)
+		);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(DOT),
+			Rate::saturating_from_rational(1, 10000)
+		);
+	});
+}
+
+#[test]
+fn compound_interest_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(CDPEngineModule::compound_interest_rate(Rate::zero(), 10), Rate::zero());
+		assert_eq!(
+			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 0),
+			Rate::zero()
+		);
+		assert_eq!(
+			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 1),
+			Rate::saturating_from_rational(1, 10000)
+		);
+		assert_eq!(
+			CDPEngineModule::compound_interest_rate(Rate::saturating_from_rational(1, 10000), 2),
+			Rate::saturating_from_rational(20001, 100000000)
+		);
+
+		// 1% APY
+		assert_eq!(
+			CDPEngineModule::compound_interest_rate(
+				Rate::saturating_from_rational(315_523_000u128, 1_000_000_000_000_000_000u128),
+				6
+			),
+			Rate::saturating_from_rational(1_893_138_000u128, 1_000_000_000_000_000_000u128)
+		);
+		assert_eq!(
+			CDPEngineModule::compound_interest_rate(
+				Rate::saturating_from_rational(315_523_000u128, 1_000_000_000_000_000_000u128),
+				12
+			),
+			Rate::saturating_from_rational(3_786_276_004u128, 1_000_000_000_000_000_000u128)
+		);
+	});
+}
+
+#[test]
+fn accumulate_interest_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			DOT,
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+
+		CDPEngineModule::accumulate_interest(1, 0);
+		assert_eq!(CDPEngineModule::last_accumulation_secs(), 1);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+		assert_eq!(CDPEngineModule::debit_exchange_rate(BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(DOT),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+		assert_eq!(CDPEngineModule::debit_exchange_rate(DOT), None);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
 
 		CDPEngineModule::accumulate_interest(2, 1);
 		assert_eq!(CDPEngineModule::last_accumulation_secs(), 2);
@@ -745,6 +1136,176 @@ fn accumulate_interest_work() {
 	});
 }
 
+fn interest_rate_controller_params() -> InterestRateControllerParams<BlockNumber> {
+	InterestRateControllerParams {
+		period: 10,
+		proportional_gain: Rate::saturating_from_rational(1, 2),
+		integral_gain: Rate::saturating_from_rational(1, 10),
+		derivative_gain: Rate::saturating_from_rational(1, 10),
+		min_interest_rate_per_sec: Rate::saturating_from_rational(2, 100),
+		max_interest_rate_per_sec: Rate::saturating_from_rational(20, 100),
+		max_step: Rate::saturating_from_rational(5, 100),
+	}
+}
+
+#[test]
+fn set_interest_rate_controller_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_interest_rate_controller(Origin::signed(5), Some(interest_rate_controller_params())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_interest_rate_controller(
+				Origin::signed(1),
+				Some(InterestRateControllerParams {
+					period: 0,
+					..interest_rate_controller_params()
+				})
+			),
+			Error::<Runtime>::InvalidInterestRateControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_interest_rate_controller(
+				Origin::signed(1),
+				Some(InterestRateControllerParams {
+					min_interest_rate_per_sec: Rate::saturating_from_rational(30, 100),
+					..interest_rate_controller_params()
+				})
+			),
+			Error::<Runtime>::InvalidInterestRateControllerParams
+		);
+
+		PegDeviations::<Runtime>::put((FixedI128::saturating_from_rational(1, 10), FixedI128::zero()));
+		assert_ok!(CDPEngineModule::set_interest_rate_controller(
+			Origin::signed(1),
+			Some(interest_rate_controller_params())
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::InterestRateControllerUpdated(
+			Some(interest_rate_controller_params()),
+		)));
+		assert_eq!(
+			CDPEngineModule::interest_rate_controller(),
+			Some(interest_rate_controller_params())
+		);
+		assert_eq!(
+			CDPEngineModule::peg_deviations(),
+			(FixedI128::zero(), FixedI128::zero())
+		);
+
+		assert_ok!(CDPEngineModule::set_interest_rate_controller(Origin::signed(1), None));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::InterestRateControllerUpdated(
+			None,
+		)));
+		assert_eq!(CDPEngineModule::interest_rate_controller(), None);
+	});
+}
+
+#[test]
+fn adjust_global_interest_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_global_params(
+			Origin::signed(1),
+			Rate::saturating_from_rational(1, 100),
+		));
+		MockStablePriceSource::set_price(Some(Price::saturating_from_rational(9, 10)));
+
+		// the controller is disabled
+		CDPEngineModule::on_initialize(10);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(1, 100)
+		);
+
+		assert_ok!(CDPEngineModule::set_interest_rate_controller(
+			Origin::signed(1),
+			Some(interest_rate_controller_params())
+		));
+
+		// the adjustment period is not reached
+		CDPEngineModule::on_initialize(11);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(1, 100)
+		);
+
+		// below the peg, raise the rate by the max step
+		CDPEngineModule::on_initialize(20);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalInterestRatePerSecAdjusted(
+			FixedI128::saturating_from_rational(1, 10),
+			Rate::saturating_from_rational(1, 100),
+			Rate::saturating_from_rational(6, 100),
+		)));
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(6, 100)
+		);
+		assert_eq!(
+			CDPEngineModule::peg_deviations(),
+			(FixedI128::saturating_from_rational(1, 10), FixedI128::zero())
+		);
+
+		// recovering towards the peg, lower the rate
+		MockStablePriceSource::set_price(Some(Price::saturating_from_rational(95, 100)));
+		CDPEngineModule::on_initialize(30);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalInterestRatePerSecAdjusted(
+			FixedI128::saturating_from_rational(5, 100),
+			Rate::saturating_from_rational(6, 100),
+			Rate::saturating_from_rational(25, 1000),
+		)));
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(25, 1000)
+		);
+
+		// above the peg, lower the rate by the max step but keep it within the bounds
+		MockStablePriceSource::set_price(Some(Price::saturating_from_rational(12, 10)));
+		CDPEngineModule::on_initialize(40);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalInterestRatePerSecAdjusted(
+			FixedI128::saturating_from_rational(-2, 10),
+			Rate::saturating_from_rational(25, 1000),
+			Rate::saturating_from_rational(2, 100),
+		)));
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(2, 100)
+		);
+		assert_eq!(
+			CDPEngineModule::peg_deviations(),
+			(
+				FixedI128::saturating_from_rational(-2, 10),
+				FixedI128::saturating_from_rational(5, 100)
+			)
+		);
+
+		// no market price of stable currency
+		MockStablePriceSource::set_price(None);
+		CDPEngineModule::on_initialize(50);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(2, 100)
+		);
+		assert_eq!(
+			CDPEngineModule::peg_deviations(),
+			(
+				FixedI128::saturating_from_rational(-2, 10),
+				FixedI128::saturating_from_rational(5, 100)
+			)
+		);
+
+		// the controller stops after emergency shutdown
+		MockStablePriceSource::set_price(Some(Price::saturating_from_rational(9, 10)));
+		mock_shutdown();
+		CDPEngineModule::on_initialize(60);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(2, 100)
+		);
+	});
+}
+
 #[test]
 fn settle_cdp_has_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -757,6 +1318,7 @@ fn settle_cdp_has_debit_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -804,6 +1366,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
@@ -833,6 +1396,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None),
@@ -847,6 +1411,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		assert_noop!(
@@ -906,6 +1471,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_eq!(DEXModule::get_liquidity_pool(BTC, ACA), (100, 1000));
@@ -926,6 +1492,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::CloseCDPInDebitByDEX(
@@ -943,18 +1510,18 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 }
 
 #[test]
-fn offchain_worker_works_cdp() {
-	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
-	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
-	let mut ext = ExtBuilder::default().build();
-	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
-	ext.register_extension(TransactionPoolExt::new(pool));
-	ext.register_extension(OffchainDbExt::new(offchain));
-
-	ext.execute_with(|| {
-		// number of currencies allowed as collateral (cycles through all of them)
-		let collateral_currencies_num = CollateralCurrencyIds::get().len() as u64;
+fn deleverage_cdp_by_dex_work() {
+	ExtBuilder::default().build().execute_with(|| {
 		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
@@ -963,14 +1530,76 @@ fn offchain_worker_works_cdp() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_integer(10)));
 
-		// offchain worker will not liquidate alice
-		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
-		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 100));
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
+		assert_noop!(
+			CDPEngineModule::deleverage_cdp_by_dex(ALICE, BTC, Ratio::saturating_from_integer(4)),
+			Error::<Runtime>::NoDebitValue
+		);
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 3000));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 300);
+
+		MockPriceSource::set_relative_price(None);
+		assert_noop!(
+			CDPEngineModule::deleverage_cdp_by_dex(ALICE, BTC, Ratio::saturating_from_integer(4)),
+			Error::<Runtime>::MustBeSafe
+		);
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_integer(10)));
+
+		// repay 100 debit value to bring the collateral ratio from 3.33 back above 4
+		assert_ok!(CDPEngineModule::deleverage_cdp_by_dex(
+			ALICE,
+			BTC,
+			Ratio::saturating_from_integer(4)
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::DeleverageCDPByDEX(
+			BTC, ALICE, 12, 100,
+		)));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (112, 900));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 300);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 2000);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 88);
+		assert_eq!(CDPTreasuryModule::get_surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::get_debit_pool(), 100);
+	});
+}
+
+#[test]
+fn offchain_worker_works_cdp() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		// number of currencies allowed as collateral (cycles through all of them)
+		let collateral_currencies_num = CollateralCurrencyIds::get().len() as u64;
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+
+		// offchain worker will not liquidate alice
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 100));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
 		// jump 2 blocks at a time because code rotates through the different T::CollateralCurrencyIds
 		run_to_block_offchain(System::block_number() + collateral_currencies_num);
@@ -989,6 +1618,7 @@ fn offchain_worker_works_cdp() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(System::block_number() + collateral_currencies_num);
 
@@ -1030,6 +1660,77 @@ fn offchain_worker_works_cdp() {
 	});
 }
 
+#[test]
+fn offchain_worker_submit_partial_liquidation() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		let collateral_currencies_num = CollateralCurrencyIds::get().len() as u64;
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 4))),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 1000, 2000));
+
+		// changes alice into unsafe position
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_integer(6))),
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(32, 5))),
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// the first liquidation is limited by close factor
+		run_to_block_offchain(System::block_number() + collateral_currencies_num);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		if let MockCall::CDPEngineModule(crate::Call::liquidate {
+			currency_id: currency_call,
+			who: who_call,
+		}) = tx.call
+		{
+			assert_ok!(CDPEngineModule::liquidate(Origin::none(), currency_call, who_call));
+		}
+		assert!(pool_state.write().transactions.pop().is_none());
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 1500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 880);
+
+		// alice is still unsafe, the second liquidation brings it back to required collateral ratio
+		run_to_block_offchain(System::block_number() + collateral_currencies_num);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		if let MockCall::CDPEngineModule(crate::Call::liquidate {
+			currency_id: currency_call,
+			who: who_call,
+		}) = tx.call
+		{
+			assert_ok!(CDPEngineModule::liquidate(Origin::none(), currency_call, who_call));
+		}
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 1300);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 832);
+
+		// alice is safe now
+		run_to_block_offchain(System::block_number() + collateral_currencies_num);
+		assert!(pool_state.write().transactions.pop().is_none());
+	});
+}
+
 #[test]
 fn offchain_worker_iteration_limit_works() {
 	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -1051,6 +1752,7 @@ fn offchain_worker_iteration_limit_works() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
@@ -1064,6 +1766,7 @@ fn offchain_worker_iteration_limit_works() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(2);
 		let tx = pool_state.write().transactions.pop().unwrap();
@@ -1117,6 +1820,7 @@ fn offchain_default_max_iterator_works() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		// checks that max iterations is stored as none
 		assert!(offchain
@@ -1137,6 +1841,7 @@ fn offchain_default_max_iterator_works() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(2);
 		// should only run 1000 iterations stopping due to DEFAULT_MAX_ITERATIONS
@@ -1146,3 +1851,301 @@ fn offchain_default_max_iterator_works() {
 		assert_eq!(pool_state.write().transactions.len(), 1001);
 	});
 }
+
+#[test]
+fn set_basket_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_basket_params(Origin::signed(5), Change::NoChange, Change::NoChange, Change::NoChange),
+			BadOrigin
+		);
+		assert_eq!(CDPEngineModule::basket_params(), Default::default());
+		assert_eq!(
+			CDPEngineModule::get_basket_liquidation_penalty(),
+			DefaultLiquidationPenalty::get()
+		);
+
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(10000),
+		));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::BasketInterestRatePerSecUpdated(
+			Some(Rate::saturating_from_rational(1, 100000)),
+		)));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::BasketLiquidationPenaltyUpdated(
+			Some(Rate::saturating_from_rational(2, 10)),
+		)));
+		System::assert_has_event(Event::CDPEngineModule(
+			crate::Event::BasketMaximumTotalDebitValueUpdated(10000),
+		));
+		assert_eq!(
+			CDPEngineModule::basket_params(),
+			BasketRiskManagementParams {
+				maximum_total_debit_value: 10000,
+				interest_rate_per_sec: Some(Rate::saturating_from_rational(1, 100000)),
+				liquidation_penalty: Some(Rate::saturating_from_rational(2, 10)),
+			}
+		);
+		assert_eq!(
+			CDPEngineModule::get_basket_interest_rate_per_sec(),
+			Rate::saturating_from_rational(1, 100000)
+		);
+		assert_eq!(
+			CDPEngineModule::get_basket_liquidation_penalty(),
+			Rate::saturating_from_rational(2, 10)
+		);
+	});
+}
+
+#[test]
+fn adjust_basket_position_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockPriceSource::set_dot_relative_price(Some(Price::one()));
+		assert_noop!(
+			CDPEngineModule::adjust_basket_position(&ALICE, &[(BTC, 100), (ACA, 100)], 500),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+
+		// basket CDPs are disabled until the hard cap is set
+		assert_noop!(
+			CDPEngineModule::adjust_basket_position(&ALICE, &[(BTC, 100), (DOT, 100)], 500),
+			Error::<Runtime>::ExceedDebitValueHardCap,
+		);
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+
+		// the risk-weighted collateral value is 100 / 1.5 + 100 / 1.5 = 132
+		assert_noop!(
+			CDPEngineModule::adjust_basket_position(&ALICE, &[(BTC, 100), (DOT, 100)], 1330),
+			Error::<Runtime>::BelowRequiredCollateralRatio,
+		);
+		assert_ok!(CDPEngineModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1320
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 132);
+		assert_eq!(
+			LoansModule::basket_positions(ALICE).collateral_amounts(),
+			vec![(DOT, 100), (BTC, 100)]
+		);
+		assert_eq!(LoansModule::basket_positions(ALICE).debit, 1320);
+
+		// the same debit can not be backed by the isolated CDP
+		assert_noop!(
+			CDPEngineModule::adjust_position(&BOB, BTC, 100, 1320),
+			Error::<Runtime>::BelowLiquidationRatio,
+		);
+
+		assert_eq!(
+			CDPEngineModule::calculate_basket_collateral_ratio(&[(DOT, 100), (BTC, 100)], 1320),
+			Some(Ratio::one())
+		);
+		assert_eq!(
+			CDPEngineModule::check_basket_cdp_status(&[(DOT, 100), (BTC, 100)], 1320),
+			CDPStatus::Safe
+		);
+		assert_eq!(
+			CDPEngineModule::check_basket_cdp_status(&[(DOT, 100), (BTC, 100)], 1330),
+			CDPStatus::Unsafe
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_basket_position(&ALICE, &[(DOT, -100)], 0),
+			Error::<Runtime>::BelowRequiredCollateralRatio,
+		);
+
+		// the frozen collateral can not be withdrawn even if the debit is repaid
+		mock_freeze_collateral(DOT);
+		assert_noop!(
+			CDPEngineModule::adjust_basket_position(&ALICE, &[(DOT, -10)], -100),
+			Error::<Runtime>::CollateralFrozen,
+		);
+		assert_ok!(CDPEngineModule::adjust_basket_position(&ALICE, &[(BTC, -10)], -100));
+		mock_unfreeze_collateral(DOT);
+
+		MockPriceSource::set_dot_relative_price(None);
+		assert_eq!(
+			CDPEngineModule::check_basket_cdp_status(&[(DOT, 100), (BTC, 100)], 1320),
+			CDPStatus::ChecksFailed(Error::<Runtime>::InvalidFeedPrice.into())
+		);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_basket_cdp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MockPriceSource::set_dot_relative_price(Some(Price::one()));
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1000
+		));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_basket_cdp(ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		// the risk-weighted collateral value is 100 / 3 + 100 / 1.5 = 99
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_basket(Origin::none(), ALICE));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeBasketCDP(
+			ALICE,
+			100,
+			vec![
+				(DOT, 100, LiquidationStrategy::Auction { auction_count: 1 }),
+				(BTC, 100, LiquidationStrategy::Auction { auction_count: 1 }),
+			],
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_eq!(LoansModule::basket_positions(ALICE), Default::default());
+		assert_eq!(LoansModule::total_basket_positions(), Default::default());
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::liquidate_basket(Origin::none(), ALICE),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
+#[test]
+fn settle_basket_cdp_has_debit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MockPriceSource::set_dot_relative_price(Some(Price::one()));
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			0
+		));
+		assert_noop!(
+			CDPEngineModule::settle_basket_cdp_has_debit(ALICE),
+			Error::<Runtime>::NoDebitValue,
+		);
+		assert_ok!(CDPEngineModule::adjust_basket_position(&ALICE, &[], 1200));
+		assert_noop!(
+			CDPEngineModule::settle_basket(Origin::none(), ALICE),
+			Error::<Runtime>::MustAfterShutdown
+		);
+
+		mock_shutdown();
+		assert_ok!(CDPEngineModule::settle_basket(Origin::none(), ALICE));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::SettleBasketCDPInDebit(ALICE)));
+		assert_eq!(
+			LoansModule::basket_positions(ALICE).collateral_amounts(),
+			vec![(BTC, 80)]
+		);
+		assert_eq!(LoansModule::basket_positions(ALICE).debit, 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 120);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 100);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 20);
+	});
+}
+
+#[test]
+fn offchain_worker_works_basket_cdp() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		MockPriceSource::set_dot_relative_price(Some(Price::one()));
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 100), (DOT, 100)],
+			1000
+		));
+		assert_ok!(CDPEngineModule::adjust_basket_position(
+			&BOB,
+			&[(BTC, 100), (DOT, 100)],
+			500
+		));
+
+		// offchain worker will not liquidate alice
+		run_to_block_offchain(2);
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		// changes alice into unsafe position
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		run_to_block_offchain(3);
+
+		// offchain worker will liquidate alice
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		if let MockCall::CDPEngineModule(crate::Call::liquidate_basket { who }) = tx.call {
+			assert_ok!(CDPEngineModule::liquidate_basket(Origin::none(), who));
+		}
+		// empty offchain tx pool (Bob was not liquidated)
+		assert!(pool_state.write().transactions.pop().is_none());
+		assert_eq!(LoansModule::basket_positions(ALICE), Default::default());
+		assert_eq!(LoansModule::basket_positions(BOB).debit, 500);
+
+		// emergency shutdown will settle Bobs debit position
+		mock_shutdown();
+		run_to_block_offchain(4);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		if let MockCall::CDPEngineModule(crate::Call::settle_basket { who }) = tx.call {
+			assert_ok!(CDPEngineModule::settle_basket(Origin::none(), who));
+		}
+		assert_eq!(LoansModule::basket_positions(BOB).debit, 0);
+		assert_eq!(
+			LoansModule::basket_positions(BOB).collateral_amounts(),
+			vec![(DOT, 50), (BTC, 100)]
+		);
+	});
+}
@@ -52,7 +52,14 @@ pub trait WeightInfo {
 	fn set_global_params() -> Weight;
 	fn liquidate_by_auction(b: u32) -> Weight;
 	fn liquidate_by_dex() -> Weight;
+	fn liquidate_by_evm_contract() -> Weight;
+	fn liquidate_by_stability_pool() -> Weight;
 	fn settle() -> Weight;
+	fn set_liquidation_strategies() -> Weight;
+	fn set_basket_params() -> Weight;
+	fn settle_basket() -> Weight;
+	fn set_interest_rate_controller() -> Weight;
+	fn adjust_global_interest_rate() -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -83,11 +90,51 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(29 as Weight))
 			.saturating_add(T::DbWeight::get().writes(15 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_evm_contract() -> Weight {
+		(268000000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(31 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_stability_pool() -> Weight {
+		(214000000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_liquidation_strategies() -> Weight {
+		(17_420_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_basket_params() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_basket() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_interest_rate_controller() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_global_interest_rate() -> Weight {
+		(31_274_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -117,9 +164,49 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(29 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_evm_contract() -> Weight {
+		(268000000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(31 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_stability_pool() -> Weight {
+		(214000000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(27 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
 	fn settle() -> Weight {
 		(97_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_liquidation_strategies() -> Weight {
+		(17_420_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_basket_params() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_basket() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_interest_rate_controller() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_global_interest_rate() -> Weight {
+		(31_274_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
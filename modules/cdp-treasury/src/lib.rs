@@ -32,9 +32,10 @@ use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::convert::TryInto;
 use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio};
@@ -46,6 +47,22 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The mode of collateral auction
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum CollateralAuctionMode {
+	/// English auction, bidders raise the bid price until the auction ends
+	English,
+	/// Dutch auction, the price descends over blocks and any taker can buy
+	/// the collateral instantly
+	Dutch,
+}
+
+impl Default for CollateralAuctionMode {
+	fn default() -> Self {
+		CollateralAuctionMode::English
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -108,6 +125,20 @@ pub mod module {
 		/// The expected amount size for per lot collateral auction of specific
 		/// collateral type updated. \[collateral_type, new_size\]
 		ExpectedCollateralAuctionSizeUpdated(CurrencyId, Balance),
+		/// The collateral auction mode of specific collateral type updated.
+		/// \[collateral_type, new_mode\]
+		CollateralAuctionModeUpdated(CurrencyId, CollateralAuctionMode),
+		/// The buffer size of surplus pool updated. \[new_size\]
+		SurplusBufferSizeUpdated(Balance),
+		/// The fixed amount of stable currency for sale per surplus auction
+		/// updated. \[new_size\]
+		SurplusAuctionFixedSizeUpdated(Balance),
+		/// The fixed amount of stable currency to raise per debit auction
+		/// updated. \[new_size\]
+		DebitAuctionFixedSizeUpdated(Balance),
+		/// The initial amount of native currency for sale per debit auction
+		/// updated. \[new_amount\]
+		InitialAmountPerDebitAuctionUpdated(Balance),
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -126,6 +157,43 @@ pub mod module {
 	#[pallet::getter(fn debit_pool)]
 	pub type DebitPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The mode of collateral auctions created for specific collateral type.
+	///
+	/// CollateralAuctionModes: map CurrencyId => CollateralAuctionMode
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_auction_modes)]
+	pub type CollateralAuctionModes<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CollateralAuctionMode, ValueQuery>;
+
+	/// The buffer size of surplus pool, the system will process the surplus
+	/// through surplus auction when surplus is over this value.
+	///
+	/// SurplusBufferSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_buffer_size)]
+	pub type SurplusBufferSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of stable currency for sale per surplus auction.
+	///
+	/// SurplusAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auction_fixed_size)]
+	pub type SurplusAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of stable currency to raise per debit auction.
+	///
+	/// DebitAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auction_fixed_size)]
+	pub type DebitAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The initial amount of native currency for sale per debit auction.
+	///
+	/// InitialAmountPerDebitAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn initial_amount_per_debit_auction)]
+	pub type InitialAmountPerDebitAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[cfg_attr(feature = "std", derive(Default))]
 	pub struct GenesisConfig {
@@ -148,9 +216,16 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		/// Handle excessive surplus or debits of system when block end
+		/// Create surplus or debit auctions for the remainder over thresholds
+		/// when block begin
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let created_lots = Self::create_surplus_and_debit_auctions();
+			T::WeightInfo::on_initialize(created_lots)
+		}
+
+		/// Offset the same amount between debit pool and surplus pool when
+		/// block end
 		fn on_finalize(_now: T::BlockNumber) {
-			// offset the same amount between debit pool and surplus pool
 			Self::offset_surplus_and_debit();
 		}
 	}
@@ -209,6 +284,67 @@ pub mod module {
 			Self::deposit_event(Event::ExpectedCollateralAuctionSizeUpdated(currency_id, size));
 			Ok(())
 		}
+
+		/// Update the mode of collateral auctions under specific collateral
+		/// type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `mode`: the mode of collateral auctions created later
+		#[pallet::weight((T::WeightInfo::set_collateral_auction_mode(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_collateral_auction_mode(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			mode: CollateralAuctionMode,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CollateralAuctionModes::<T>::insert(currency_id, mode);
+			Self::deposit_event(Event::CollateralAuctionModeUpdated(currency_id, mode));
+			Ok(())
+		}
+
+		/// Update parameters related to surplus and debit auctions
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `surplus_buffer_size`: surplus buffer size, `None` means do not update
+		/// - `surplus_auction_fixed_size`: fixed stable amount for sale per surplus auction, `None`
+		///   means do not update
+		/// - `debit_auction_fixed_size`: fixed stable amount to raise per debit auction, `None`
+		///   means do not update
+		/// - `initial_amount_per_debit_auction`: initial native amount for sale per debit auction,
+		///   `None` means do not update
+		#[pallet::weight((T::WeightInfo::set_debit_and_surplus_handle_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_debit_and_surplus_handle_params(
+			origin: OriginFor<T>,
+			surplus_buffer_size: Option<Balance>,
+			surplus_auction_fixed_size: Option<Balance>,
+			debit_auction_fixed_size: Option<Balance>,
+			initial_amount_per_debit_auction: Option<Balance>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(amount) = surplus_buffer_size {
+				SurplusBufferSize::<T>::put(amount);
+				Self::deposit_event(Event::SurplusBufferSizeUpdated(amount));
+			}
+			if let Some(amount) = surplus_auction_fixed_size {
+				SurplusAuctionFixedSize::<T>::put(amount);
+				Self::deposit_event(Event::SurplusAuctionFixedSizeUpdated(amount));
+			}
+			if let Some(amount) = debit_auction_fixed_size {
+				DebitAuctionFixedSize::<T>::put(amount);
+				Self::deposit_event(Event::DebitAuctionFixedSizeUpdated(amount));
+			}
+			if let Some(amount) = initial_amount_per_debit_auction {
+				InitialAmountPerDebitAuction::<T>::put(amount);
+				Self::deposit_event(Event::InitialAmountPerDebitAuctionUpdated(amount));
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -234,8 +370,14 @@ impl<T: Config> Pallet<T> {
 			.saturating_sub(T::AuctionManagerHandler::get_total_collateral_in_auction(currency_id))
 	}
 
+	/// Get surplus amount which is not in surplus auction
+	pub fn surplus_pool_not_in_auction() -> Balance {
+		Self::surplus_pool().saturating_sub(T::AuctionManagerHandler::get_total_surplus_in_auction())
+	}
+
 	fn offset_surplus_and_debit() {
-		let offset_amount = sp_std::cmp::min(Self::debit_pool(), Self::surplus_pool());
+		// the surplus in auction is reserved for the winners of surplus auctions
+		let offset_amount = sp_std::cmp::min(Self::debit_pool(), Self::surplus_pool_not_in_auction());
 
 		// Burn the amount that is equal to offset amount of stable currency.
 		if !offset_amount.is_zero() {
@@ -258,6 +400,48 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	/// Create surplus auctions for the surplus over the buffer size, and debit
+	/// auctions for the debit which is not in auction, by fixed size lots.
+	/// The total lots created in one block are capped by `MaxAuctionsCount`.
+	/// Return the count of created lots.
+	fn create_surplus_and_debit_auctions() -> u32 {
+		let max_auctions_count = T::MaxAuctionsCount::get();
+		let mut created_lots: u32 = 0;
+
+		let surplus_auction_fixed_size = Self::surplus_auction_fixed_size();
+		if !surplus_auction_fixed_size.is_zero() {
+			let mut remain_surplus = Self::surplus_pool_not_in_auction().saturating_sub(Self::surplus_buffer_size());
+			while remain_surplus >= surplus_auction_fixed_size && created_lots < max_auctions_count {
+				if T::AuctionManagerHandler::new_surplus_auction(surplus_auction_fixed_size).is_err() {
+					break;
+				}
+				remain_surplus = remain_surplus.saturating_sub(surplus_auction_fixed_size);
+				created_lots = created_lots.saturating_add(1);
+			}
+		}
+
+		let debit_auction_fixed_size = Self::debit_auction_fixed_size();
+		let initial_amount_per_debit_auction = Self::initial_amount_per_debit_auction();
+		if !debit_auction_fixed_size.is_zero() && !initial_amount_per_debit_auction.is_zero() {
+			let mut remain_debit =
+				Self::debit_pool().saturating_sub(T::AuctionManagerHandler::get_total_debit_in_auction());
+			while remain_debit >= debit_auction_fixed_size && created_lots < max_auctions_count {
+				if T::AuctionManagerHandler::new_debit_auction(
+					initial_amount_per_debit_auction,
+					debit_auction_fixed_size,
+				)
+				.is_err()
+				{
+					break;
+				}
+				remain_debit = remain_debit.saturating_sub(debit_auction_fixed_size);
+				created_lots = created_lots.saturating_add(1);
+			}
+		}
+
+		created_lots
+	}
 }
 
 impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
@@ -310,6 +494,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		T::Currency::transfer(T::GetStableCurrencyId::get(), from, &Self::account_id(), surplus)
 	}
 
+	fn withdraw_surplus(to: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), to, surplus)
+	}
+
 	fn deposit_collateral(from: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)
 	}
@@ -424,6 +612,7 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		let average_amount_per_lot = amount.checked_div(lots_count).expect("lots count is at least 1; qed");
 		let average_target_per_lot = target.checked_div(lots_count).expect("lots count is at least 1; qed");
 		let mut created_lots: Balance = Zero::zero();
+		let mode = Self::collateral_auction_modes(currency_id);
 
 		while !unhandled_collateral_amount.is_zero() {
 			created_lots = created_lots.saturating_add(One::one());
@@ -434,12 +623,20 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 				(average_amount_per_lot, average_target_per_lot)
 			};
 
-			T::AuctionManagerHandler::new_collateral_auction(
-				&refund_receiver,
-				currency_id,
-				lot_collateral_amount,
-				lot_target,
-			)?;
+			match mode {
+				CollateralAuctionMode::English => T::AuctionManagerHandler::new_collateral_auction(
+					&refund_receiver,
+					currency_id,
+					lot_collateral_amount,
+					lot_target,
+				)?,
+				CollateralAuctionMode::Dutch => T::AuctionManagerHandler::new_dutch_collateral_auction(
+					&refund_receiver,
+					currency_id,
+					lot_collateral_amount,
+					lot_target,
+				)?,
+			}
 
 			unhandled_collateral_amount = unhandled_collateral_amount.saturating_sub(lot_collateral_amount);
 			unhandled_target = unhandled_target.saturating_sub(lot_target);
@@ -99,6 +99,57 @@ fn offset_surplus_and_debit_on_finalize_work() {
 	});
 }
 
+#[test]
+fn create_surplus_and_debit_auctions_on_initialize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_eq!(
+			CDPTreasuryModule::on_initialize(1),
+			<() as WeightInfo>::on_initialize(0)
+		);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 0);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Some(100),
+			Some(200),
+			Some(100),
+			Some(300),
+		));
+		assert_eq!(
+			CDPTreasuryModule::on_initialize(2),
+			<() as WeightInfo>::on_initialize(4)
+		);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut()), 800);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
+
+		CDPTreasuryModule::on_initialize(3);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 4);
+
+		// the surplus in auction will not be offset by debit
+		assert_ok!(CDPTreasuryModule::on_system_debit(1200));
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 800);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 1000);
+
+		// the lots created in one block are capped by MaxAuctionsCount
+		assert_eq!(
+			CDPTreasuryModule::on_initialize(4),
+			<() as WeightInfo>::on_initialize(5)
+		);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 5);
+		assert_eq!(TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut()), 500);
+
+		CDPTreasuryModule::on_initialize(5);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 10);
+		assert_eq!(TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut()), 1000);
+
+		CDPTreasuryModule::on_initialize(6);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 10);
+	});
+}
+
 #[test]
 fn issue_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -139,6 +190,21 @@ fn deposit_surplus_work() {
 	});
 }
 
+#[test]
+fn withdraw_surplus_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 500);
+		assert_noop!(
+			CDPTreasuryModule::withdraw_surplus(&ALICE, 501),
+			orml_tokens::Error::<Runtime>::BalanceTooLow,
+		);
+		assert_ok!(CDPTreasuryModule::withdraw_surplus(&ALICE, 300));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1300);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+	});
+}
+
 #[test]
 fn deposit_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -388,6 +454,20 @@ fn create_collateral_auctions_work() {
 		));
 		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 11);
 		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 4200);
+
+		// create dutch collateral auctions
+		// dutch auction + 2
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_mode(
+			Origin::signed(1),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 500, 1000, ALICE, true
+		));
+		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 11);
+		assert_eq!(TOTAL_DUTCH_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 2);
+		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 4700);
 	});
 }
 
@@ -411,6 +491,82 @@ fn set_expected_collateral_auction_size_work() {
 	});
 }
 
+#[test]
+fn set_collateral_auction_mode_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			CDPTreasuryModule::collateral_auction_modes(BTC),
+			CollateralAuctionMode::English
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_collateral_auction_mode(Origin::signed(5), BTC, CollateralAuctionMode::Dutch),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_mode(
+			Origin::signed(1),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::CollateralAuctionModeUpdated(
+			BTC,
+			CollateralAuctionMode::Dutch,
+		)));
+		assert_eq!(
+			CDPTreasuryModule::collateral_auction_modes(BTC),
+			CollateralAuctionMode::Dutch
+		);
+	});
+}
+
+#[test]
+fn set_debit_and_surplus_handle_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPTreasuryModule::set_debit_and_surplus_handle_params(
+				Origin::signed(5),
+				Some(100),
+				Some(200),
+				Some(300),
+				Some(400),
+			),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Some(100),
+			Some(200),
+			Some(300),
+			Some(400),
+		));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusBufferSizeUpdated(100)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusAuctionFixedSizeUpdated(
+			200,
+		)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::DebitAuctionFixedSizeUpdated(
+			300,
+		)));
+		System::assert_last_event(Event::CDPTreasuryModule(
+			crate::Event::InitialAmountPerDebitAuctionUpdated(400),
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_auction_fixed_size(), 200);
+		assert_eq!(CDPTreasuryModule::debit_auction_fixed_size(), 300);
+		assert_eq!(CDPTreasuryModule::initial_amount_per_debit_auction(), 400);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			None,
+			Some(0),
+			None,
+			None,
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_auction_fixed_size(), 0);
+	});
+}
+
 #[test]
 fn extract_surplus_to_treasury_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -48,14 +48,25 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for module_cdp_treasury.
 pub trait WeightInfo {
+	fn on_initialize(c: u32) -> Weight;
 	fn extract_surplus_to_treasury() -> Weight;
 	fn auction_collateral(b: u32) -> Weight;
 	fn set_expected_collateral_auction_size() -> Weight;
+	fn set_collateral_auction_mode() -> Weight;
+	fn set_debit_and_surplus_handle_params() -> Weight;
 }
 
 /// Weights for module_cdp_treasury using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn on_initialize(c: u32) -> Weight {
+		(8_000_000 as Weight)
+			.saturating_add((32_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
+	}
 	fn extract_surplus_to_treasury() -> Weight {
 		(124_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -70,10 +81,28 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(14_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_collateral_auction_mode() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn on_initialize(c: u32) -> Weight {
+		(8_000_000 as Weight)
+			.saturating_add((32_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
+	}
 	fn extract_surplus_to_treasury() -> Weight {
 		(124_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
@@ -88,4 +117,14 @@ impl WeightInfo for () {
 		(14_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_collateral_auction_mode() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
 }
@@ -0,0 +1,257 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the dex module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_dex_rpc_runtime_api::{BalanceInfo, LiquidityPoolInfo, ProvisioningInfo, SwapPathInfo};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as DexClient;
+pub use module_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc]
+pub trait DexApi<BlockHash, Balance, BlockNumber> {
+	#[rpc(name = "dex_getSwapTargetAmount")]
+	fn get_swap_target_amount(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<BalanceInfo<Balance>>>;
+
+	#[rpc(name = "dex_getSwapSupplyAmount")]
+	fn get_swap_supply_amount(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<BalanceInfo<Balance>>>;
+
+	#[rpc(name = "dex_getBestSwapWithExactSupply")]
+	fn get_best_swap_with_exact_supply(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<SwapPathInfo<Balance>>>;
+
+	#[rpc(name = "dex_getBestSwapWithExactTarget")]
+	fn get_best_swap_with_exact_target(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<Option<SwapPathInfo<Balance>>>;
+
+	#[rpc(name = "dex_getLiquidityPool")]
+	fn get_liquidity_pool(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<LiquidityPoolInfo<Balance>>;
+
+	#[rpc(name = "dex_getProvisioningStatus")]
+	fn get_provisioning_status(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Option<ProvisioningInfo<Balance, BlockNumber>>>;
+
+	#[rpc(name = "dex_getLiquidityShareValue")]
+	fn get_liquidity_share_value(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		share_amount: Balance,
+		at: Option<BlockHash>,
+	) -> Result<LiquidityPoolInfo<Balance>>;
+}
+
+/// A struct that implements the [`DexApi`].
+pub struct Dex<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Dex<C, B> {
+	/// Create new `Dex` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Dex {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, Balance, BlockNumber> DexApi<<Block as BlockT>::Hash, Balance, BlockNumber> for Dex<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DexRuntimeApi<Block, Balance, BlockNumber>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+	BlockNumber: Codec,
+{
+	fn get_swap_target_amount(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<BalanceInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_swap_target_amount(&at, path, supply_amount)
+			.map_err(|e| runtime_error("Unable to get swap target amount.", e))
+	}
+
+	fn get_swap_supply_amount(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<BalanceInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_swap_supply_amount(&at, path, target_amount)
+			.map_err(|e| runtime_error("Unable to get swap supply amount.", e))
+	}
+
+	fn get_best_swap_with_exact_supply(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapPathInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_best_swap_with_exact_supply(&at, supply_currency_id, target_currency_id, supply_amount)
+			.map_err(|e| runtime_error("Unable to get best swap with exact supply.", e))
+	}
+
+	fn get_best_swap_with_exact_target(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapPathInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_best_swap_with_exact_target(&at, supply_currency_id, target_currency_id, target_amount)
+			.map_err(|e| runtime_error("Unable to get best swap with exact target.", e))
+	}
+
+	fn get_liquidity_pool(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<LiquidityPoolInfo<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_liquidity_pool(&at, currency_id_a, currency_id_b)
+			.map_err(|e| runtime_error("Unable to get liquidity pool.", e))
+	}
+
+	fn get_provisioning_status(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ProvisioningInfo<Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_provisioning_status(&at, currency_id_a, currency_id_b)
+			.map_err(|e| runtime_error("Unable to get provisioning status.", e))
+	}
+
+	fn get_liquidity_share_value(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		share_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<LiquidityPoolInfo<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_liquidity_share_value(&at, currency_id_a, currency_id_b, share_amount)
+			.map_err(|e| runtime_error("Unable to get liquidity share value.", e))
+	}
+}
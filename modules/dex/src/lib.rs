@@ -21,11 +21,13 @@
 //! ## Overview
 //!
 //! Built-in decentralized exchange modules in Acala network, the swap
-//! mechanism refers to the design of Uniswap V2. In addition to being used for
-//! trading, DEX also participates in CDP liquidation, which is faster than
-//! liquidation by auction when the liquidity is sufficient. And providing
-//! market making liquidity for DEX will also receive stable currency as
-//! additional reward for its participation in the CDP liquidation.
+//! mechanism refers to the design of Uniswap V2, and trading pairs of
+//! correlated currencies can use the StableSwap curve of Curve instead. In
+//! addition to being used for trading, DEX also participates in CDP
+//! liquidation, which is faster than liquidation by auction when the liquidity
+//! is sufficient. And providing market making liquidity for DEX will also
+//! receive stable currency as additional reward for its participation in the
+//! CDP liquidation.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::too_many_arguments)]
@@ -34,18 +36,36 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
-use frame_system::pallet_prelude::*;
-use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	log,
+	pallet_prelude::*,
+	transactional, PalletId,
+};
+use frame_system::{
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::*,
+};
+use module_dex_rpc_runtime_api::{BalanceInfo, LiquidityPoolInfo, ProvisioningInfo, SwapPathInfo};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency};
+use orml_utilities::OffchainErr;
 use primitives::{Balance, CurrencyId, TradingPair};
 use scale_info::TypeInfo;
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	offchain::{
+		storage::StorageValueRef,
+		storage_lock::{StorageLock, Time},
+		Duration,
+	},
+	traits::{AccountIdConversion, CheckedMul, One, StaticLookup, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+	},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
 };
-use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio};
+use sp_std::{collections::vec_deque::VecDeque, convert::TryInto, marker::PhantomData, prelude::*, vec};
+use support::{DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Price, PriceProvider, Ratio};
 
 mod mock;
 mod tests;
@@ -54,6 +74,19 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+pub const OFFCHAIN_WORKER_DATA: &[u8] = b"acala/dex/data/";
+pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/dex/lock/";
+pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/dex/max-iterations/";
+pub const LOCK_DURATION: u64 = 100;
+pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
+
+pub type LimitOrderId = u64;
+
+/// The maximum amplification coefficient of StableSwap curve.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+/// The maximum iterations of Newton's method when solving StableSwap invariant.
+const STABLE_SWAP_MAX_ITERATIONS: u32 = 255;
+
 /// Parameters of TradingPair in Provisioning status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
 pub struct ProvisioningParameters<Balance, BlockNumber> {
@@ -87,16 +120,67 @@ impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
 	}
 }
 
+/// The curve used to price the swaps of TradingPair.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum SwapCurve {
+	/// Constant product formula (x * y = k) of Uniswap V2.
+	ConstantProduct,
+	/// StableSwap invariant of Curve with the amplification coefficient,
+	/// more efficient for correlated currencies. The pool amounts are
+	/// treated as equal in value, so the currencies should share the same
+	/// decimals.
+	StableSwap { amplification: u32 },
+}
+
+impl Default for SwapCurve {
+	fn default() -> Self {
+		Self::ConstantProduct
+	}
+}
+
+/// Cumulative prices of TradingPair, the prices are accumulated by the number
+/// of blocks they last, used to calculate time-weighted average prices.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, MaxEncodedLen, TypeInfo)]
+pub struct CumulativePrice<BlockNumber> {
+	/// accumulated inner value of the price of currency_0 in currency_1,
+	/// wrapping on overflow.
+	pub price_0_cumulative: u128,
+	/// accumulated inner value of the price of currency_1 in currency_0,
+	/// wrapping on overflow.
+	pub price_1_cumulative: u128,
+	/// the block number at which the cumulative prices were last updated.
+	pub last_updated: BlockNumber,
+}
+
+/// Limit order, the reserved supply amount will be swapped once DEX can
+/// provide at least `min_target_amount` for it.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct LimitOrder<BlockNumber> {
+	/// currency to supply.
+	pub supply_currency_id: CurrencyId,
+	/// currency to receive.
+	pub target_currency_id: CurrencyId,
+	/// the reserved amount of supply currency.
+	pub supply_amount: Balance,
+	/// acceptable minimum target amount, which determines the limit price.
+	pub min_target_amount: Balance,
+	/// the order can not be filled after this block.
+	pub expiry: BlockNumber,
+	/// the reserved deposit of native currency.
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// Currency for transfer currencies
-		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		/// Currency for transfer and reserve currencies
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId>;
 
 		/// Trading fee rate
 		/// The first item of the tuple is the numerator of the fee rate, second
@@ -110,10 +194,49 @@ pub mod module {
 		#[pallet::constant]
 		type TradingPathLimit: Get<u32>;
 
+		/// The number of parts the supply amount is split into when swapping
+		/// through multiple trading paths.
+		#[pallet::constant]
+		type SwapSplitParts: Get<u32>;
+
+		/// The maximum number of Enabled trading pairs, all of them are read
+		/// when searching the trading paths between two currencies.
+		#[pallet::constant]
+		type MaxSearchedTradingPairs: Get<u32>;
+
+		/// The maximum number of trading paths evaluated when searching the
+		/// best trading path between two currencies, which bounds the cost of
+		/// the search.
+		#[pallet::constant]
+		type MaxSearchedTradingPaths: Get<u32>;
+
+		/// The minimum number of blocks over which the time-weighted average
+		/// price of TradingPair is calculated.
+		#[pallet::constant]
+		type TwapWindow: Get<Self::BlockNumber>;
+
+		/// The maximum number of blocks since the last update for which the
+		/// time-weighted average price of TradingPair is still valid.
+		#[pallet::constant]
+		type TwapMaxAge: Get<Self::BlockNumber>;
+
 		/// The DEX's module id, keep all assets in DEX.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The native currency id, in which the deposit of limit order is
+		/// reserved.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The deposit reserved for each open limit order.
+		#[pallet::constant]
+		type LimitOrderDeposit: Get<Balance>;
+
+		/// The maximum number of open limit orders per account.
+		#[pallet::constant]
+		type MaxLimitOrdersPerAccount: Get<u32>;
+
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20
 		/// address as LP token.
 		type Erc20InfoMapping: Erc20InfoMapping;
@@ -126,6 +249,22 @@ pub mod module {
 
 		/// The origin which may list, enable or disable trading pairs.
 		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple modules send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// The account to receive the protocol share of trading fees, such as
+		/// the treasury account or the CDP treasury account.
+		#[pallet::constant]
+		type ProtocolFeeReceiver: Get<Self::AccountId>;
+
+		/// The overarching call type, used to dispatch the nested call of
+		/// flash swap.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo> + GetDispatchInfo;
 	}
 
 	#[pallet::error]
@@ -168,6 +307,30 @@ pub mod module {
 		UnqualifiedProvision,
 		/// Trading pair is still provisioning
 		StillProvisioning,
+		/// There is no available trading path between supply and target currency
+		NoAvailableTradingPath,
+		/// The expiry of limit order is invalid
+		InvalidExpiry,
+		/// The limit order is not found
+		LimitOrderNotFound,
+		/// The limit order is already expired
+		LimitOrderExpired,
+		/// The limit order is not expired yet
+		LimitOrderNotExpired,
+		/// The account has too many open limit orders
+		TooManyLimitOrders,
+		/// The exchange fee rate is invalid
+		InvalidExchangeFee,
+		/// The protocol fee share is invalid
+		InvalidProtocolFeeShare,
+		/// The swap curve is invalid
+		InvalidSwapCurve,
+		/// The swap curve can not be changed while the pool has liquidity
+		CannotChangeSwapCurve,
+		/// The flash swap is not repaid at the end of the transaction
+		FlashSwapNotRepaid,
+		/// The number of Enabled trading pairs exceeds `MaxSearchedTradingPairs`
+		TooManyEnabledTradingPairs,
 	}
 
 	#[pallet::event]
@@ -195,6 +358,39 @@ pub mod module {
 		/// Provisioning trading pair convert to Enabled. \[trading_pair,
 		/// pool_0_amount, pool_1_amount, total_share_amount\]
 		ProvisioningToEnabled(TradingPair, Balance, Balance, Balance),
+		/// Place limit order and reserve the supply amount. \[owner, order_id,
+		/// supply_currency_id, target_currency_id, supply_amount,
+		/// min_target_amount, expiry\]
+		PlaceLimitOrder(
+			T::AccountId,
+			LimitOrderId,
+			CurrencyId,
+			CurrencyId,
+			Balance,
+			Balance,
+			T::BlockNumber,
+		),
+		/// Cancel limit order and unreserve the supply amount. \[owner,
+		/// order_id\]
+		CancelLimitOrder(T::AccountId, LimitOrderId),
+		/// Limit order is filled. \[owner, order_id, trading_path,
+		/// supply_amount, target_amount\]
+		FillLimitOrder(T::AccountId, LimitOrderId, Vec<CurrencyId>, Balance, Balance),
+		/// Expired limit order is removed and the supply amount is unreserved.
+		/// \[owner, order_id\]
+		ExpireLimitOrder(T::AccountId, LimitOrderId),
+		/// The exchange fee of trading pair is updated, `None` means to use the
+		/// default exchange fee. \[trading_pair, exchange_fee\]
+		TradingPairFeeUpdated(TradingPair, Option<(u32, u32)>),
+		/// The protocol share of trading fees is updated. \[protocol_fee_share\]
+		ProtocolFeeShareUpdated(Ratio),
+		/// Protocol fee is charged from the swap. \[trading_pair, currency_id,
+		/// protocol_fee_amount\]
+		ChargeProtocolFee(TradingPair, CurrencyId, Balance),
+		/// Borrow target currency from DEX first and repay supply currency
+		/// after the nested call. \[trader, trading_path,
+		/// liquidity_change_list\]
+		FlashSwap(T::AccountId, Vec<CurrencyId>, Vec<Balance>),
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -212,6 +408,15 @@ pub mod module {
 	pub type TradingPairStatuses<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, TradingPairStatus<Balance, T::BlockNumber>, ValueQuery>;
 
+	/// The trading pairs in Enabled status, which are searched for the
+	/// trading paths between two currencies.
+	///
+	/// EnabledTradingPairs: Vec<TradingPair>
+	#[pallet::storage]
+	#[pallet::getter(fn enabled_trading_pairs)]
+	pub type EnabledTradingPairs<T: Config> =
+		StorageValue<_, BoundedVec<TradingPair, T::MaxSearchedTradingPairs>, ValueQuery>;
+
 	/// Provision of TradingPair by AccountId.
 	///
 	/// ProvisioningPool: double_map TradingPair, AccountId => (Balance,
@@ -229,6 +434,82 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Cumulative prices of TradingPair.
+	///
+	/// CumulativePrices: map TradingPair => CumulativePrice
+	#[pallet::storage]
+	#[pallet::getter(fn cumulative_prices)]
+	pub type CumulativePrices<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, CumulativePrice<T::BlockNumber>, ValueQuery>;
+
+	/// Cumulative prices of TradingPair at the start of the current TWAP window.
+	///
+	/// TwapObservations: map TradingPair => Option<CumulativePrice>
+	#[pallet::storage]
+	#[pallet::getter(fn twap_observations)]
+	pub type TwapObservations<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, CumulativePrice<T::BlockNumber>, OptionQuery>;
+
+	/// Time-weighted average prices of TradingPair over the last completed
+	/// TWAP window, and the block number at which they were updated.
+	///
+	/// TwapPrices: map TradingPair => Option<(ExchangeRate, ExchangeRate, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn twap_prices)]
+	pub type TwapPrices<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate, T::BlockNumber), OptionQuery>;
+
+	/// Open limit orders.
+	///
+	/// LimitOrders: double_map AccountId, LimitOrderId => Option<LimitOrder>
+	#[pallet::storage]
+	#[pallet::getter(fn limit_orders)]
+	pub type LimitOrders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		LimitOrderId,
+		LimitOrder<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The number of open limit orders of AccountId.
+	///
+	/// LimitOrderCount: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn limit_order_count)]
+	pub type LimitOrderCount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Next id for limit order.
+	///
+	/// NextLimitOrderId: LimitOrderId
+	#[pallet::storage]
+	#[pallet::getter(fn next_limit_order_id)]
+	pub type NextLimitOrderId<T: Config> = StorageValue<_, LimitOrderId, ValueQuery>;
+
+	/// Exchange fee of TradingPair, use `GetExchangeFee` if not set.
+	///
+	/// TradingPairFees: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_fees)]
+	pub type TradingPairFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// The share of trading fees which is charged as protocol fee and sent to
+	/// `ProtocolFeeReceiver`.
+	///
+	/// ProtocolFeeShare: Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_share)]
+	pub type ProtocolFeeShare<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+
+	/// The swap curve of TradingPair.
+	///
+	/// TradingPairCurves: map TradingPair => SwapCurve
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_curves)]
+	pub type TradingPairCurves<T: Config> = StorageMap<_, Twox64Concat, TradingPair, SwapCurve, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -265,7 +546,10 @@ pub mod module {
 			);
 
 			self.initial_enabled_trading_pairs.iter().for_each(|trading_pair| {
-				TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+				assert!(
+					<Pallet<T>>::set_trading_pair_enabled(*trading_pair).is_ok(),
+					"too many enabled trading pairs."
+				);
 			});
 
 			self.initial_added_liquidity_pools
@@ -293,11 +577,65 @@ pub mod module {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Index the Enabled trading pairs into `EnabledTradingPairs`. The
+		/// trading pairs are listed by `ListingOrigin`, so there're few of them.
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+				return 0;
+			}
+
+			let mut count: u64 = 0;
+			let mut enabled_trading_pairs: Vec<TradingPair> = vec![];
+			for (trading_pair, status) in TradingPairStatuses::<T>::iter() {
+				count += 1;
+				if let TradingPairStatus::<_, _>::Enabled = status {
+					enabled_trading_pairs.push(trading_pair);
+				}
+			}
+			if enabled_trading_pairs.len() > T::MaxSearchedTradingPairs::get() as usize {
+				log::warn!(
+					target: "dex",
+					"{:?} Enabled trading pairs exceed MaxSearchedTradingPairs, the rest are not searched",
+					enabled_trading_pairs.len(),
+				);
+				enabled_trading_pairs.truncate(T::MaxSearchedTradingPairs::get() as usize);
+			}
+			EnabledTradingPairs::<T>::put(
+				TryInto::<BoundedVec<TradingPair, T::MaxSearchedTradingPairs>>::try_into(enabled_trading_pairs)
+					.unwrap_or_default(),
+			);
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(count.saturating_add(1), 2)
+		}
+
+		/// Runs after every block. Start offchain worker to check limit orders
+		/// and submit unsigned tx to fill or remove them.
+		fn offchain_worker(now: T::BlockNumber) {
+			if let Err(e) = Self::_offchain_worker(now) {
+				log::info!(
+					target: "dex offchain worker",
+					"cannot run offchain worker at {:?}: {:?}",
+					now,
+					e,
+				);
+			} else {
+				log::debug!(
+					target: "dex offchain worker",
+					"offchain worker start at block: {:?} already done!",
+					now,
+				);
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -456,7 +794,8 @@ pub mod module {
 			Ok(())
 		}
 
-		/// List a new provisioning trading pair.
+		/// List a new provisioning trading pair with the swap curve it will
+		/// use once enabled.
 		#[pallet::weight((<T as Config>::WeightInfo::list_provisioning(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn list_provisioning(
@@ -468,11 +807,13 @@ pub mod module {
 			#[pallet::compact] target_provision_a: Balance,
 			#[pallet::compact] target_provision_b: Balance,
 			#[pallet::compact] not_before: T::BlockNumber,
+			curve: SwapCurve,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
 
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			Self::ensure_valid_curve(curve)?;
 			ensure!(
 				matches!(
 					Self::trading_pair_statuses(trading_pair),
@@ -514,6 +855,7 @@ pub mod module {
 					not_before,
 				}),
 			);
+			TradingPairCurves::<T>::insert(trading_pair, curve);
 			Self::deposit_event(Event::ListProvisioning(trading_pair));
 			Ok(())
 		}
@@ -615,13 +957,14 @@ pub mod module {
 
 					// inject provision to liquidity pool
 					LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+						Self::accumulate_cumulative_prices(trading_pair, *pool_0, *pool_1);
 						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
 						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
 						Ok(())
 					})?;
 
 					// update trading_pair to Enabled status
-					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+					Self::set_trading_pair_enabled(trading_pair)?;
 
 					// record initial exchange rate so that founders can use it to calculate their own shares
 					InitialShareExchangeRates::<T>::insert(
@@ -642,7 +985,7 @@ pub mod module {
 			Ok(())
 		}
 
-		/// Enable a trading pair
+		/// Enable a trading pair with the swap curve
 		/// if the status of trading pair is `Disabled`, or `Provisioning` without any accumulated
 		/// provision, enable it directly.
 		#[pallet::weight((<T as Config>::WeightInfo::enable_trading_pair(), DispatchClass::Operational))]
@@ -651,10 +994,12 @@ pub mod module {
 			origin: OriginFor<T>,
 			currency_id_a: CurrencyId,
 			currency_id_b: CurrencyId,
+			curve: SwapCurve,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			Self::ensure_valid_curve(curve)?;
 			match Self::trading_pair_statuses(trading_pair) {
 				TradingPairStatus::<_, _>::Disabled => {}
 				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
@@ -666,8 +1011,13 @@ pub mod module {
 				}
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
+			if curve != Self::trading_pair_curves(trading_pair) {
+				let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+				ensure!(pool_0.is_zero() && pool_1.is_zero(), Error::<T>::CannotChangeSwapCurve);
+			}
 
-			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			Self::set_trading_pair_enabled(trading_pair)?;
+			TradingPairCurves::<T>::insert(trading_pair, curve);
 			Self::deposit_event(Event::EnableTradingPair(trading_pair));
 			Ok(())
 		}
@@ -692,9 +1042,290 @@ pub mod module {
 			);
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+			EnabledTradingPairs::<T>::mutate(|enabled_trading_pairs| {
+				enabled_trading_pairs.retain(|enabled_trading_pair| *enabled_trading_pair != trading_pair)
+			});
 			Self::deposit_event(Event::DisableTradingPair(trading_pair));
 			Ok(())
 		}
+
+		/// Trading with DEX, swap with exact supply amount through the trading
+		/// path which gets the most target amount.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply_by_best_path(T::MaxSearchedTradingPaths::get()))]
+		#[transactional]
+		pub fn swap_with_exact_supply_by_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (path, _) =
+				Self::get_best_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+					.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
+		/// Trading with DEX, swap with exact target amount through the trading
+		/// path which costs the least supply amount.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `target_amount`: exact target amount.
+		/// - `max_supply_amount`: acceptable maximum supply amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target_by_best_path(T::MaxSearchedTradingPaths::get()))]
+		#[transactional]
+		pub fn swap_with_exact_target_by_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (path, _) =
+				Self::get_best_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+					.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+			Ok(())
+		}
+
+		/// Trading with DEX, swap with exact supply amount which is split
+		/// across multiple trading paths to reduce the slippage.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum total target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply_by_split_paths(
+			T::SwapSplitParts::get(),
+			T::MaxSearchedTradingPaths::get(),
+		))]
+		#[transactional]
+		pub fn swap_with_exact_supply_by_split_paths(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_supply_by_split_paths(
+				&who,
+				supply_currency_id,
+				target_currency_id,
+				supply_amount,
+				min_target_amount,
+				T::SwapSplitParts::get(),
+			)?;
+			Ok(())
+		}
+
+		/// Place a limit order, the supply amount and `LimitOrderDeposit` of
+		/// native currency will be reserved until the order is filled,
+		/// cancelled or expired.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		/// - `expiry`: the order can not be filled after this block.
+		#[pallet::weight(<T as Config>::WeightInfo::place_limit_order(T::MaxSearchedTradingPaths::get()))]
+		#[transactional]
+		pub fn place_limit_order(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+			#[pallet::compact] expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_place_limit_order(
+				&who,
+				supply_currency_id,
+				target_currency_id,
+				supply_amount,
+				min_target_amount,
+				expiry,
+			)?;
+			Ok(())
+		}
+
+		/// Cancel a limit order and unreserve the supply amount and deposit.
+		///
+		/// - `order_id`: the id of limit order.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_limit_order())]
+		#[transactional]
+		pub fn cancel_limit_order(origin: OriginFor<T>, order_id: LimitOrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Self::limit_orders(&who, order_id).ok_or(Error::<T>::LimitOrderNotFound)?;
+			Self::remove_limit_order(&who, order_id, &order);
+			Self::deposit_event(Event::CancelLimitOrder(who, order_id));
+			Ok(())
+		}
+
+		/// Fill a limit order through the trading path which gets the most
+		/// target amount.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `owner`: the owner of limit order.
+		/// - `order_id`: the id of limit order.
+		#[pallet::weight(<T as Config>::WeightInfo::fill_limit_order(T::MaxSearchedTradingPaths::get()))]
+		#[transactional]
+		pub fn fill_limit_order(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			order_id: LimitOrderId,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_fill_limit_order(&owner, order_id)?;
+			Ok(())
+		}
+
+		/// Remove an expired limit order and unreserve the supply amount and
+		/// deposit.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `owner`: the owner of limit order.
+		/// - `order_id`: the id of limit order.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_expired_limit_order())]
+		#[transactional]
+		pub fn remove_expired_limit_order(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			order_id: LimitOrderId,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_remove_expired_limit_order(&owner, order_id)?;
+			Ok(())
+		}
+
+		/// Set the exchange fee of trading pair.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `exchange_fee`: the exchange fee rate as (numerator, denominator), `None` means to use
+		///   the default `GetExchangeFee`.
+		#[pallet::weight((<T as Config>::WeightInfo::set_trading_pair_fee(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_trading_pair_fee(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			exchange_fee: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			if let Some((fee_numerator, fee_denominator)) = exchange_fee {
+				ensure!(
+					!fee_denominator.is_zero() && fee_numerator < fee_denominator,
+					Error::<T>::InvalidExchangeFee
+				);
+			}
+
+			TradingPairFees::<T>::set(trading_pair, exchange_fee);
+			Self::deposit_event(Event::TradingPairFeeUpdated(trading_pair, exchange_fee));
+			Ok(())
+		}
+
+		/// Set the share of trading fees which is charged as protocol fee.
+		///
+		/// The dispatch origin of this call must be `ListingOrigin`.
+		///
+		/// - `share`: the share of trading fees, must not be greater than 1.
+		#[pallet::weight((<T as Config>::WeightInfo::set_protocol_fee_share(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_protocol_fee_share(origin: OriginFor<T>, share: Ratio) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(share <= Ratio::one(), Error::<T>::InvalidProtocolFeeShare);
+
+			ProtocolFeeShare::<T>::put(share);
+			Self::deposit_event(Event::ProtocolFeeShareUpdated(share));
+			Ok(())
+		}
+
+		/// Flash swap with DEX, receive the exact target amount first, then
+		/// dispatch `call` as the trader, and repay the supply amount at the
+		/// end. The whole swap reverts if the supply amount can not be
+		/// repaid.
+		///
+		/// - `path`: trading path.
+		/// - `target_amount`: exact target amount to borrow.
+		/// - `max_supply_amount`: acceptable maximum supply amount to repay.
+		/// - `call`: the call to dispatch with the borrowed target amount.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::flash_swap(path.len() as u32).saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		#[transactional]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_flash_swap(&who, &path, target_amount, max_supply_amount, *call)?;
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::fill_limit_order { owner, order_id } => {
+					let account = T::Lookup::lookup(owner.clone())?;
+					let order = Self::limit_orders(&account, order_id).ok_or(InvalidTransaction::Stale)?;
+					if Self::is_limit_order_expired(&order) || !Self::is_limit_order_fillable(&order) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("DEXOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides((<frame_system::Pallet<T>>::block_number(), order_id))
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				Call::remove_expired_limit_order { owner, order_id } => {
+					let account = T::Lookup::lookup(owner.clone())?;
+					let order = Self::limit_orders(&account, order_id).ok_or(InvalidTransaction::Stale)?;
+					if !Self::is_limit_order_expired(&order) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("DEXOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(order_id)
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
 	}
 }
 
@@ -703,6 +1334,21 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	/// Update the status of trading pair to Enabled and index it into
+	/// `EnabledTradingPairs`.
+	fn set_trading_pair_enabled(trading_pair: TradingPair) -> DispatchResult {
+		EnabledTradingPairs::<T>::try_mutate(|enabled_trading_pairs| -> DispatchResult {
+			if !enabled_trading_pairs.contains(&trading_pair) {
+				enabled_trading_pairs
+					.try_push(trading_pair)
+					.map_err(|_| Error::<T>::TooManyEnabledTradingPairs)?;
+			}
+			Ok(())
+		})?;
+		TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+		Ok(())
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -849,6 +1495,7 @@ impl<T: Config> Pallet<T> {
 		);
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			Self::accumulate_cumulative_prices(trading_pair, *pool_0, *pool_1);
 			let dex_share_currency_id = trading_pair.dex_share_currency_id();
 			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 			let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
@@ -871,9 +1518,19 @@ impl<T: Config> Pallet<T> {
 				let shares_from_token_1 = exchange_rate_1
 					.checked_mul_int(max_amount_1)
 					.ok_or(ArithmeticError::Overflow)?;
-				let initial_shares = shares_from_token_0
-					.checked_add(shares_from_token_1)
-					.ok_or(ArithmeticError::Overflow)?;
+				let initial_shares = match Self::trading_pair_curves(trading_pair) {
+					SwapCurve::ConstantProduct => shares_from_token_0
+						.checked_add(shares_from_token_1)
+						.ok_or(ArithmeticError::Overflow)?,
+					// use the StableSwap invariant as initial dex share amount.
+					SwapCurve::StableSwap { amplification } => Self::get_stable_swap_invariant(
+						U256::from(max_amount_0),
+						U256::from(max_amount_1),
+						amplification,
+					)
+					.and_then(|d| TryInto::<Balance>::try_into(d).ok())
+					.ok_or(ArithmeticError::Overflow)?,
+				};
 
 				(max_amount_0, max_amount_1, initial_shares)
 			} else {
@@ -956,6 +1613,7 @@ impl<T: Config> Pallet<T> {
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			Self::accumulate_cumulative_prices(trading_pair, *pool_0, *pool_1);
 			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
 				(min_withdrawn_a, min_withdrawn_b)
 			} else {
@@ -995,7 +1653,7 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+	pub fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
 			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
 			if currency_id_a == trading_pair.first() {
@@ -1008,12 +1666,44 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the amounts of currency A and currency B which `share_amount` of
+	/// dex share can be withdrawn for.
+	pub fn get_liquidity_share_value(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		share_amount: Balance,
+	) -> (Balance, Balance) {
+		let total_shares = TradingPair::from_currency_ids(currency_id_a, currency_id_b)
+			.map(|trading_pair| T::Currency::total_issuance(trading_pair.dex_share_currency_id()))
+			.unwrap_or_else(Zero::zero);
+		if total_shares.is_zero() {
+			return (Zero::zero(), Zero::zero());
+		}
+
+		let proportion = Ratio::checked_from_rational(share_amount, total_shares).unwrap_or_default();
+		let (pool_a, pool_b) = Self::get_liquidity(currency_id_a, currency_id_b);
+		(
+			proportion.saturating_mul_int(pool_a),
+			proportion.saturating_mul_int(pool_b),
+		)
+	}
+
+	/// Get the exchange fee of trading pair, fall back to `GetExchangeFee`.
+	pub fn get_exchange_fee(trading_pair: TradingPair) -> (u32, u32) {
+		Self::trading_pair_fees(trading_pair).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
 	/// Get how much target amount will be got for specific supply amount.
-	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+	fn get_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let supply_amount_with_fee: U256 =
 				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
 			let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
@@ -1029,11 +1719,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get how much supply amount will be paid for specific target amount.
-	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+	fn get_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let numerator: U256 = U256::from(supply_pool)
 				.saturating_mul(U256::from(target_amount))
 				.saturating_mul(U256::from(fee_denominator));
@@ -1049,35 +1744,225 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	fn get_target_amounts(
-		path: &[CurrencyId],
-		supply_amount: Balance,
-	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
-		let path_length = path.len();
-		ensure!(
-			path_length >= 2 && path_length <= T::TradingPathLimit::get().saturated_into(),
-			Error::<T>::InvalidTradingPathLength
-		);
-		let mut target_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
-		target_amounts[0] = supply_amount;
-
-		let mut i: usize = 0;
-		while i + 1 < path_length {
-			let trading_pair =
-				TradingPair::from_currency_ids(path[i], path[i + 1]).ok_or(Error::<T>::InvalidCurrencyId)?;
+	fn ensure_valid_curve(curve: SwapCurve) -> DispatchResult {
+		if let SwapCurve::StableSwap { amplification } = curve {
 			ensure!(
-				matches!(
-					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
-				),
-				Error::<T>::MustBeEnabled
+				!amplification.is_zero() && amplification <= MAX_AMPLIFICATION,
+				Error::<T>::InvalidSwapCurve
 			);
-			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
-			ensure!(
-				!supply_pool.is_zero() && !target_pool.is_zero(),
+		}
+		Ok(())
+	}
+
+	/// Calculate the StableSwap invariant `D` of two pools by Newton's method:
+	/// `4A(x + y) + D = 4AD + D^3 / (4xy)`.
+	fn get_stable_swap_invariant(pool_0: U256, pool_1: U256, amplification: u32) -> Option<U256> {
+		let sum = pool_0.checked_add(pool_1)?;
+		if sum.is_zero() {
+			return Some(U256::zero());
+		}
+		if pool_0.is_zero() || pool_1.is_zero() {
+			return None;
+		}
+
+		let ann = U256::from(amplification).checked_mul(U256::from(4u32))?;
+		let mut d = sum;
+		for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+			let d_p = d
+				.checked_mul(d)?
+				.checked_div(pool_0.checked_mul(U256::from(2u32))?)?
+				.checked_mul(d)?
+				.checked_div(pool_1.checked_mul(U256::from(2u32))?)?;
+			let d_prev = d;
+			let numerator = ann
+				.checked_mul(sum)?
+				.checked_add(d_p.checked_mul(U256::from(2u32))?)?
+				.checked_mul(d)?;
+			let denominator = ann
+				.checked_sub(U256::one())?
+				.checked_mul(d)?
+				.checked_add(d_p.checked_mul(U256::from(3u32))?)?;
+			d = numerator.checked_div(denominator)?;
+
+			if d.max(d_prev) - d.min(d_prev) <= U256::one() {
+				return Some(d);
+			}
+		}
+		None
+	}
+
+	/// Calculate the amount of one pool which keeps the StableSwap invariant
+	/// `d` when the other pool is `pool`.
+	fn get_stable_swap_pool_amount(pool: U256, d: U256, amplification: u32) -> Option<U256> {
+		let ann = U256::from(amplification).checked_mul(U256::from(4u32))?;
+		let c = d
+			.checked_mul(d)?
+			.checked_div(pool.checked_mul(U256::from(2u32))?)?
+			.checked_mul(d)?
+			.checked_div(ann.checked_mul(U256::from(2u32))?)?;
+		let b = pool.checked_add(d.checked_div(ann)?)?;
+
+		let mut y = d;
+		for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+			let y_prev = y;
+			let numerator = y.checked_mul(y)?.checked_add(c)?;
+			let denominator = y.checked_mul(U256::from(2u32))?.checked_add(b)?.checked_sub(d)?;
+			y = numerator.checked_div(denominator)?;
+
+			if y.max(y_prev) - y.min(y_prev) <= U256::one() {
+				return Some(y);
+			}
+		}
+		None
+	}
+
+	/// Calculate the marginal prices of two pools under StableSwap curve by
+	/// the partial derivatives of the invariant, the price of currency 0 in
+	/// currency 1 is `(4A + P / x) / (4A + P / y)` where `P = D^3 / (4xy)`.
+	fn get_stable_swap_marginal_prices(
+		pool_0: Balance,
+		pool_1: Balance,
+		amplification: u32,
+	) -> Option<(ExchangeRate, ExchangeRate)> {
+		let (pool_0, pool_1) = (U256::from(pool_0), U256::from(pool_1));
+		let d = Self::get_stable_swap_invariant(pool_0, pool_1, amplification)?;
+		let ann = U256::from(amplification).checked_mul(U256::from(4u32))?;
+		let p = d
+			.checked_mul(d)?
+			.checked_div(pool_0.checked_mul(U256::from(2u32))?)?
+			.checked_mul(d)?
+			.checked_div(pool_1.checked_mul(U256::from(2u32))?)?;
+
+		// (4A + P / x) * x and (4A + P / y) * y
+		let partial_0 = ann.checked_mul(pool_0)?.checked_add(p)?;
+		let partial_1 = ann.checked_mul(pool_1)?.checked_add(p)?;
+		let accuracy = U256::from(ExchangeRate::accuracy());
+		let price_0 = partial_0
+			.checked_mul(accuracy)?
+			.checked_div(partial_1)?
+			.checked_mul(pool_1)?
+			.checked_div(pool_0)?;
+		let price_1 = partial_1
+			.checked_mul(accuracy)?
+			.checked_div(partial_0)?
+			.checked_mul(pool_0)?
+			.checked_div(pool_1)?;
+
+		Some((
+			ExchangeRate::from_inner(TryInto::<u128>::try_into(price_0).ok()?),
+			ExchangeRate::from_inner(TryInto::<u128>::try_into(price_1).ok()?),
+		))
+	}
+
+	/// Get how much target amount will be got for specific supply amount
+	/// under StableSwap curve.
+	fn get_stable_swap_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+		amplification: u32,
+	) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			Zero::zero()
+		} else {
+			let (fee_numerator, fee_denominator) = exchange_fee;
+			let (supply_pool, target_pool) = (U256::from(supply_pool), U256::from(target_pool));
+
+			Self::get_stable_swap_invariant(supply_pool, target_pool, amplification)
+				.and_then(|d| {
+					let supply_amount_with_fee = U256::from(supply_amount)
+						.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+						.checked_div(U256::from(fee_denominator))?;
+					let new_target_pool = Self::get_stable_swap_pool_amount(
+						supply_pool.checked_add(supply_amount_with_fee)?,
+						d,
+						amplification,
+					)?;
+					// sub 1 from result to make up the possible losses of pool caused by rounding
+					target_pool.checked_sub(new_target_pool)?.checked_sub(U256::one())
+				})
+				.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+				.unwrap_or_else(Zero::zero)
+		}
+	}
+
+	/// Get how much supply amount will be paid for specific target amount
+	/// under StableSwap curve.
+	fn get_stable_swap_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+		amplification: u32,
+	) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() || target_amount >= target_pool {
+			Zero::zero()
+		} else {
+			let (fee_numerator, fee_denominator) = exchange_fee;
+			let (supply_pool, target_pool) = (U256::from(supply_pool), U256::from(target_pool));
+
+			Self::get_stable_swap_invariant(supply_pool, target_pool, amplification)
+				.and_then(|d| {
+					let new_supply_pool = Self::get_stable_swap_pool_amount(
+						target_pool.checked_sub(U256::from(target_amount))?,
+						d,
+						amplification,
+					)?;
+					// add 1 to result to make up the possible losses of pool caused by rounding
+					new_supply_pool
+						.checked_sub(supply_pool)?
+						.checked_add(U256::one())?
+						.checked_mul(U256::from(fee_denominator))?
+						.checked_div(U256::from(fee_denominator.checked_sub(fee_numerator)?))?
+						.checked_add(U256::one())
+				})
+				.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+				.unwrap_or_else(Zero::zero)
+		}
+	}
+
+	fn get_target_amounts(
+		path: &[CurrencyId],
+		supply_amount: Balance,
+	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
+		let path_length = path.len();
+		ensure!(
+			path_length >= 2 && path_length <= T::TradingPathLimit::get().saturated_into(),
+			Error::<T>::InvalidTradingPathLength
+		);
+		let mut target_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
+		target_amounts[0] = supply_amount;
+
+		let mut i: usize = 0;
+		while i + 1 < path_length {
+			let trading_pair =
+				TradingPair::from_currency_ids(path[i], path[i + 1]).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Enabled
+				),
+				Error::<T>::MustBeEnabled
+			);
+			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
+			ensure!(
+				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+			let exchange_fee = Self::get_exchange_fee(trading_pair);
+			let target_amount = match Self::trading_pair_curves(trading_pair) {
+				SwapCurve::ConstantProduct => {
+					Self::get_target_amount(supply_pool, target_pool, target_amounts[i], exchange_fee)
+				}
+				SwapCurve::StableSwap { amplification } => Self::get_stable_swap_target_amount(
+					supply_pool,
+					target_pool,
+					target_amounts[i],
+					exchange_fee,
+					amplification,
+				),
+			};
 			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
 
 			target_amounts[i + 1] = target_amount;
@@ -1115,7 +2000,19 @@ impl<T: Config> Pallet<T> {
 				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+			let exchange_fee = Self::get_exchange_fee(trading_pair);
+			let supply_amount = match Self::trading_pair_curves(trading_pair) {
+				SwapCurve::ConstantProduct => {
+					Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i], exchange_fee)
+				}
+				SwapCurve::StableSwap { amplification } => Self::get_stable_swap_supply_amount(
+					supply_pool,
+					target_pool,
+					supply_amounts[i],
+					exchange_fee,
+					amplification,
+				),
+			};
 			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
 
 			supply_amounts[i - 1] = supply_amount;
@@ -1125,6 +2022,252 @@ impl<T: Config> Pallet<T> {
 		Ok(supply_amounts)
 	}
 
+	/// Get the trading paths from `supply_currency_id` to `target_currency_id`
+	/// which only go through `Enabled` trading pairs. The length of paths is
+	/// bounded by `TradingPathLimit`, and shorter paths come first.
+	///
+	/// The search stops once `MaxSearchedTradingPaths` trading paths are
+	/// found, so the longer ones may be left out.
+	fn get_trading_paths(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Vec<Vec<CurrencyId>> {
+		let path_limit: usize = T::TradingPathLimit::get().saturated_into();
+		let max_searched_paths: usize = T::MaxSearchedTradingPaths::get().saturated_into();
+		if supply_currency_id == target_currency_id || path_limit < 2 || max_searched_paths.is_zero() {
+			return vec![];
+		}
+
+		let enabled_trading_pairs = Self::enabled_trading_pairs();
+		let mut trading_paths: Vec<Vec<CurrencyId>> = vec![];
+		// breadth-first search, the pending paths are extended in the order of their length
+		let mut pending_paths: VecDeque<Vec<CurrencyId>> = VecDeque::from(vec![vec![supply_currency_id]]);
+		while let Some(path) = pending_paths.pop_front() {
+			let last_currency_id = path[path.len() - 1];
+			for trading_pair in enabled_trading_pairs.iter() {
+				let next_currency_id = if trading_pair.first() == last_currency_id {
+					trading_pair.second()
+				} else if trading_pair.second() == last_currency_id {
+					trading_pair.first()
+				} else {
+					continue;
+				};
+
+				// a currency can appear only once in a trading path
+				if path.contains(&next_currency_id) {
+					continue;
+				}
+
+				let mut new_path = path.clone();
+				new_path.push(next_currency_id);
+				if next_currency_id == target_currency_id {
+					trading_paths.push(new_path);
+					if trading_paths.len() >= max_searched_paths {
+						return trading_paths;
+					}
+				} else if new_path.len() < path_limit {
+					pending_paths.push_back(new_path);
+				}
+			}
+		}
+
+		trading_paths
+	}
+
+	/// Get the trading path which gets the most target amount for specific
+	/// supply amount, and the target amount of it.
+	pub fn get_best_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::get_trading_paths(supply_currency_id, target_currency_id)
+			.into_iter()
+			.filter_map(|path| {
+				Self::get_target_amounts(&path, supply_amount)
+					.ok()
+					.map(|amounts| (path, amounts[amounts.len() - 1]))
+			})
+			.fold(None, |best, (path, target_amount)| match best {
+				Some((_, best_target_amount)) if best_target_amount >= target_amount => best,
+				_ => Some((path, target_amount)),
+			})
+	}
+
+	/// Get the trading path which costs the least supply amount for specific
+	/// target amount, and the supply amount of it.
+	pub fn get_best_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::get_trading_paths(supply_currency_id, target_currency_id)
+			.into_iter()
+			.filter_map(|path| {
+				Self::get_supply_amounts(&path, target_amount)
+					.ok()
+					.map(|amounts| (path, amounts[0]))
+			})
+			.fold(None, |best, (path, supply_amount)| match best {
+				Some((_, best_supply_amount)) if best_supply_amount <= supply_amount => best,
+				_ => Some((path, supply_amount)),
+			})
+	}
+
+	/// Accumulate the prices of TradingPair with the liquidity before it
+	/// changes, and update the time-weighted average prices once `TwapWindow`
+	/// blocks have passed since the start of the current window.
+	fn accumulate_cumulative_prices(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut cumulative_prices = Self::cumulative_prices(trading_pair);
+
+		if pool_0.is_zero() || pool_1.is_zero() {
+			// there's no price for empty pool, restart the TWAP window
+			TwapObservations::<T>::remove(trading_pair);
+			TwapPrices::<T>::remove(trading_pair);
+			cumulative_prices.last_updated = now;
+			CumulativePrices::<T>::insert(trading_pair, cumulative_prices);
+			return;
+		}
+
+		let elapsed: u128 = now.saturating_sub(cumulative_prices.last_updated).saturated_into();
+		if !elapsed.is_zero() {
+			let (price_0, price_1) = Self::get_marginal_prices(trading_pair, pool_0, pool_1);
+			cumulative_prices.price_0_cumulative = cumulative_prices
+				.price_0_cumulative
+				.wrapping_add(price_0.into_inner().wrapping_mul(elapsed));
+			cumulative_prices.price_1_cumulative = cumulative_prices
+				.price_1_cumulative
+				.wrapping_add(price_1.into_inner().wrapping_mul(elapsed));
+		}
+		cumulative_prices.last_updated = now;
+		CumulativePrices::<T>::insert(trading_pair, cumulative_prices);
+
+		match Self::twap_observations(trading_pair) {
+			Some(observation) => {
+				let window = now.saturating_sub(observation.last_updated);
+				if !window.is_zero() && window >= T::TwapWindow::get() {
+					let window: u128 = window.saturated_into();
+					let average_price_0 = ExchangeRate::from_inner(
+						cumulative_prices
+							.price_0_cumulative
+							.wrapping_sub(observation.price_0_cumulative)
+							/ window,
+					);
+					let average_price_1 = ExchangeRate::from_inner(
+						cumulative_prices
+							.price_1_cumulative
+							.wrapping_sub(observation.price_1_cumulative)
+							/ window,
+					);
+					TwapPrices::<T>::insert(trading_pair, (average_price_0, average_price_1, now));
+					TwapObservations::<T>::insert(trading_pair, cumulative_prices);
+				}
+			}
+			None => TwapObservations::<T>::insert(trading_pair, cumulative_prices),
+		}
+	}
+
+	/// Get the marginal prices of the pools of TradingPair under its swap
+	/// curve, the price of currency 0 in currency 1 and vice versa.
+	fn get_marginal_prices(
+		trading_pair: TradingPair,
+		pool_0: Balance,
+		pool_1: Balance,
+	) -> (ExchangeRate, ExchangeRate) {
+		match Self::trading_pair_curves(trading_pair) {
+			SwapCurve::ConstantProduct => (
+				ExchangeRate::checked_from_rational(pool_1, pool_0).unwrap_or_default(),
+				ExchangeRate::checked_from_rational(pool_0, pool_1).unwrap_or_default(),
+			),
+			SwapCurve::StableSwap { amplification } => {
+				Self::get_stable_swap_marginal_prices(pool_0, pool_1, amplification).unwrap_or_default()
+			}
+		}
+	}
+
+	/// Get the time-weighted average price of `base_currency_id` in
+	/// `quote_currency_id`, return None if it's older than `TwapMaxAge`.
+	pub fn get_twap_price(base_currency_id: CurrencyId, quote_currency_id: CurrencyId) -> Option<ExchangeRate> {
+		Self::get_twap_price_within(base_currency_id, quote_currency_id, T::TwapMaxAge::get())
+	}
+
+	/// Get the time-weighted average price of `base_currency_id` in
+	/// `quote_currency_id`, return None if it's older than `max_age` or
+	/// `TwapMaxAge`.
+	pub fn get_twap_price_within(
+		base_currency_id: CurrencyId,
+		quote_currency_id: CurrencyId,
+		max_age: T::BlockNumber,
+	) -> Option<ExchangeRate> {
+		let trading_pair = TradingPair::from_currency_ids(base_currency_id, quote_currency_id)?;
+		let (price_0, price_1, updated_at) = Self::twap_prices(trading_pair)?;
+		let age = frame_system::Pallet::<T>::block_number().saturating_sub(updated_at);
+		if age > max_age.min(T::TwapMaxAge::get()) {
+			return None;
+		}
+
+		if base_currency_id == trading_pair.first() {
+			Some(price_0)
+		} else {
+			Some(price_1)
+		}
+	}
+
+	/// Get the invariant of pools under the swap curve.
+	fn get_invariant(
+		curve: SwapCurve,
+		pool_0: Balance,
+		pool_1: Balance,
+	) -> sp_std::result::Result<U256, DispatchError> {
+		match curve {
+			SwapCurve::ConstantProduct => Ok(U256::from(pool_0).saturating_mul(U256::from(pool_1))),
+			SwapCurve::StableSwap { amplification } => {
+				Self::get_stable_swap_invariant(U256::from(pool_0), U256::from(pool_1), amplification)
+					.ok_or_else(|| Error::<T>::InvariantCheckFailed.into())
+			}
+		}
+	}
+
+	/// Transfer the protocol share of the trading fee of `supply_increment`
+	/// from DEX to `ProtocolFeeReceiver`, return the actual protocol fee. The
+	/// fee is kept in the pool if the transfer failed.
+	fn charge_protocol_fee(trading_pair: TradingPair, currency_id: CurrencyId, supply_increment: Balance) -> Balance {
+		let protocol_fee_share = Self::protocol_fee_share();
+		if protocol_fee_share.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = Self::get_exchange_fee(trading_pair);
+		let exchange_fee: Balance = U256::from(supply_increment)
+			.saturating_mul(U256::from(fee_numerator))
+			.checked_div(U256::from(fee_denominator))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero);
+		let protocol_fee = protocol_fee_share.saturating_mul_int(exchange_fee);
+		if protocol_fee.is_zero() {
+			return Zero::zero();
+		}
+
+		match T::Currency::transfer(
+			currency_id,
+			&Self::account_id(),
+			&T::ProtocolFeeReceiver::get(),
+			protocol_fee,
+		) {
+			Ok(_) => {
+				Self::deposit_event(Event::ChargeProtocolFee(trading_pair, currency_id, protocol_fee));
+				protocol_fee
+			}
+			Err(e) => {
+				log::warn!(
+					target: "dex",
+					"charge protocol fee: failed to transfer {:?} {:?} to protocol fee receiver: {:?}. \
+					This is unexpected but should be safe",
+					protocol_fee, currency_id, e
+				);
+				Zero::zero()
+			}
+		}
+	}
+
 	fn _swap(
 		supply_currency_id: CurrencyId,
 		target_currency_id: CurrencyId,
@@ -1132,8 +2275,13 @@ impl<T: Config> Pallet<T> {
 		target_decrement: Balance,
 	) -> DispatchResult {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(supply_currency_id, target_currency_id) {
+			let protocol_fee = Self::charge_protocol_fee(trading_pair, supply_currency_id, supply_increment);
+			let supply_increment = supply_increment.saturating_sub(protocol_fee);
+
 			LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
-				let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+				Self::accumulate_cumulative_prices(trading_pair, *pool_0, *pool_1);
+				let curve = Self::trading_pair_curves(trading_pair);
+				let invariant_before_swap = Self::get_invariant(curve, *pool_0, *pool_1)?;
 
 				if supply_currency_id == trading_pair.first() {
 					*pool_0 = pool_0.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
@@ -1143,8 +2291,8 @@ impl<T: Config> Pallet<T> {
 					*pool_1 = pool_1.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
 				}
 
-				// invariant check to ensure the constant product formulas (k = x * y)
-				let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+				// invariant check to ensure the formulas of swap curve, (k = x * y) for constant product
+				let invariant_after_swap = Self::get_invariant(curve, *pool_0, *pool_1)?;
 				ensure!(
 					invariant_after_swap >= invariant_before_swap,
 					Error::<T>::InvariantCheckFailed,
@@ -1215,6 +2363,429 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Swap(who.clone(), path.to_vec(), amounts));
 		Ok(actual_supply_amount)
 	}
+
+	/// Lend the exact target amount to `who` before dispatching `call`, and
+	/// take the supply amount from `who` afterwards, the supply amount is
+	/// determined by the liquidity before the flash swap.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	fn do_flash_swap(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		call: <T as Config>::Call,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let amounts = Self::get_supply_amounts(path, target_amount)?;
+		ensure!(amounts[0] <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
+		let module_account_id = Self::account_id();
+		let actual_supply_amount = amounts[0];
+
+		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
+		call.dispatch(frame_system::RawOrigin::Signed(who.clone()).into())
+			.map_err(|e| e.error)?;
+		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)
+			.map_err(|_| Error::<T>::FlashSwapNotRepaid)?;
+		Self::_swap_by_path(path, &amounts)?;
+
+		Self::deposit_event(Event::FlashSwap(who.clone(), path.to_vec(), amounts));
+		Ok(actual_supply_amount)
+	}
+
+	/// Split `supply_amount` into `parts` parts, and swap each part through
+	/// the trading path which gets the most target amount at that moment, so
+	/// that the marginal prices of the trading paths even out. The supply
+	/// amount less than `parts` is swapped as a single part. A `Swap` event is
+	/// emitted for each trading path used, with the amounts of all the parts
+	/// swapped through it.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn do_swap_with_exact_supply_by_split_paths(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		parts: u32,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+		let trading_paths = Self::get_trading_paths(supply_currency_id, target_currency_id);
+		ensure!(!trading_paths.is_empty(), Error::<T>::NoAvailableTradingPath);
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(supply_currency_id, who, &module_account_id, supply_amount)?;
+
+		let parts = Balance::from(parts.max(1));
+		let part_amount = if supply_amount < parts {
+			supply_amount
+		} else {
+			supply_amount / parts
+		};
+		let mut remaining_supply_amount = supply_amount;
+		let mut actual_target_amount: Balance = Zero::zero();
+		// the summed amounts of the trading paths used, in the order of their first use
+		let mut used_paths: Vec<(&Vec<CurrencyId>, Vec<Balance>)> = vec![];
+		while !remaining_supply_amount.is_zero() {
+			// the last part also takes the remainder of the division
+			let amount = if remaining_supply_amount < part_amount.saturating_mul(2) {
+				remaining_supply_amount
+			} else {
+				part_amount
+			};
+
+			let mut best: Option<(&Vec<CurrencyId>, Vec<Balance>)> = None;
+			for path in trading_paths.iter() {
+				if let Ok(amounts) = Self::get_target_amounts(path, amount) {
+					let is_better = best.as_ref().map_or(true, |(_, best_amounts)| {
+						amounts[amounts.len() - 1] > best_amounts[best_amounts.len() - 1]
+					});
+					if is_better {
+						best = Some((path, amounts));
+					}
+				}
+			}
+			let (path, amounts) = best.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::_swap_by_path(path, &amounts)?;
+
+			actual_target_amount = actual_target_amount
+				.checked_add(amounts[amounts.len() - 1])
+				.ok_or(ArithmeticError::Overflow)?;
+			remaining_supply_amount = remaining_supply_amount.saturating_sub(amount);
+
+			match used_paths.iter_mut().find(|(used_path, _)| *used_path == path) {
+				Some((_, used_amounts)) => {
+					for (used_amount, amount) in used_amounts.iter_mut().zip(amounts) {
+						*used_amount = used_amount.saturating_add(amount);
+					}
+				}
+				None => used_paths.push((path, amounts)),
+			}
+		}
+
+		ensure!(
+			actual_target_amount >= min_target_amount,
+			Error::<T>::InsufficientTargetAmount
+		);
+		T::Currency::transfer(target_currency_id, &module_account_id, who, actual_target_amount)?;
+
+		for (path, amounts) in used_paths {
+			Self::deposit_event(Event::Swap(who.clone(), path.clone(), amounts));
+		}
+		Ok(actual_target_amount)
+	}
+
+	fn is_limit_order_expired(order: &LimitOrder<T::BlockNumber>) -> bool {
+		frame_system::Pallet::<T>::block_number() > order.expiry
+	}
+
+	fn is_limit_order_fillable(order: &LimitOrder<T::BlockNumber>) -> bool {
+		Self::get_best_path_with_exact_supply(order.supply_currency_id, order.target_currency_id, order.supply_amount)
+			.map_or(false, |(_, target_amount)| target_amount >= order.min_target_amount)
+	}
+
+	fn do_place_limit_order(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		expiry: T::BlockNumber,
+	) -> sp_std::result::Result<LimitOrderId, DispatchError> {
+		ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+		ensure!(!min_target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
+		ensure!(
+			expiry >= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::InvalidExpiry
+		);
+		ensure!(
+			!Self::get_trading_paths(supply_currency_id, target_currency_id).is_empty(),
+			Error::<T>::NoAvailableTradingPath
+		);
+		let order_count = Self::limit_order_count(who);
+		ensure!(
+			order_count < T::MaxLimitOrdersPerAccount::get(),
+			Error::<T>::TooManyLimitOrders
+		);
+
+		let order_id =
+			NextLimitOrderId::<T>::try_mutate(|next_id| -> sp_std::result::Result<LimitOrderId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+		let deposit = T::LimitOrderDeposit::get();
+		T::Currency::reserve(supply_currency_id, who, supply_amount)?;
+		T::Currency::reserve(T::GetNativeCurrencyId::get(), who, deposit)?;
+		LimitOrders::<T>::insert(
+			who,
+			order_id,
+			LimitOrder {
+				supply_currency_id,
+				target_currency_id,
+				supply_amount,
+				min_target_amount,
+				expiry,
+				deposit,
+			},
+		);
+		LimitOrderCount::<T>::insert(who, order_count.saturating_add(1));
+
+		Self::deposit_event(Event::PlaceLimitOrder(
+			who.clone(),
+			order_id,
+			supply_currency_id,
+			target_currency_id,
+			supply_amount,
+			min_target_amount,
+			expiry,
+		));
+		Ok(order_id)
+	}
+
+	/// Remove the limit order and unreserve the supply amount and deposit of
+	/// it.
+	fn remove_limit_order(owner: &T::AccountId, order_id: LimitOrderId, order: &LimitOrder<T::BlockNumber>) {
+		LimitOrders::<T>::remove(owner, order_id);
+		LimitOrderCount::<T>::mutate_exists(owner, |count| {
+			*count = count
+				.map(|count| count.saturating_sub(1))
+				.filter(|count| !count.is_zero());
+		});
+		T::Currency::unreserve(order.supply_currency_id, owner, order.supply_amount);
+		T::Currency::unreserve(T::GetNativeCurrencyId::get(), owner, order.deposit);
+	}
+
+	/// Fill the limit order through the best trading path.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	fn do_fill_limit_order(owner: &T::AccountId, order_id: LimitOrderId) -> DispatchResult {
+		let order = Self::limit_orders(owner, order_id).ok_or(Error::<T>::LimitOrderNotFound)?;
+		ensure!(!Self::is_limit_order_expired(&order), Error::<T>::LimitOrderExpired);
+
+		let (path, _) = Self::get_best_path_with_exact_supply(
+			order.supply_currency_id,
+			order.target_currency_id,
+			order.supply_amount,
+		)
+		.ok_or(Error::<T>::NoAvailableTradingPath)?;
+
+		Self::remove_limit_order(owner, order_id, &order);
+		let target_amount =
+			Self::do_swap_with_exact_supply(owner, &path, order.supply_amount, order.min_target_amount)?;
+
+		Self::deposit_event(Event::FillLimitOrder(
+			owner.clone(),
+			order_id,
+			path,
+			order.supply_amount,
+			target_amount,
+		));
+		Ok(())
+	}
+
+	fn do_remove_expired_limit_order(owner: &T::AccountId, order_id: LimitOrderId) -> DispatchResult {
+		let order = Self::limit_orders(owner, order_id).ok_or(Error::<T>::LimitOrderNotFound)?;
+		ensure!(Self::is_limit_order_expired(&order), Error::<T>::LimitOrderNotExpired);
+
+		Self::remove_limit_order(owner, order_id, &order);
+
+		Self::deposit_event(Event::ExpireLimitOrder(owner.clone(), order_id));
+		Ok(())
+	}
+
+	fn submit_unsigned_fill_limit_order_tx(owner: T::AccountId, order_id: LimitOrderId) {
+		let owner = T::Lookup::unlookup(owner);
+		let call = Call::<T>::fill_limit_order {
+			owner: owner.clone(),
+			order_id,
+		};
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "dex offchain worker",
+				"submit unsigned fill limit order tx for \nLimitOrder - AccountId {:?} OrderId {:?} \nfailed!",
+				owner, order_id,
+			);
+		}
+	}
+
+	fn submit_unsigned_remove_expired_limit_order_tx(owner: T::AccountId, order_id: LimitOrderId) {
+		let owner = T::Lookup::unlookup(owner);
+		let call = Call::<T>::remove_expired_limit_order {
+			owner: owner.clone(),
+			order_id,
+		};
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "dex offchain worker",
+				"submit unsigned remove expired limit order tx for \nLimitOrder - AccountId {:?} OrderId {:?} \nfailed!",
+				owner, order_id,
+			);
+		}
+	}
+
+	fn _offchain_worker(now: T::BlockNumber) -> Result<(), OffchainErr> {
+		// check if we are a potential validator
+		if !sp_io::offchain::is_validator() {
+			return Err(OffchainErr::NotValidator);
+		}
+
+		// acquire offchain worker lock
+		let lock_expiration = Duration::from_millis(LOCK_DURATION);
+		let mut lock = StorageLock::<'_, Time>::with_deadline(OFFCHAIN_WORKER_LOCK, lock_expiration);
+		let mut guard = lock.try_lock().map_err(|_| OffchainErr::OffchainLock)?;
+
+		let to_be_continue = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA);
+
+		// get to_be_continue record
+		let start_key = if let Ok(Some(maybe_last_iterator_previous_key)) = to_be_continue.get::<Option<Vec<u8>>>() {
+			maybe_last_iterator_previous_key
+		} else {
+			None
+		};
+
+		// get the max iterationns config
+		let max_iterations = StorageValueRef::persistent(OFFCHAIN_WORKER_MAX_ITERATIONS)
+			.get::<u32>()
+			.unwrap_or(Some(DEFAULT_MAX_ITERATIONS))
+			.unwrap_or(DEFAULT_MAX_ITERATIONS);
+
+		// If start key is Some(value) continue iterating from that point in storage otherwise start
+		// iterating from the beginning of <LimitOrders<T>>
+		let mut map_iterator = match start_key.clone() {
+			Some(key) => LimitOrders::<T>::iter_from(key),
+			None => LimitOrders::<T>::iter(),
+		};
+
+		let mut finished = true;
+		let mut iteration_count = 0;
+		let iteration_start_time = sp_io::offchain::timestamp();
+
+		#[allow(clippy::while_let_on_iterator)]
+		while let Some((owner, order_id, order)) = map_iterator.next() {
+			if now > order.expiry {
+				Self::submit_unsigned_remove_expired_limit_order_tx(owner, order_id);
+			} else if Self::is_limit_order_fillable(&order) {
+				Self::submit_unsigned_fill_limit_order_tx(owner, order_id);
+			}
+
+			iteration_count += 1;
+			if iteration_count == max_iterations {
+				finished = false;
+				break;
+			}
+			// extend offchain worker lock
+			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+		}
+		let iteration_end_time = sp_io::offchain::timestamp();
+		log::debug!(
+			target: "dex offchain worker",
+			"iteration info:\n max iterations is {:?}\n start key: {:?}, iterate count: {:?}\n iteration start at: {:?}, end at: {:?}, execution time: {:?}\n",
+			max_iterations,
+			start_key,
+			iteration_count,
+			iteration_start_time,
+			iteration_end_time,
+			iteration_end_time.diff(&iteration_start_time)
+		);
+
+		// if iteration for map storage finished, clear to be continue record
+		// otherwise, update to be continue record
+		if finished {
+			to_be_continue.set(&Option::<Vec<u8>>::None);
+		} else {
+			to_be_continue.set(&Some(map_iterator.last_raw_key()));
+		}
+
+		// Consume the guard but **do not** unlock the underlying lock.
+		guard.forget();
+
+		Ok(())
+	}
+}
+
+/// Helpers of `DexApi` runtime api.
+impl<T: Config> Pallet<T> {
+	pub fn swap_target_amount_info(path: &[CurrencyId], supply_amount: Balance) -> Option<BalanceInfo<Balance>> {
+		Self::get_swap_target_amount(path, supply_amount).map(|amount| BalanceInfo { amount })
+	}
+
+	pub fn swap_supply_amount_info(path: &[CurrencyId], target_amount: Balance) -> Option<BalanceInfo<Balance>> {
+		Self::get_swap_supply_amount(path, target_amount).map(|amount| BalanceInfo { amount })
+	}
+
+	pub fn best_swap_with_exact_supply_info(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<SwapPathInfo<Balance>> {
+		Self::get_best_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount).map(
+			|(path, target_amount)| SwapPathInfo {
+				path,
+				supply_amount,
+				target_amount,
+			},
+		)
+	}
+
+	pub fn best_swap_with_exact_target_info(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<SwapPathInfo<Balance>> {
+		Self::get_best_path_with_exact_target(supply_currency_id, target_currency_id, target_amount).map(
+			|(path, supply_amount)| SwapPathInfo {
+				path,
+				supply_amount,
+				target_amount,
+			},
+		)
+	}
+
+	pub fn liquidity_pool_info(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> LiquidityPoolInfo<Balance> {
+		let (amount_a, amount_b) = Self::get_liquidity(currency_id_a, currency_id_b);
+		LiquidityPoolInfo { amount_a, amount_b }
+	}
+
+	/// Get the parameters of a Provisioning trading pair, with the amounts
+	/// ordered as currency A and currency B.
+	pub fn provisioning_info(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+	) -> Option<ProvisioningInfo<Balance, T::BlockNumber>> {
+		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
+		match Self::trading_pair_statuses(trading_pair) {
+			TradingPairStatus::Provisioning(parameters) => {
+				let ordered = |(amount_0, amount_1): (Balance, Balance)| {
+					let (amount_a, amount_b) = if currency_id_a == trading_pair.first() {
+						(amount_0, amount_1)
+					} else {
+						(amount_1, amount_0)
+					};
+					LiquidityPoolInfo { amount_a, amount_b }
+				};
+				Some(ProvisioningInfo {
+					min_contribution: ordered(parameters.min_contribution),
+					target_provision: ordered(parameters.target_provision),
+					accumulated_provision: ordered(parameters.accumulated_provision),
+					not_before: parameters.not_before,
+				})
+			}
+			_ => None,
+		}
+	}
+
+	pub fn liquidity_share_value_info(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		share_amount: Balance,
+	) -> LiquidityPoolInfo<Balance> {
+		let (amount_a, amount_b) = Self::get_liquidity_share_value(currency_id_a, currency_id_b, share_amount);
+		LiquidityPoolInfo { amount_a, amount_b }
+	}
 }
 
 impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
@@ -1239,6 +2810,38 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 			.map(|amounts| amounts[0])
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		Self::get_best_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+			.map(|(path, _)| path)
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		Self::get_best_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+			.map(|(path, _)| path)
+	}
+
+	fn get_limit_orders(who: &T::AccountId) -> Vec<(LimitOrderId, CurrencyId, CurrencyId, Balance, Balance)> {
+		LimitOrders::<T>::iter_prefix(who)
+			.map(|(order_id, order)| {
+				(
+					order_id,
+					order.supply_currency_id,
+					order.target_currency_id,
+					order.supply_amount,
+					order.min_target_amount,
+				)
+			})
+			.collect()
+	}
+
 	fn swap_with_exact_supply(
 		who: &T::AccountId,
 		path: &[CurrencyId],
@@ -1302,3 +2905,64 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		)
 	}
 }
+
+/// PriceProvider that provides the time-weighted average prices of the DEX
+/// pools paired with stable currency, which is priced at
+/// `StableCurrencyPrice`.
+pub struct DexTwapPriceProvider<T, GetStableCurrencyId, StableCurrencyPrice>(
+	PhantomData<(T, GetStableCurrencyId, StableCurrencyPrice)>,
+);
+impl<T, GetStableCurrencyId, StableCurrencyPrice> PriceProvider<CurrencyId>
+	for DexTwapPriceProvider<T, GetStableCurrencyId, StableCurrencyPrice>
+where
+	T: Config,
+	GetStableCurrencyId: Get<CurrencyId>,
+	StableCurrencyPrice: Get<Price>,
+{
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		let stable_currency_id = GetStableCurrencyId::get();
+		if currency_id == stable_currency_id {
+			Some(StableCurrencyPrice::get())
+		} else {
+			Pallet::<T>::get_twap_price(currency_id, stable_currency_id)?.checked_mul(&StableCurrencyPrice::get())
+		}
+	}
+}
+
+/// PriceProvider that provides the market price of stable currency, which is
+/// derived from the price of `ReferenceCurrencyId` provided by
+/// `ReferencePriceSource` and the time-weighted average price of the DEX pool
+/// of `ReferenceCurrencyId` and stable currency. The price is unavailable if
+/// the time-weighted average price has not been updated within `MaxAge`.
+pub struct DexStableCurrencyPriceProvider<T, GetStableCurrencyId, ReferenceCurrencyId, ReferencePriceSource, MaxAge>(
+	PhantomData<(
+		T,
+		GetStableCurrencyId,
+		ReferenceCurrencyId,
+		ReferencePriceSource,
+		MaxAge,
+	)>,
+);
+impl<T, GetStableCurrencyId, ReferenceCurrencyId, ReferencePriceSource, MaxAge> PriceProvider<CurrencyId>
+	for DexStableCurrencyPriceProvider<T, GetStableCurrencyId, ReferenceCurrencyId, ReferencePriceSource, MaxAge>
+where
+	T: Config,
+	GetStableCurrencyId: Get<CurrencyId>,
+	ReferenceCurrencyId: Get<CurrencyId>,
+	ReferencePriceSource: PriceProvider<CurrencyId>,
+	MaxAge: Get<T::BlockNumber>,
+{
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		let stable_currency_id = GetStableCurrencyId::get();
+		if currency_id != stable_currency_id {
+			return None;
+		}
+
+		let reference_currency_id = ReferenceCurrencyId::get();
+		ReferencePriceSource::get_price(reference_currency_id)?.checked_div(&Pallet::<T>::get_twap_price_within(
+			reference_currency_id,
+			stable_currency_id,
+			MaxAge::get(),
+		)?)
+	}
+}
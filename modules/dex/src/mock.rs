@@ -29,13 +29,17 @@ use frame_system::EnsureSignedBy;
 use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
 use primitives::{Amount, TokenSymbol};
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::IdentityLookup,
+};
 
 pub type BlockNumber = u64;
 pub type AccountId = u128;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CAROL: AccountId = 4;
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -118,7 +122,21 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const SwapSplitParts: u32 = 4;
+	pub static MaxSearchedTradingPairs: u32 = 10;
+	pub static MaxSearchedTradingPaths: u32 = 10;
+	pub const LimitOrderDeposit: Balance = 1_000_000;
+	pub static MaxLimitOrdersPerAccount: u32 = 10;
+	pub const TwapWindow: BlockNumber = 10;
+	pub const TwapMaxAge: BlockNumber = 20;
+	pub const StableCurrencyTwapMaxAge: BlockNumber = 5;
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub StableCurrencyFixedPrice: Price = Price::one();
+	pub const GetDOTCurrencyId: CurrencyId = DOT;
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const ProtocolFeeReceiver: AccountId = 10;
 }
 
 impl Config for Runtime {
@@ -126,11 +144,22 @@ impl Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = ();
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type UnsignedPriority = UnsignedPriority;
+	type ProtocolFeeReceiver = ProtocolFeeReceiver;
+	type Call = Call;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -143,11 +172,21 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
-		DexModule: dex::{Pallet, Storage, Call, Event<T>, Config<T>},
+		DexModule: dex::{Pallet, Storage, Call, Event<T>, Config<T>, ValidateUnsigned},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 	}
 );
 
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
 pub struct ExtBuilder {
 	balances: Vec<(AccountId, CurrencyId, Balance)>,
 	initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), BlockNumber)>,
@@ -165,6 +204,8 @@ impl Default for ExtBuilder {
 				(BOB, BTC, 1_000_000_000_000_000_000u128),
 				(ALICE, DOT, 1_000_000_000_000_000_000u128),
 				(BOB, DOT, 1_000_000_000_000_000_000u128),
+				(ALICE, ACA, 1_000_000_000_000_000_000u128),
+				(BOB, ACA, 1_000_000_000_000_000_000u128),
 			],
 			initial_listing_trading_pairs: vec![],
 			initial_enabled_trading_pairs: vec![],
@@ -23,10 +23,14 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE,
-	AUSD, BOB, BTC, DOT,
+	AUSDBTCPair, AUSDDOTPair, Call as MockCall, DexModule, Event, ExtBuilder, Extrinsic, GetDOTCurrencyId,
+	GetStableCurrencyId, LimitOrderDeposit, ListingOrigin, MaxLimitOrdersPerAccount, MaxSearchedTradingPairs,
+	MaxSearchedTradingPaths, Origin, ProtocolFeeReceiver, Runtime, StableCurrencyFixedPrice, StableCurrencyTwapMaxAge,
+	System, Tokens, ACA, ALICE, AUSD, BOB, BTC, CAROL, DOT,
 };
 use orml_traits::MultiReservableCurrency;
+use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+use sp_io::offchain;
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -44,6 +48,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			BadOrigin
 		);
@@ -61,6 +66,7 @@ fn list_provisioning_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -83,6 +89,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			Error::<Runtime>::InvalidCurrencyId
 		);
@@ -97,6 +104,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			Error::<Runtime>::MustBeDisabled
 		);
@@ -145,6 +153,7 @@ fn update_provisioning_parameters_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -184,7 +193,7 @@ fn enable_diabled_trading_pair_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT),
+			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT, SwapCurve::ConstantProduct),
 			BadOrigin
 		);
 
@@ -195,7 +204,8 @@ fn enable_diabled_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -204,7 +214,12 @@ fn enable_diabled_trading_pair_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), DOT, AUSD),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				SwapCurve::ConstantProduct
+			),
 			Error::<Runtime>::AlreadyEnabled
 		);
 	});
@@ -224,6 +239,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::list_provisioning(
 			Origin::signed(ListingOrigin::get()),
@@ -234,6 +250,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -255,7 +272,8 @@ fn enable_provisioning_without_provision_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -264,7 +282,12 @@ fn enable_provisioning_without_provision_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				BTC,
+				SwapCurve::ConstantProduct
+			),
 			Error::<Runtime>::StillProvisioning
 		);
 	});
@@ -284,6 +307,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -304,6 +328,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -373,6 +398,44 @@ fn end_provisioning_trading_work() {
 	});
 }
 
+#[test]
+fn enable_trading_pair_fails_when_too_many_enabled_trading_pairs() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxSearchedTradingPairs::set(1);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::ConstantProduct,
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				BTC,
+				SwapCurve::ConstantProduct,
+			),
+			Error::<Runtime>::TooManyEnabledTradingPairs
+		);
+
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC,
+			SwapCurve::ConstantProduct,
+		));
+		assert_eq!(
+			DexModule::enabled_trading_pairs().into_inner(),
+			vec![AUSDBTCPair::get()]
+		);
+	});
+}
+
 #[test]
 fn disable_trading_pair_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -381,12 +444,17 @@ fn disable_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
 			TradingPairStatus::<_, _>::Enabled
 		);
+		assert_eq!(
+			DexModule::enabled_trading_pairs().into_inner(),
+			vec![AUSDDOTPair::get()]
+		);
 
 		assert_noop!(
 			DexModule::disable_trading_pair(Origin::signed(ALICE), AUSD, DOT),
@@ -402,6 +470,7 @@ fn disable_trading_pair_work() {
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
 			TradingPairStatus::<_, _>::Disabled
 		);
+		assert_eq!(DexModule::enabled_trading_pairs().into_inner(), vec![]);
 		System::assert_last_event(Event::DexModule(crate::Event::DisableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
@@ -418,6 +487,7 @@ fn disable_trading_pair_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_noop!(
 			DexModule::disable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
@@ -451,6 +521,7 @@ fn add_provision_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 
 		assert_noop!(
@@ -533,6 +604,7 @@ fn claim_dex_share_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			0,
+			SwapCurve::ConstantProduct,
 		));
 
 		assert_ok!(DexModule::add_provision(
@@ -623,26 +695,26 @@ fn get_liquidity_work() {
 #[test]
 fn get_target_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_target_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000), 9949);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_target_amount(10000, 0, 1000, (1, 100)), 0);
+		assert_eq!(DexModule::get_target_amount(0, 20000, 1000, (1, 100)), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 0, (1, 100)), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000, (1, 100)), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000, (1, 100)), 9949);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000, (1, 100)), 1801);
 	});
 }
 
 #[test]
 fn get_supply_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 1, 1), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949), 9999);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999), 9949);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801), 1000);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000, (1, 100)), 0);
+		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000, (1, 100)), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0, (1, 100)), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 1, 1, (1, 100)), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949, (1, 100)), 9999);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999, (1, 100)), 9949);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801, (1, 100)), 1000);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000, (1, 100)), 1801);
 	});
 }
 
@@ -696,7 +768,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_supply_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				1_000_000_000_000_000_000_000
+				1_000_000_000_000_000_000_000,
+				(1, 100)
 			),
 			3_140_495_867_768_595_041_323
 		);
@@ -704,7 +777,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_target_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				3_140_495_867_768_595_041_323
+				3_140_495_867_768_595_041_323,
+				(1, 100)
 			),
 			1_000_000_000_000_000_000_000
 		);
@@ -1338,3 +1412,1241 @@ fn initialize_added_liquidity_pools_genesis_work() {
 			);
 		});
 }
+
+#[test]
+fn get_trading_paths_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				DexModule::get_trading_paths(DOT, BTC),
+				vec![vec![DOT, BTC], vec![DOT, AUSD, BTC]]
+			);
+			assert_eq!(
+				DexModule::get_trading_paths(DOT, AUSD),
+				vec![vec![DOT, AUSD], vec![DOT, BTC, AUSD]]
+			);
+			assert_eq!(DexModule::get_trading_paths(DOT, DOT), Vec::<Vec<CurrencyId>>::new());
+			assert_eq!(DexModule::get_trading_paths(DOT, ACA), Vec::<Vec<CurrencyId>>::new());
+
+			assert_ok!(DexModule::disable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				BTC
+			));
+			assert_eq!(DexModule::get_trading_paths(DOT, BTC), vec![vec![DOT, AUSD, BTC]]);
+
+			// only the shortest `MaxSearchedTradingPaths` trading paths are searched
+			MaxSearchedTradingPaths::set(1);
+			assert_eq!(DexModule::get_trading_paths(DOT, AUSD), vec![vec![DOT, AUSD]]);
+			MaxSearchedTradingPaths::set(0);
+			assert_eq!(DexModule::get_trading_paths(DOT, BTC), Vec::<Vec<CurrencyId>>::new());
+			assert_eq!(DexModule::get_best_path_with_exact_supply(DOT, BTC, 1_000), None);
+			assert_eq!(DexModule::get_best_path_with_exact_target(DOT, BTC, 1_000), None);
+		});
+}
+
+#[test]
+fn get_best_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				DexModule::get_best_path_with_exact_supply(DOT, BTC, 1_000_000_000_000),
+				None
+			);
+			assert_eq!(DexModule::get_best_path_with_exact_target(DOT, BTC, 1_000_000), None);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000_000_000,
+				100_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::get_best_path_with_exact_supply(DOT, BTC, 1_000_000_000_000),
+				Some((vec![DOT, BTC], 49_748_743))
+			);
+			assert_eq!(
+				DexModule::get_best_path_with_exact_target(DOT, BTC, 1_000_000),
+				Some((vec![DOT, BTC], 10_203_040_507))
+			);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::get_best_path_with_exact_supply(DOT, BTC, 1_000_000_000_000),
+				Some((vec![DOT, AUSD, BTC], 462_789_390))
+			);
+			assert_eq!(
+				DexModule::get_best_path_with_exact_target(DOT, BTC, 1_000_000),
+				Some((vec![DOT, AUSD, BTC], 2_040_853_417))
+			);
+			assert_eq!(DexModule::get_best_path_with_exact_supply(DOT, ACA, 1_000_000), None);
+		});
+}
+
+#[test]
+fn swap_by_best_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000_000_000,
+				100_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_path(Origin::signed(BOB), DOT, ACA, 1_000_000_000_000, 0),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_path(
+					Origin::signed(BOB),
+					DOT,
+					BTC,
+					1_000_000_000_000,
+					462_789_391
+				),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target_by_best_path(Origin::signed(BOB), DOT, BTC, 1_000_000, 2_040_853_416),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+
+			assert_ok!(DexModule::swap_with_exact_target_by_best_path(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				1_000_000,
+				2_040_853_417
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				vec![2_040_853_417, 10_102_020_304, 1_000_000],
+			)));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_999_997_959_146_583);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_001_000_000);
+		});
+}
+
+#[test]
+fn do_swap_with_exact_supply_by_split_paths_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				100_000_000_000_000,
+				10_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				100_000_000_000_000,
+				50_000_000_000,
+				0,
+				false,
+			));
+
+			// swap through the best single path
+			assert_eq!(
+				DexModule::get_best_path_with_exact_supply(DOT, BTC, 10_000_000_000_000),
+				Some((vec![DOT, BTC], 4_504_094_631))
+			);
+
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply_by_split_paths(&BOB, DOT, BTC, 0, 0, 4),
+				Error::<Runtime>::ZeroSupplyAmount
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply_by_split_paths(&BOB, DOT, ACA, 10_000_000_000_000, 0, 4),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply_by_split_paths(
+					&BOB,
+					DOT,
+					BTC,
+					10_000_000_000_000,
+					4_522_989_175,
+					4
+				),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+
+			assert_eq!(
+				DexModule::do_swap_with_exact_supply_by_split_paths(
+					&BOB,
+					DOT,
+					BTC,
+					10_000_000_000_000,
+					4_522_989_174,
+					4
+				),
+				Ok(4_522_989_174)
+			);
+			// a swap event for each trading path used
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, BTC],
+				vec![7_500_000_000_000, 3_455_121_097],
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				vec![2_500_000_000_000, 12_076_116_125_884, 1_067_868_077],
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(487_923_883_874_116, 102_500_000_000_000)
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, BTC),
+				(112_076_116_125_884, 8_932_131_923)
+			);
+			assert_eq!(
+				DexModule::get_liquidity(DOT, BTC),
+				(107_500_000_000_000, 46_544_878_903)
+			);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_990_000_000_000_000);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_004_522_989_174);
+
+			// the supply amount less than the parts is swapped as a single part
+			let (path, target_amount) = DexModule::get_best_path_with_exact_supply(DOT, BTC, 1_000_000_000).unwrap();
+			let amounts = DexModule::get_target_amounts(&path, 1_000_000_000).unwrap();
+			assert_eq!(
+				DexModule::do_swap_with_exact_supply_by_split_paths(&BOB, DOT, BTC, 1_000_000_000, 0, u32::MAX),
+				Ok(target_amount)
+			);
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(BOB, path, amounts)));
+		});
+}
+
+#[test]
+fn place_and_cancel_limit_order_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::place_limit_order(Origin::signed(BOB), DOT, BTC, 0, 1_000_000, 10),
+				Error::<Runtime>::ZeroSupplyAmount
+			);
+			assert_noop!(
+				DexModule::place_limit_order(Origin::signed(BOB), DOT, BTC, 10_000_000_000, 0, 10),
+				Error::<Runtime>::ZeroTargetAmount
+			);
+			assert_noop!(
+				DexModule::place_limit_order(Origin::signed(BOB), DOT, BTC, 10_000_000_000, 1_000_000, 0),
+				Error::<Runtime>::InvalidExpiry
+			);
+			assert_noop!(
+				DexModule::place_limit_order(Origin::signed(BOB), DOT, ACA, 10_000_000_000, 1_000_000, 10),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+
+			assert_eq!(DexModule::next_limit_order_id(), 0);
+			assert_ok!(DexModule::place_limit_order(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				10_000_000_000,
+				1_000_000,
+				10
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::PlaceLimitOrder(
+				BOB,
+				0,
+				DOT,
+				BTC,
+				10_000_000_000,
+				1_000_000,
+				10,
+			)));
+			assert_eq!(DexModule::next_limit_order_id(), 1);
+			assert_eq!(
+				DexModule::limit_orders(BOB, 0),
+				Some(LimitOrder {
+					supply_currency_id: DOT,
+					target_currency_id: BTC,
+					supply_amount: 10_000_000_000,
+					min_target_amount: 1_000_000,
+					expiry: 10,
+					deposit: LimitOrderDeposit::get(),
+				})
+			);
+			assert_eq!(DexModule::limit_order_count(BOB), 1);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_999_990_000_000_000);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 10_000_000_000);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), LimitOrderDeposit::get());
+
+			assert_ok!(DexModule::place_limit_order(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				20_000_000_000,
+				2_000_000_000,
+				20
+			));
+			let mut orders = <DexModule as DEXManager<_, _, _>>::get_limit_orders(&BOB);
+			orders.sort_by_key(|(order_id, ..)| *order_id);
+			assert_eq!(
+				orders,
+				vec![
+					(0, DOT, BTC, 10_000_000_000, 1_000_000),
+					(1, AUSD, DOT, 20_000_000_000, 2_000_000_000)
+				]
+			);
+			assert_eq!(<DexModule as DEXManager<_, _, _>>::get_limit_orders(&ALICE), vec![]);
+			assert_eq!(DexModule::limit_order_count(BOB), 2);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 2 * LimitOrderDeposit::get());
+
+			// BOB can not place more limit orders than `MaxLimitOrdersPerAccount`
+			MaxLimitOrdersPerAccount::set(2);
+			assert_noop!(
+				DexModule::place_limit_order(Origin::signed(BOB), DOT, BTC, 10_000_000_000, 1_000_000, 10),
+				Error::<Runtime>::TooManyLimitOrders
+			);
+
+			assert_noop!(
+				DexModule::cancel_limit_order(Origin::signed(ALICE), 0),
+				Error::<Runtime>::LimitOrderNotFound
+			);
+			assert_ok!(DexModule::cancel_limit_order(Origin::signed(BOB), 0));
+			System::assert_last_event(Event::DexModule(crate::Event::CancelLimitOrder(BOB, 0)));
+			assert_eq!(DexModule::limit_orders(BOB, 0), None);
+			assert_eq!(DexModule::limit_order_count(BOB), 1);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+			assert_eq!(Tokens::reserved_balance(AUSD, &BOB), 20_000_000_000);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), LimitOrderDeposit::get());
+
+			assert_ok!(DexModule::place_limit_order(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				10_000_000_000,
+				1_000_000,
+				10
+			));
+			assert_eq!(DexModule::limit_order_count(BOB), 2);
+		});
+}
+
+#[test]
+fn fill_limit_order_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000_000_000,
+				100_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::place_limit_order(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				10_000_000_000,
+				1_000_000,
+				10
+			));
+
+			assert_noop!(DexModule::fill_limit_order(Origin::signed(ALICE), BOB, 0), BadOrigin);
+			assert_noop!(
+				DexModule::fill_limit_order(Origin::none(), BOB, 1),
+				Error::<Runtime>::LimitOrderNotFound
+			);
+			// the pool can only provide 980_295 BTC now
+			assert_noop!(
+				DexModule::fill_limit_order(Origin::none(), BOB, 0),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+
+			// the price of DOT rises
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(ALICE),
+				vec![BTC, DOT],
+				10_000_000,
+				0
+			));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (909_918_107_371, 110_000_000));
+
+			assert_ok!(DexModule::fill_limit_order(Origin::none(), BOB, 0));
+			System::assert_last_event(Event::DexModule(crate::Event::FillLimitOrder(
+				BOB,
+				0,
+				vec![DOT, BTC],
+				10_000_000_000,
+				1_183_929,
+			)));
+			assert_eq!(DexModule::limit_orders(BOB, 0), None);
+			assert_eq!(DexModule::limit_order_count(BOB), 0);
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (919_918_107_371, 108_816_071));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_999_990_000_000_000);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_001_183_929);
+		});
+}
+
+#[test]
+fn remove_expired_limit_order_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000_000_000,
+				100_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::place_limit_order(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				10_000_000_000,
+				1,
+				10
+			));
+
+			System::set_block_number(10);
+			assert_noop!(
+				DexModule::remove_expired_limit_order(Origin::none(), BOB, 0),
+				Error::<Runtime>::LimitOrderNotExpired
+			);
+
+			System::set_block_number(11);
+			assert_noop!(
+				DexModule::fill_limit_order(Origin::none(), BOB, 0),
+				Error::<Runtime>::LimitOrderExpired
+			);
+			assert_ok!(DexModule::remove_expired_limit_order(Origin::none(), BOB, 0));
+			System::assert_last_event(Event::DexModule(crate::Event::ExpireLimitOrder(BOB, 0)));
+			assert_eq!(DexModule::limit_orders(BOB, 0), None);
+			assert_eq!(DexModule::limit_order_count(BOB), 0);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+			assert_noop!(
+				DexModule::remove_expired_limit_order(Origin::none(), BOB, 0),
+				Error::<Runtime>::LimitOrderNotFound
+			);
+		});
+}
+
+#[test]
+fn offchain_worker_submit_limit_order_tx_work() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().initialize_enabled_trading_pairs().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			DOT,
+			BTC,
+			1_000_000_000_000,
+			100_000_000,
+			0,
+			false,
+		));
+		assert_ok!(DexModule::place_limit_order(
+			Origin::signed(BOB),
+			DOT,
+			BTC,
+			10_000_000_000,
+			1_000_000,
+			10
+		));
+		assert_ok!(DexModule::place_limit_order(
+			Origin::signed(ALICE),
+			DOT,
+			BTC,
+			10_000_000_000,
+			1,
+			10
+		));
+
+		// offchain worker only submits tx to fill ALICE's order
+		DexModule::offchain_worker(System::block_number());
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			MockCall::DexModule(crate::Call::fill_limit_order {
+				owner: ALICE,
+				order_id: 1
+			})
+		);
+		assert!(pool_state.write().transactions.pop().is_none());
+		assert!(DexModule::validate_unsigned(
+			TransactionSource::Local,
+			&crate::Call::fill_limit_order {
+				owner: BOB,
+				order_id: 0
+			}
+		)
+		.is_err());
+
+		// this unlocks the concurrency storage lock so offchain_worker will fire next time
+		offchain::sleep_until(offchain::timestamp().add(Duration::from_millis(LOCK_DURATION + 200)));
+		System::set_block_number(11);
+
+		// offchain worker submits txs to remove both expired orders
+		DexModule::offchain_worker(System::block_number());
+		let mut calls = vec![];
+		while let Some(tx) = pool_state.write().transactions.pop() {
+			calls.push(Extrinsic::decode(&mut &*tx).unwrap().call);
+		}
+		assert_eq!(calls.len(), 2);
+		assert!(
+			calls.contains(&MockCall::DexModule(crate::Call::remove_expired_limit_order {
+				owner: BOB,
+				order_id: 0
+			}))
+		);
+		assert!(
+			calls.contains(&MockCall::DexModule(crate::Call::remove_expired_limit_order {
+				owner: ALICE,
+				order_id: 1
+			}))
+		);
+		assert!(DexModule::validate_unsigned(
+			TransactionSource::Local,
+			&crate::Call::remove_expired_limit_order {
+				owner: BOB,
+				order_id: 0
+			}
+		)
+		.is_ok());
+	});
+}
+
+#[test]
+fn twap_price_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000_000,
+				100_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::cumulative_prices(AUSDDOTPair::get()),
+				CumulativePrice {
+					price_0_cumulative: 0,
+					price_1_cumulative: 0,
+					last_updated: 1,
+				}
+			);
+			assert_eq!(DexModule::twap_observations(AUSDDOTPair::get()), None);
+
+			System::set_block_number(3);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				10_000_000_000,
+				0
+			));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (909_918_107_371, 110_000_000_000));
+			let cumulative_prices = CumulativePrice {
+				price_0_cumulative: 200_000_000_000_000_000,
+				price_1_cumulative: 20_000_000_000_000_000_000,
+				last_updated: 3,
+			};
+			assert_eq!(DexModule::cumulative_prices(AUSDDOTPair::get()), cumulative_prices);
+			assert_eq!(
+				DexModule::twap_observations(AUSDDOTPair::get()),
+				Some(cumulative_prices)
+			);
+			assert_eq!(DexModule::get_twap_price(DOT, AUSD), None);
+
+			// the TWAP window is not completed
+			System::set_block_number(12);
+			DexModule::accumulate_cumulative_prices(AUSDDOTPair::get(), 909_918_107_371, 110_000_000_000);
+			assert_eq!(DexModule::get_twap_price(DOT, AUSD), None);
+			assert_eq!(
+				DexModule::twap_observations(AUSDDOTPair::get()),
+				Some(cumulative_prices)
+			);
+
+			System::set_block_number(13);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				10_000_000_000,
+				0
+			));
+			let (pool_0, pool_1) = (909_918_107_371u128, 110_000_000_000u128);
+			assert_eq!(
+				DexModule::get_twap_price(AUSD, DOT),
+				ExchangeRate::checked_from_rational(pool_1, pool_0)
+			);
+			assert_eq!(
+				DexModule::get_twap_price(DOT, AUSD),
+				ExchangeRate::checked_from_rational(pool_0, pool_1)
+			);
+			assert_eq!(
+				DexModule::twap_observations(AUSDDOTPair::get()),
+				Some(DexModule::cumulative_prices(AUSDDOTPair::get()))
+			);
+
+			assert_eq!(
+				DexTwapPriceProvider::<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>::get_price(AUSD),
+				Some(Price::one())
+			);
+			assert_eq!(
+				DexTwapPriceProvider::<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>::get_price(DOT),
+				ExchangeRate::checked_from_rational(pool_0, pool_1)
+			);
+			assert_eq!(
+				DexTwapPriceProvider::<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>::get_price(BTC),
+				None
+			);
+
+			type StablePriceProvider = DexStableCurrencyPriceProvider<
+				Runtime,
+				GetStableCurrencyId,
+				GetDOTCurrencyId,
+				DexTwapPriceProvider<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>,
+				StableCurrencyTwapMaxAge,
+			>;
+			assert_eq!(StablePriceProvider::get_price(AUSD), Some(Price::one()));
+			assert_eq!(StablePriceProvider::get_price(DOT), None);
+
+			// the TWAP is stale for the stable currency price without being updated
+			// within `StableCurrencyTwapMaxAge`
+			System::set_block_number(18);
+			assert_eq!(
+				DexModule::get_twap_price_within(DOT, AUSD, StableCurrencyTwapMaxAge::get()),
+				ExchangeRate::checked_from_rational(pool_0, pool_1)
+			);
+			System::set_block_number(19);
+			assert_eq!(StablePriceProvider::get_price(AUSD), None);
+			assert_eq!(
+				DexModule::get_twap_price_within(DOT, AUSD, StableCurrencyTwapMaxAge::get()),
+				None
+			);
+			assert_eq!(
+				DexModule::get_twap_price(DOT, AUSD),
+				ExchangeRate::checked_from_rational(pool_0, pool_1)
+			);
+
+			// the TWAP is stale without being updated within `TwapMaxAge`
+			System::set_block_number(33);
+			assert_eq!(
+				DexModule::get_twap_price(DOT, AUSD),
+				ExchangeRate::checked_from_rational(pool_0, pool_1)
+			);
+			System::set_block_number(34);
+			assert_eq!(DexModule::get_twap_price(DOT, AUSD), None);
+			assert_eq!(
+				DexTwapPriceProvider::<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>::get_price(DOT),
+				None
+			);
+		});
+}
+
+#[test]
+fn set_trading_pair_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::set_trading_pair_fee(Origin::signed(ALICE), AUSD, DOT, Some((3, 1000))),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::set_trading_pair_fee(Origin::signed(ListingOrigin::get()), AUSD, AUSD, Some((3, 1000))),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::set_trading_pair_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((1, 0))),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+			assert_noop!(
+				DexModule::set_trading_pair_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((100, 100))),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+
+			assert_eq!(DexModule::trading_pair_fees(AUSDDOTPair::get()), None);
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (1, 100));
+
+			assert_ok!(DexModule::set_trading_pair_fee(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				Some((3, 1000))
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::TradingPairFeeUpdated(
+				AUSDDOTPair::get(),
+				Some((3, 1000)),
+			)));
+			assert_eq!(DexModule::trading_pair_fees(AUSDDOTPair::get()), Some((3, 1000)));
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (3, 1000));
+			assert_eq!(DexModule::get_exchange_fee(AUSDBTCPair::get()), (1, 100));
+
+			assert_ok!(DexModule::set_trading_pair_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				None
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::TradingPairFeeUpdated(
+				AUSDDOTPair::get(),
+				None,
+			)));
+			assert_eq!(DexModule::trading_pair_fees(AUSDDOTPair::get()), None);
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (1, 100));
+		});
+}
+
+#[test]
+fn set_protocol_fee_share_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::set_protocol_fee_share(Origin::signed(ALICE), Ratio::saturating_from_rational(1, 2)),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::set_protocol_fee_share(
+				Origin::signed(ListingOrigin::get()),
+				Ratio::saturating_from_rational(3, 2)
+			),
+			Error::<Runtime>::InvalidProtocolFeeShare
+		);
+
+		assert_eq!(DexModule::protocol_fee_share(), Ratio::zero());
+		assert_ok!(DexModule::set_protocol_fee_share(
+			Origin::signed(ListingOrigin::get()),
+			Ratio::saturating_from_rational(1, 2)
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ProtocolFeeShareUpdated(
+			Ratio::saturating_from_rational(1, 2),
+		)));
+		assert_eq!(DexModule::protocol_fee_share(), Ratio::saturating_from_rational(1, 2));
+	});
+}
+
+#[test]
+fn swap_with_trading_pair_fee_and_protocol_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::get_swap_target_amount(&[DOT, AUSD], 1_000_000_000_000),
+				Some(4_901_475_393_603)
+			);
+
+			assert_ok!(DexModule::set_trading_pair_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((3, 1000))
+			));
+			assert_eq!(
+				DexModule::get_swap_target_amount(&[DOT, AUSD], 1_000_000_000_000),
+				Some(4_935_790_171_985)
+			);
+
+			assert_ok!(DexModule::set_protocol_fee_share(
+				Origin::signed(ListingOrigin::get()),
+				Ratio::saturating_from_rational(1, 2)
+			));
+			assert_eq!(Tokens::free_balance(DOT, &ProtocolFeeReceiver::get()), 0);
+
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				1_000_000_000_000,
+				0,
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::ChargeProtocolFee(
+				AUSDDOTPair::get(),
+				DOT,
+				1_500_000_000,
+			)));
+			assert_eq!(Tokens::free_balance(DOT, &ProtocolFeeReceiver::get()), 1_500_000_000);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(495_064_209_828_015, 100_998_500_000_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				495_064_209_828_015
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 100_998_500_000_000);
+		});
+}
+
+#[test]
+fn enable_trading_pair_with_stable_swap_curve_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::StableSwap { amplification: 0 }
+			),
+			Error::<Runtime>::InvalidSwapCurve
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::StableSwap {
+					amplification: MAX_AMPLIFICATION + 1
+				}
+			),
+			Error::<Runtime>::InvalidSwapCurve
+		);
+
+		assert_eq!(
+			DexModule::trading_pair_curves(AUSDDOTPair::get()),
+			SwapCurve::ConstantProduct
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::StableSwap { amplification: 100 }
+		));
+		assert_eq!(
+			DexModule::trading_pair_curves(AUSDDOTPair::get()),
+			SwapCurve::StableSwap { amplification: 100 }
+		);
+
+		// the swap curve can not be changed once the pool has liquidity
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000,
+			1_000_000_000_000,
+			0,
+			false,
+		));
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::ConstantProduct
+			),
+			Error::<Runtime>::CannotChangeSwapCurve
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::StableSwap { amplification: 100 }
+		));
+	});
+}
+
+#[test]
+fn stable_swap_curve_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::StableSwap { amplification: 100 }
+		));
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000_000,
+			1_000_000_000_000_000,
+			0,
+			false,
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
+			ALICE,
+			AUSD,
+			1_000_000_000_000_000,
+			DOT,
+			1_000_000_000_000_000,
+			2_000_000_000_000_000,
+		)));
+
+		// constant product curve would only give 9_802_950_787_206
+		assert_eq!(
+			DexModule::get_swap_target_amount(&[AUSD, DOT], 10_000_000_000_000),
+			Some(9_899_512_364_761)
+		);
+		assert_eq!(
+			DexModule::get_swap_supply_amount(&[AUSD, DOT], 9_899_512_364_761),
+			Some(10_000_000_000_001)
+		);
+
+		assert_ok!(DexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			10_000_000_000_000,
+			9_899_512_364_761,
+		));
+		assert_eq!(
+			DexModule::get_liquidity(AUSD, DOT),
+			(1_010_000_000_000_000, 990_100_487_635_239)
+		);
+		assert_eq!(
+			Tokens::free_balance(DOT, &BOB),
+			1_000_000_000_000_000_000 + 9_899_512_364_761
+		);
+
+		// the invariant check of StableSwap curve
+		assert_noop!(
+			DexModule::_swap(AUSD, DOT, 10_000_000_000_000, 10_000_000_000_000),
+			Error::<Runtime>::InvariantCheckFailed
+		);
+
+		// the cumulative prices are accumulated with the marginal prices of StableSwap curve
+		let (pool_0, pool_1) = (1_010_000_000_000_000u128, 990_100_487_635_239u128);
+		let (price_0, price_1) = DexModule::get_marginal_prices(AUSDDOTPair::get(), pool_0, pool_1);
+		assert!(price_0 < ExchangeRate::one());
+		assert!(price_0 > ExchangeRate::checked_from_rational(pool_1, pool_0).unwrap());
+		assert!(price_1 > ExchangeRate::one());
+		assert!(price_1 < ExchangeRate::checked_from_rational(pool_0, pool_1).unwrap());
+		assert_eq!(
+			DexModule::get_marginal_prices(AUSDDOTPair::get(), pool_0, pool_0),
+			(ExchangeRate::one(), ExchangeRate::one())
+		);
+
+		System::set_block_number(2);
+		DexModule::accumulate_cumulative_prices(AUSDDOTPair::get(), pool_0, pool_1);
+		let cumulative_prices = DexModule::cumulative_prices(AUSDDOTPair::get());
+		assert_eq!(cumulative_prices.price_0_cumulative, price_0.into_inner());
+		assert_eq!(cumulative_prices.price_1_cumulative, price_1.into_inner());
+	});
+}
+
+#[test]
+fn flash_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			let remark_call = Box::new(MockCall::System(frame_system::Call::remark { remark: vec![] }));
+			assert_noop!(
+				DexModule::flash_swap(
+					Origin::signed(BOB),
+					vec![AUSD, DOT],
+					1_000_000_000_000,
+					5_000_000_000_000,
+					remark_call.clone()
+				),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+
+			assert_ok!(DexModule::flash_swap(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				1_000_000_000_000,
+				5_101_520_253_036,
+				remark_call
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::FlashSwap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![5_101_520_253_036, 1_000_000_000_000],
+			)));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(505_101_520_253_036, 99_000_000_000_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &BOB),
+				1_000_000_000_000_000_000 - 5_101_520_253_036
+			);
+			assert_eq!(
+				Tokens::free_balance(DOT, &BOB),
+				1_000_000_000_000_000_000 + 1_000_000_000_000
+			);
+		});
+}
+
+#[test]
+fn flash_swap_not_repaid() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			// CAROL sells the borrowed DOT back to the pool, the received AUSD
+			// is not enough to repay the flash swap.
+			let swap_call = Box::new(MockCall::DexModule(crate::Call::swap_with_exact_supply {
+				path: vec![DOT, AUSD],
+				supply_amount: 1_000_000_000_000,
+				min_target_amount: 0,
+			}));
+			assert_noop!(
+				DexModule::flash_swap(
+					Origin::signed(CAROL),
+					vec![AUSD, DOT],
+					1_000_000_000_000,
+					5_101_520_253_036,
+					swap_call
+				),
+				Error::<Runtime>::FlashSwapNotRepaid
+			);
+
+			// the error of nested call is returned
+			let failed_call = Box::new(MockCall::DexModule(crate::Call::swap_with_exact_supply {
+				path: vec![DOT, AUSD],
+				supply_amount: 2_000_000_000_000,
+				min_target_amount: 0,
+			}));
+			assert_noop!(
+				DexModule::flash_swap(
+					Origin::signed(CAROL),
+					vec![AUSD, DOT],
+					1_000_000_000_000,
+					5_101_520_253_036,
+					failed_call
+				),
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(500_000_000_000_000, 100_000_000_000_000)
+			);
+		});
+}
+
+#[test]
+fn get_liquidity_share_value_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(DexModule::get_liquidity_share_value(AUSD, DOT, 1_000), (0, 0));
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				Tokens::total_issuance(AUSDDOTPair::get().dex_share_currency_id()),
+				1_000_000_000_000_000
+			);
+			assert_eq!(
+				DexModule::get_liquidity_share_value(AUSD, DOT, 100_000_000_000_000),
+				(50_000_000_000_000, 10_000_000_000_000)
+			);
+			assert_eq!(
+				DexModule::get_liquidity_share_value(DOT, AUSD, 100_000_000_000_000),
+				(10_000_000_000_000, 50_000_000_000_000)
+			);
+			assert_eq!(DexModule::get_liquidity_share_value(AUSD, AUSD, 1_000), (0, 0));
+		});
+}
+
+#[test]
+fn provisioning_info_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(DexModule::provisioning_info(AUSD, DOT), None);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			1_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000u128,
+			2_000_000_000_000u128,
+			10,
+			SwapCurve::ConstantProduct,
+		));
+		assert_eq!(
+			DexModule::provisioning_info(AUSD, DOT),
+			Some(ProvisioningInfo {
+				min_contribution: LiquidityPoolInfo {
+					amount_a: 1_000_000_000_000u128,
+					amount_b: 1_000_000_000_000u128,
+				},
+				target_provision: LiquidityPoolInfo {
+					amount_a: 5_000_000_000_000u128,
+					amount_b: 2_000_000_000_000u128,
+				},
+				accumulated_provision: LiquidityPoolInfo {
+					amount_a: 0,
+					amount_b: 0
+				},
+				not_before: 10,
+			})
+		);
+		assert_eq!(
+			DexModule::provisioning_info(DOT, AUSD),
+			Some(ProvisioningInfo {
+				min_contribution: LiquidityPoolInfo {
+					amount_a: 1_000_000_000_000u128,
+					amount_b: 1_000_000_000_000u128,
+				},
+				target_provision: LiquidityPoolInfo {
+					amount_a: 2_000_000_000_000u128,
+					amount_b: 5_000_000_000_000u128,
+				},
+				accumulated_provision: LiquidityPoolInfo {
+					amount_a: 0,
+					amount_b: 0
+				},
+				not_before: 10,
+			})
+		);
+		assert_eq!(DexModule::provisioning_info(AUSD, AUSD), None);
+	});
+}
@@ -60,20 +60,32 @@ pub trait WeightInfo {
 	fn remove_liquidity_by_unstake() -> Weight;
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
+	fn swap_with_exact_supply_by_best_path(p: u32, ) -> Weight;
+	fn swap_with_exact_target_by_best_path(p: u32, ) -> Weight;
+	fn swap_with_exact_supply_by_split_paths(u: u32, p: u32, ) -> Weight;
+	fn place_limit_order(p: u32, ) -> Weight;
+	fn cancel_limit_order() -> Weight;
+	fn fill_limit_order(p: u32, ) -> Weight;
+	fn remove_expired_limit_order() -> Weight;
+	fn set_trading_pair_fee() -> Weight;
+	fn set_protocol_fee_share() -> Weight;
+	fn flash_swap(u: u32, ) -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Estimated, to be replaced by the benchmarked weight.
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn disable_trading_pair() -> Weight {
 		(28_920_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
@@ -85,10 +97,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn end_provisioning() -> Weight {
 		(36_413_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
@@ -134,19 +147,93 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_best_path(p: u32, ) -> Weight {
+		(157_286_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_target_by_best_path(p: u32, ) -> Weight {
+		(157_512_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_split_paths(u: u32, p: u32, ) -> Weight {
+		(181_262_000 as Weight)
+			.saturating_add((64_918_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((21_406_000 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn place_limit_order(p: u32, ) -> Weight {
+		(101_412_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn cancel_limit_order() -> Weight {
+		(58_208_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fill_limit_order(p: u32, ) -> Weight {
+		(497_554_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(135 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_expired_limit_order() -> Weight {
+		(58_351_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_trading_pair_fee() -> Weight {
+		(18_630_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_protocol_fee_share() -> Weight {
+		(12_410_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn flash_swap(u: u32, ) -> Weight {
+		(75_520_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// Estimated, to be replaced by the benchmarked weight.
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn disable_trading_pair() -> Weight {
 		(28_920_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
@@ -158,10 +245,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn end_provisioning() -> Weight {
 		(36_413_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
@@ -207,4 +295,76 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_best_path(p: u32, ) -> Weight {
+		(157_286_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_target_by_best_path(p: u32, ) -> Weight {
+		(157_512_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_split_paths(u: u32, p: u32, ) -> Weight {
+		(181_262_000 as Weight)
+			.saturating_add((64_918_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((21_406_000 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn place_limit_order(p: u32, ) -> Weight {
+		(101_412_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn cancel_limit_order() -> Weight {
+		(58_208_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fill_limit_order(p: u32, ) -> Weight {
+		(497_554_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(135 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_expired_limit_order() -> Weight {
+		(58_351_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_trading_pair_fee() -> Weight {
+		(18_630_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_protocol_fee_share() -> Weight {
+		(12_410_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn flash_swap(u: u32, ) -> Weight {
+		(75_520_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
 }
@@ -28,16 +28,36 @@
 //! CDPs has debit, cancel all active auctions module, when debits and gaps are
 //! settled, the stable currency holder are allowed to refund a basket of
 //! remaining collateral assets.
+//!
+//! After emergency shutdown, the CDPs of every collateral type and the basket
+//! CDPs are settled by the idle scheduler tasks, and the settlement progress of
+//! each collateral type is recorded as report. The final redemption will be opened
+//! automatically once all CDPs are settled and all auctions are done.
+//!
+//! Besides the global shutdown, a single collateral type can be frozen when
+//! its price feed fails. The price of the frozen collateral is locked, its CDPs
+//! can not issue new debit or be liquidated and its auctions are paused, while
+//! the other collateral types keep working.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
+use codec::FullCodec;
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use primitives::{Balance, CurrencyId};
-use sp_runtime::{traits::Zero, FixedPointNumber};
-use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Ratio};
+use loans::{BasketPosition, Position};
+use module_emergency_shutdown_rpc_runtime_api::SettlementReportInfo;
+use primitives::{task::TaskResult, Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{fmt::Debug, prelude::*};
+use support::{
+	AuctionManager, CDPSettlement, CDPTreasury, CollateralFreeze, DispatchableTask, EmergencyShutdown, IdleScheduler,
+	LockablePrice, Ratio,
+};
 
 mod mock;
 mod tests;
@@ -46,6 +66,21 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The settlement progress of the CDPs of a collateral type after emergency
+/// shutdown.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, TypeInfo)]
+pub struct SettlementReport {
+	/// The number of settled CDPs which had debit
+	pub settled_count: u32,
+	/// The total debit of the settled CDPs
+	pub settled_debit: Balance,
+	/// The total collateral confiscated from the settled CDPs, not recorded
+	/// for the basket CDPs whose collaterals are of multiple types
+	pub confiscated_collateral: Balance,
+	/// Whether all CDPs of the collateral type have been settled
+	pub finished: bool,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -72,6 +107,22 @@ pub mod module {
 		/// this.
 		type ShutdownOrigin: EnsureOrigin<Self::Origin>;
 
+		/// Settle the CDPs has debit
+		type CDPSettlement: CDPSettlement<Self::AccountId, CurrencyId>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask
+			+ FullCodec
+			+ Debug
+			+ Clone
+			+ PartialEq
+			+ TypeInfo
+			+ From<EmergencyShutdownTask<Self>>;
+
+		/// Idle scheduler to settle CDPs and open final redemption after
+		/// shutdown.
+		type IdleScheduler: IdleScheduler<Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -88,6 +139,12 @@ pub mod module {
 		ExistPotentialSurplus,
 		/// Exist unhandled debit, means settlement has not been completed
 		ExistUnhandledDebit,
+		/// Invalid collateral type
+		InvalidCollateralType,
+		/// The collateral type has already been frozen
+		AlreadyFrozen,
+		/// The collateral type is not frozen
+		NotFrozen,
 	}
 
 	#[pallet::event]
@@ -99,6 +156,15 @@ pub mod module {
 		OpenRefund(T::BlockNumber),
 		/// Refund info. \[caller, stable_coin_amount, refund_list\]
 		Refund(T::AccountId, Balance, Vec<(CurrencyId, Balance)>),
+		/// All CDPs of the collateral type have been settled.
+		/// \[collateral_type, settlement_report\]
+		CollateralSettled(CurrencyId, SettlementReport),
+		/// All basket CDPs have been settled. \[settlement_report\]
+		BasketSettled(SettlementReport),
+		/// The collateral type is frozen. \[collateral_type\]
+		CollateralFrozen(CurrencyId),
+		/// The collateral type is unfrozen. \[collateral_type\]
+		CollateralUnfrozen(CurrencyId),
 	}
 
 	/// Emergency shutdown flag
@@ -115,6 +181,47 @@ pub mod module {
 	#[pallet::getter(fn can_refund)]
 	pub type CanRefund<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The settlement progress of the CDPs of collateral types
+	///
+	/// SettlementReports: map CurrencyId => SettlementReport
+	#[pallet::storage]
+	#[pallet::getter(fn settlement_reports)]
+	pub type SettlementReports<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, SettlementReport, ValueQuery>;
+
+	/// The last key of the CDPs iterated by the settlement task of collateral
+	/// types
+	///
+	/// LastSettlementKeyIterated: map CurrencyId => Option<Vec<u8>>
+	#[pallet::storage]
+	pub type LastSettlementKeyIterated<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Vec<u8>, OptionQuery>;
+
+	/// The settlement progress of the basket CDPs
+	///
+	/// BasketSettlementReport: SettlementReport
+	#[pallet::storage]
+	#[pallet::getter(fn basket_settlement_report)]
+	pub type BasketSettlementReport<T: Config> = StorageValue<_, SettlementReport, ValueQuery>;
+
+	/// The last key of the basket CDPs iterated by the settlement task
+	///
+	/// LastBasketSettlementKeyIterated: Option<Vec<u8>>
+	#[pallet::storage]
+	pub type LastBasketSettlementKeyIterated<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The block number since which the collateral types are frozen
+	///
+	/// FrozenCollaterals: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_collaterals)]
+	pub type FrozenCollaterals<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+	/// The total blocks of the finished freezes of collateral types
+	///
+	/// FinishedFrozenBlocks: map CurrencyId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn finished_frozen_blocks)]
+	pub type FinishedFrozenBlocks<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -135,11 +242,14 @@ pub mod module {
 			// get all collateral types
 			let collateral_currency_ids = T::CollateralCurrencyIds::get();
 
-			// lock price for every collateral
+			// lock price and schedule the task to settle CDPs for every collateral
 			for currency_id in collateral_currency_ids {
 				// TODO: check the results
 				let _ = <T as Config>::PriceSource::lock_price(currency_id);
+				T::IdleScheduler::schedule(EmergencyShutdownTask::SettleCDPs { currency_id }.into())?;
 			}
+			T::IdleScheduler::schedule(EmergencyShutdownTask::SettleBasketCDPs.into())?;
+			T::IdleScheduler::schedule(EmergencyShutdownTask::OpenCollateralRefund.into())?;
 
 			IsShutdown::<T>::put(true);
 			Self::deposit_event(Event::Shutdown(<frame_system::Pallet<T>>::block_number()));
@@ -155,27 +265,9 @@ pub mod module {
 			T::ShutdownOrigin::ensure_origin(origin)?;
 			ensure!(Self::is_shutdown(), Error::<T>::MustAfterShutdown); // must after shutdown
 
-			// Ensure all debits of CDPs have been settled, and all collateral auction has
-			// been done or canceled. Settle all collaterals type CDPs which have debit,
-			// cancel all collateral auctions in forward stage and wait for all collateral
-			// auctions in reverse stage to be ended.
-			let collateral_currency_ids = T::CollateralCurrencyIds::get();
-			for currency_id in collateral_currency_ids {
-				// there's no collateral auction
-				ensure!(
-					<T as Config>::AuctionManagerHandler::get_total_collateral_in_auction(currency_id).is_zero(),
-					Error::<T>::ExistPotentialSurplus,
-				);
-				// there's on debit in CDP
-				ensure!(
-					<loans::Pallet<T>>::total_positions(currency_id).debit.is_zero(),
-					Error::<T>::ExistUnhandledDebit,
-				);
-			}
+			Self::check_settlement_completed()?;
 
-			// Open refund stage
-			CanRefund::<T>::put(true);
-			Self::deposit_event(Event::OpenRefund(<frame_system::Pallet<T>>::block_number()));
+			Self::do_open_collateral_refund();
 			Ok(())
 		}
 
@@ -209,6 +301,233 @@ pub mod module {
 			Self::deposit_event(Event::Refund(who, amount, refund_assets));
 			Ok(())
 		}
+
+		/// Freeze a collateral type, lock its price and halt the new debit,
+		/// liquidations and auctions of it.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::weight((T::WeightInfo::freeze_collateral(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn freeze_collateral(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+			ensure!(
+				Self::frozen_collaterals(currency_id).is_none(),
+				Error::<T>::AlreadyFrozen
+			);
+
+			// the price can not be locked if the price feed has already failed, then the
+			// collateral is frozen without price.
+			let _ = <T as Config>::PriceSource::lock_price(currency_id);
+			FrozenCollaterals::<T>::insert(currency_id, <frame_system::Pallet<T>>::block_number());
+			Self::deposit_event(Event::CollateralFrozen(currency_id));
+			Ok(())
+		}
+
+		/// Unfreeze a collateral type and unlock its price.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::weight((T::WeightInfo::unfreeze_collateral(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn unfreeze_collateral(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_shutdown(), Error::<T>::AlreadyShutdown);
+			let frozen_since = FrozenCollaterals::<T>::take(currency_id).ok_or(Error::<T>::NotFrozen)?;
+
+			let frozen_blocks: u32 = <frame_system::Pallet<T>>::block_number()
+				.saturating_sub(frozen_since)
+				.unique_saturated_into();
+			FinishedFrozenBlocks::<T>::mutate(currency_id, |blocks| *blocks = blocks.saturating_add(frozen_blocks));
+			let _ = <T as Config>::PriceSource::unlock_price(currency_id);
+			Self::deposit_event(Event::CollateralUnfrozen(currency_id));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure the settlement is completed, so that the final redemption can be
+	/// opened.
+	fn check_settlement_completed() -> DispatchResult {
+		// Ensure all debits of CDPs have been settled, and all collateral, surplus and
+		// debit auctions have been done or canceled. Settle all collaterals type CDPs
+		// which have debit, cancel all collateral, surplus and debit auctions in forward
+		// stage and wait for all collateral auctions in reverse stage to be ended.
+		let collateral_currency_ids = T::CollateralCurrencyIds::get();
+		for currency_id in collateral_currency_ids {
+			// there's no collateral auction
+			ensure!(
+				<T as Config>::AuctionManagerHandler::get_total_collateral_in_auction(currency_id).is_zero(),
+				Error::<T>::ExistPotentialSurplus,
+			);
+			// there's on debit in CDP
+			ensure!(
+				<loans::Pallet<T>>::total_positions(currency_id).debit.is_zero(),
+				Error::<T>::ExistUnhandledDebit,
+			);
+		}
+		// there's no surplus auction or debit auction
+		ensure!(
+			<T as Config>::AuctionManagerHandler::get_total_surplus_in_auction().is_zero(),
+			Error::<T>::ExistPotentialSurplus,
+		);
+		ensure!(
+			<T as Config>::AuctionManagerHandler::get_total_debit_in_auction().is_zero(),
+			Error::<T>::ExistUnhandledDebit,
+		);
+		// there's no debit in basket CDP
+		ensure!(
+			<loans::Pallet<T>>::total_basket_positions().debit.is_zero(),
+			Error::<T>::ExistUnhandledDebit,
+		);
+		Ok(())
+	}
+
+	/// The settlement report of `currency_id` with the remaining debit of its
+	/// CDPs.
+	pub fn get_settlement_report(currency_id: CurrencyId) -> SettlementReportInfo<Balance> {
+		let report = Self::settlement_reports(currency_id);
+		SettlementReportInfo {
+			settled_count: report.settled_count,
+			settled_debit: report.settled_debit,
+			confiscated_collateral: report.confiscated_collateral,
+			remaining_debit: <loans::Pallet<T>>::total_positions(currency_id).debit,
+			finished: report.finished,
+		}
+	}
+
+	/// The settlement reports of all collateral types.
+	pub fn get_settlement_reports() -> Vec<(CurrencyId, SettlementReportInfo<Balance>)> {
+		T::CollateralCurrencyIds::get()
+			.into_iter()
+			.map(|currency_id| (currency_id, Self::get_settlement_report(currency_id)))
+			.collect()
+	}
+
+	/// Open refund stage
+	fn do_open_collateral_refund() {
+		CanRefund::<T>::put(true);
+		Self::deposit_event(Event::OpenRefund(<frame_system::Pallet<T>>::block_number()));
+	}
+
+	/// Settle the CDPs of `currency_id` from the last iterated key within
+	/// `weight`. Return the used weight and whether all CDPs have been settled.
+	fn settle_cdps(currency_id: CurrencyId, weight: Weight) -> (Weight, bool) {
+		let settle_weight = T::WeightInfo::settle_cdp();
+		let iterate_weight = T::DbWeight::get().reads(1);
+		let mut used_weight: Weight = 0;
+		let mut report = Self::settlement_reports(currency_id);
+
+		// If the last key is Some(value) continue iterating from that point in storage otherwise
+		// start iterating from the beginning of <loans::Positons<T>>
+		let mut iterator = match LastSettlementKeyIterated::<T>::get(currency_id) {
+			Some(key) => <loans::Positions<T>>::iter_prefix_from(currency_id, key),
+			None => <loans::Positions<T>>::iter_prefix(currency_id),
+		};
+
+		let mut finished_iteration = true;
+		loop {
+			if used_weight.saturating_add(settle_weight) > weight {
+				finished_iteration = false;
+				break;
+			}
+
+			match iterator.next() {
+				Some((who, Position { collateral, debit })) => {
+					used_weight = used_weight.saturating_add(iterate_weight);
+					if debit.is_zero() {
+						continue;
+					}
+
+					used_weight = used_weight.saturating_add(settle_weight);
+					if T::CDPSettlement::settle_cdp_has_debit(who.clone(), currency_id).is_ok() {
+						let remain_collateral = <loans::Pallet<T>>::positions(currency_id, &who).collateral;
+						report.settled_count = report.settled_count.saturating_add(1);
+						report.settled_debit = report.settled_debit.saturating_add(debit);
+						report.confiscated_collateral = report
+							.confiscated_collateral
+							.saturating_add(collateral.saturating_sub(remain_collateral));
+					}
+				}
+				None => break,
+			}
+		}
+
+		// CDPs failed to settle will be retried in the next round of iteration
+		let settled = finished_iteration && <loans::Pallet<T>>::total_positions(currency_id).debit.is_zero();
+		if finished_iteration {
+			LastSettlementKeyIterated::<T>::remove(currency_id);
+		} else {
+			LastSettlementKeyIterated::<T>::insert(currency_id, iterator.last_raw_key());
+		}
+		report.finished = settled;
+		SettlementReports::<T>::insert(currency_id, report);
+		if settled {
+			Self::deposit_event(Event::CollateralSettled(currency_id, report));
+		}
+
+		(used_weight.saturating_add(T::DbWeight::get().writes(2)), settled)
+	}
+
+	/// Settle the basket CDPs from the last iterated key within `weight`.
+	/// Return the used weight and whether all basket CDPs have been settled.
+	fn settle_basket_cdps(weight: Weight) -> (Weight, bool) {
+		let settle_weight = T::WeightInfo::settle_basket_cdp();
+		let iterate_weight = T::DbWeight::get().reads(1);
+		let mut used_weight: Weight = 0;
+		let mut report = Self::basket_settlement_report();
+
+		let mut iterator = match LastBasketSettlementKeyIterated::<T>::get() {
+			Some(key) => <loans::BasketPositions<T>>::iter_from(key),
+			None => <loans::BasketPositions<T>>::iter(),
+		};
+
+		let mut finished_iteration = true;
+		loop {
+			if used_weight.saturating_add(settle_weight) > weight {
+				finished_iteration = false;
+				break;
+			}
+
+			match iterator.next() {
+				Some((who, BasketPosition { debit, .. })) => {
+					used_weight = used_weight.saturating_add(iterate_weight);
+					if debit.is_zero() {
+						continue;
+					}
+
+					used_weight = used_weight.saturating_add(settle_weight);
+					if T::CDPSettlement::settle_basket_cdp_has_debit(who).is_ok() {
+						report.settled_count = report.settled_count.saturating_add(1);
+						report.settled_debit = report.settled_debit.saturating_add(debit);
+					}
+				}
+				None => break,
+			}
+		}
+
+		// basket CDPs failed to settle will be retried in the next round of iteration
+		let settled = finished_iteration && <loans::Pallet<T>>::total_basket_positions().debit.is_zero();
+		if finished_iteration {
+			LastBasketSettlementKeyIterated::<T>::kill();
+		} else {
+			LastBasketSettlementKeyIterated::<T>::put(iterator.last_raw_key());
+		}
+		report.finished = settled;
+		BasketSettlementReport::<T>::put(report);
+		if settled {
+			Self::deposit_event(Event::BasketSettled(report));
+		}
+
+		(used_weight.saturating_add(T::DbWeight::get().writes(2)), settled)
 	}
 }
 
@@ -217,3 +536,87 @@ impl<T: Config> EmergencyShutdown for Pallet<T> {
 		Self::is_shutdown()
 	}
 }
+
+impl<T: Config> CollateralFreeze<CurrencyId> for Pallet<T> {
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		Self::frozen_collaterals(currency_id).is_some()
+	}
+
+	fn frozen_blocks(currency_id: CurrencyId) -> u32 {
+		let ongoing: u32 = Self::frozen_collaterals(currency_id).map_or(0, |frozen_since| {
+			<frame_system::Pallet<T>>::block_number()
+				.saturating_sub(frozen_since)
+				.unique_saturated_into()
+		});
+		Self::finished_frozen_blocks(currency_id).saturating_add(ongoing)
+	}
+}
+
+/// The tasks of emergency shutdown dispatched by the idle scheduler.
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum EmergencyShutdownTask<T: Config> {
+	/// Settle all CDPs has debit of the collateral type.
+	SettleCDPs { currency_id: CurrencyId },
+	/// Settle all basket CDPs has debit.
+	SettleBasketCDPs,
+	/// Open the final redemption once the settlement is completed.
+	OpenCollateralRefund,
+	#[doc(hidden)]
+	#[codec(skip)]
+	__Ignore(PhantomData<T>),
+}
+
+impl<T: Config> DispatchableTask for EmergencyShutdownTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			EmergencyShutdownTask::SettleCDPs { currency_id } => {
+				let (used_weight, finished) = Pallet::<T>::settle_cdps(currency_id, weight);
+				TaskResult {
+					result: Ok(()),
+					used_weight,
+					finished,
+				}
+			}
+			EmergencyShutdownTask::SettleBasketCDPs => {
+				let (used_weight, finished) = Pallet::<T>::settle_basket_cdps(weight);
+				TaskResult {
+					result: Ok(()),
+					used_weight,
+					finished,
+				}
+			}
+			EmergencyShutdownTask::OpenCollateralRefund => {
+				let check_weight = T::WeightInfo::open_collateral_refund();
+				if weight < check_weight {
+					return TaskResult {
+						result: Ok(()),
+						used_weight: 0,
+						finished: false,
+					};
+				}
+
+				let finished = Pallet::<T>::can_refund() || Pallet::<T>::check_settlement_completed().is_ok();
+				if finished && !Pallet::<T>::can_refund() {
+					Pallet::<T>::do_open_collateral_refund();
+				}
+				TaskResult {
+					result: Ok(()),
+					used_weight: check_weight,
+					finished,
+				}
+			}
+			EmergencyShutdownTask::__Ignore(_) => TaskResult {
+				result: Ok(()),
+				used_weight: 0,
+				finished: true,
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<EmergencyShutdownTask<T>> for () {
+	fn from(_task: EmergencyShutdownTask<T>) -> Self {
+		unimplemented!()
+	}
+}
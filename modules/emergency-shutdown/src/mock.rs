@@ -35,7 +35,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, Convert, IdentityLookup},
 	DispatchResult,
 };
-use support::{AuctionManager, LockablePrice};
+use support::{AuctionManager, CDPSettlement, LockablePrice};
 
 pub type AccountId = u128;
 pub type AuctionId = u32;
@@ -177,6 +177,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		unimplemented!()
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -188,6 +205,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 ord_parameter_types! {
@@ -214,6 +239,19 @@ impl cdp_treasury::Config for Runtime {
 	type WeightInfo = ();
 }
 
+pub struct MockCDPSettlement;
+impl CDPSettlement<AccountId, CurrencyId> for MockCDPSettlement {
+	fn settle_cdp_has_debit(who: AccountId, currency_id: CurrencyId) -> DispatchResult {
+		let Position { collateral, debit } = Loans::positions(currency_id, &who);
+		Loans::confiscate_collateral_and_debit(&who, currency_id, collateral.min(debit), debit)
+	}
+
+	fn settle_basket_cdp_has_debit(who: AccountId) -> DispatchResult {
+		let position = Loans::basket_positions(&who);
+		Loans::confiscate_basket_collaterals_and_debit(&who, &position.collateral_amounts(), position.debit)
+	}
+}
+
 ord_parameter_types! {
 	pub const CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 }
@@ -225,6 +263,9 @@ impl Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type AuctionManagerHandler = MockAuctionManager;
 	type ShutdownOrigin = EnsureSignedBy<One, AccountId>;
+	type CDPSettlement = MockCDPSettlement;
+	type Task = ();
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
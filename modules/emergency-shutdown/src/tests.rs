@@ -71,6 +71,146 @@ fn open_collateral_refund_work() {
 	});
 }
 
+#[test]
+fn settle_cdps_task_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Loans::adjust_position(&ALICE, BTC, 100, 50));
+		assert_ok!(Loans::adjust_position(&BOB, BTC, 100, 30));
+		assert_ok!(Loans::adjust_position(&BOB, DOT, 100, 0));
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+
+		let task = EmergencyShutdownTask::<Runtime>::SettleCDPs { currency_id: BTC };
+		let settle_weight = <() as WeightInfo>::settle_cdp();
+
+		// not enough weight
+		let result = task.clone().dispatch(0);
+		assert!(!result.finished);
+		assert_eq!(Loans::total_positions(BTC).debit, 80);
+		assert_eq!(
+			EmergencyShutdownModule::settlement_reports(BTC),
+			SettlementReport::default()
+		);
+
+		// settle one CDP at a time
+		let result = task.clone().dispatch(settle_weight);
+		assert_eq!(result.used_weight, settle_weight);
+		assert!(!result.finished);
+		assert_eq!(EmergencyShutdownModule::settlement_reports(BTC).settled_count, 1);
+		assert!(!EmergencyShutdownModule::settlement_reports(BTC).finished);
+		assert_eq!(EmergencyShutdownModule::get_settlement_report(BTC).remaining_debit, 30);
+		assert!(LastSettlementKeyIterated::<Runtime>::contains_key(BTC));
+
+		let result = task.clone().dispatch(settle_weight);
+		assert!(!result.finished);
+		assert_eq!(EmergencyShutdownModule::settlement_reports(BTC).settled_count, 2);
+		assert_eq!(Loans::total_positions(BTC).debit, 0);
+
+		let result = task.dispatch(settle_weight);
+		assert!(result.finished);
+		let report = SettlementReport {
+			settled_count: 2,
+			settled_debit: 80,
+			confiscated_collateral: 80,
+			finished: true,
+		};
+		assert_eq!(EmergencyShutdownModule::settlement_reports(BTC), report);
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::CollateralSettled(
+			BTC, report,
+		)));
+		assert_eq!(
+			EmergencyShutdownModule::get_settlement_report(BTC),
+			SettlementReportInfo {
+				settled_count: 2,
+				settled_debit: 80,
+				confiscated_collateral: 80,
+				remaining_debit: 0,
+				finished: true,
+			}
+		);
+		assert!(!LastSettlementKeyIterated::<Runtime>::contains_key(BTC));
+		assert_eq!(Loans::positions(BTC, ALICE).collateral, 50);
+		assert_eq!(Loans::positions(BTC, BOB).collateral, 70);
+
+		// the CDPs without debit are settled already
+		let result = EmergencyShutdownTask::<Runtime>::SettleCDPs { currency_id: DOT }.dispatch(Weight::max_value());
+		assert!(result.finished);
+		assert_eq!(
+			EmergencyShutdownModule::settlement_reports(DOT),
+			SettlementReport {
+				finished: true,
+				..Default::default()
+			}
+		);
+		assert_eq!(Loans::positions(DOT, BOB).collateral, 100);
+	});
+}
+
+#[test]
+fn settle_basket_cdps_task_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Loans::adjust_basket_position(&ALICE, &[(BTC, 100), (DOT, 100)], 50));
+		assert_ok!(Loans::adjust_basket_position(&BOB, &[(BTC, 100)], 0));
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+
+		let task = EmergencyShutdownTask::<Runtime>::SettleBasketCDPs;
+		let settle_weight = <() as WeightInfo>::settle_basket_cdp();
+
+		// not enough weight
+		let result = task.clone().dispatch(0);
+		assert!(!result.finished);
+		assert_eq!(Loans::total_basket_positions().debit, 50);
+		assert_eq!(
+			EmergencyShutdownModule::basket_settlement_report(),
+			SettlementReport::default()
+		);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)),
+			Error::<Runtime>::ExistUnhandledDebit,
+		);
+
+		let result = task.dispatch(Weight::max_value());
+		assert!(result.finished);
+		let report = SettlementReport {
+			settled_count: 1,
+			settled_debit: 50,
+			confiscated_collateral: 0,
+			finished: true,
+		};
+		assert_eq!(EmergencyShutdownModule::basket_settlement_report(), report);
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::BasketSettled(report)));
+		assert!(!LastBasketSettlementKeyIterated::<Runtime>::exists());
+		assert_eq!(Loans::total_basket_positions().debit, 0);
+		assert_eq!(Loans::basket_positions(BOB).collaterals.get(&BTC), Some(&100));
+		assert_ok!(EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)));
+	});
+}
+
+#[test]
+fn open_collateral_refund_task_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Loans::adjust_position(&ALICE, BTC, 100, 50));
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+
+		let task = EmergencyShutdownTask::<Runtime>::OpenCollateralRefund;
+		let result = task.clone().dispatch(Weight::max_value());
+		assert!(!result.finished);
+		assert!(!EmergencyShutdownModule::can_refund());
+
+		assert!(
+			EmergencyShutdownTask::<Runtime>::SettleCDPs { currency_id: BTC }
+				.dispatch(Weight::max_value())
+				.finished
+		);
+		let result = task.dispatch(Weight::max_value());
+		assert!(result.finished);
+		assert!(EmergencyShutdownModule::can_refund());
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::OpenRefund(1)));
+	});
+}
+
 #[test]
 fn refund_collaterals_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -80,3 +220,69 @@ fn refund_collaterals_fail() {
 		);
 	});
 }
+
+#[test]
+fn freeze_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			EmergencyShutdownModule::freeze_collateral(Origin::signed(5), BTC),
+			BadOrigin,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::freeze_collateral(Origin::signed(1), ACA),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+
+		assert!(!EmergencyShutdownModule::is_frozen(BTC));
+		assert_ok!(EmergencyShutdownModule::freeze_collateral(Origin::signed(1), BTC));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::CollateralFrozen(BTC)));
+		assert!(EmergencyShutdownModule::is_frozen(BTC));
+		assert!(!EmergencyShutdownModule::is_frozen(DOT));
+		assert_noop!(
+			EmergencyShutdownModule::freeze_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::AlreadyFrozen,
+		);
+
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::freeze_collateral(Origin::signed(1), DOT),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn unfreeze_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			EmergencyShutdownModule::unfreeze_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::NotFrozen,
+		);
+		assert_ok!(EmergencyShutdownModule::freeze_collateral(Origin::signed(1), BTC));
+		assert_noop!(
+			EmergencyShutdownModule::unfreeze_collateral(Origin::signed(5), BTC),
+			BadOrigin,
+		);
+
+		System::set_block_number(11);
+		assert_eq!(EmergencyShutdownModule::frozen_blocks(BTC), 10);
+		assert_ok!(EmergencyShutdownModule::unfreeze_collateral(Origin::signed(1), BTC));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::CollateralUnfrozen(BTC)));
+		assert!(!EmergencyShutdownModule::is_frozen(BTC));
+		assert_eq!(EmergencyShutdownModule::finished_frozen_blocks(BTC), 10);
+
+		// the clock is paused only while the collateral is frozen
+		System::set_block_number(20);
+		assert_eq!(EmergencyShutdownModule::frozen_blocks(BTC), 10);
+		assert_ok!(EmergencyShutdownModule::freeze_collateral(Origin::signed(1), BTC));
+		System::set_block_number(25);
+		assert_eq!(EmergencyShutdownModule::frozen_blocks(BTC), 15);
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::unfreeze_collateral(Origin::signed(1), BTC),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
@@ -51,18 +51,22 @@ pub trait WeightInfo {
 	fn emergency_shutdown(c: u32, ) -> Weight;
 	fn open_collateral_refund() -> Weight;
 	fn refund_collaterals(c: u32, ) -> Weight;
+	fn settle_cdp() -> Weight;
+	fn settle_basket_cdp() -> Weight;
+	fn freeze_collateral() -> Weight;
+	fn unfreeze_collateral() -> Weight;
 }
 
 /// Weights for module_emergency_shutdown using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Estimated, to be replaced by the benchmarked weight.
 	fn emergency_shutdown(c: u32, ) -> Weight {
 		(232_768_000 as Weight)
-			// Standard Error: 565_000
 			.saturating_add((20_539_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().reads(60 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
 	}
 	fn open_collateral_refund() -> Weight {
 		(62_000_000 as Weight)
@@ -78,17 +82,41 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_cdp() -> Weight {
+		(83_527_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_basket_cdp() -> Weight {
+		(121_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn freeze_collateral() -> Weight {
+		(27_446_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn unfreeze_collateral() -> Weight {
+		(23_158_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// Estimated, to be replaced by the benchmarked weight.
 	fn emergency_shutdown(c: u32, ) -> Weight {
 		(232_768_000 as Weight)
-			// Standard Error: 565_000
 			.saturating_add((20_539_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(60 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
 	}
 	fn open_collateral_refund() -> Weight {
 		(62_000_000 as Weight)
@@ -104,4 +132,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_cdp() -> Weight {
+		(83_527_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_basket_cdp() -> Weight {
+		(121_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn freeze_collateral() -> Weight {
+		(27_446_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn unfreeze_collateral() -> Weight {
+		(23_158_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -0,0 +1,90 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the homa lite module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_homa_lite_rpc_runtime_api::RedeemLiquidityInfo;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as HomaLiteClient;
+pub use module_homa_lite_rpc_runtime_api::HomaLiteApi as HomaLiteRuntimeApi;
+
+#[rpc]
+pub trait HomaLiteApi<BlockHash, Balance> {
+	#[rpc(name = "homaLite_getRedeemLiquidity")]
+	fn get_redeem_liquidity(&self, at: Option<BlockHash>) -> Result<Vec<RedeemLiquidityInfo<Balance>>>;
+}
+
+/// A struct that implements the [`HomaLiteApi`].
+pub struct HomaLite<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> HomaLite<C, B> {
+	/// Create new `HomaLite` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		HomaLite {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, Balance> HomaLiteApi<<Block as BlockT>::Hash, Balance> for HomaLite<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: HomaLiteRuntimeApi<Block, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_redeem_liquidity(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<RedeemLiquidityInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_redeem_liquidity(&at).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get redeem liquidity.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
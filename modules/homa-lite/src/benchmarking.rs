@@ -89,7 +89,14 @@ benchmarks! {
 		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &caller, amount)?;
 	}: _(RawOrigin::Signed(caller), amount, Permill::default())
 
-	schedule_unbond {}: _(RawOrigin::Root, 1_000_000_000_000, <T as frame_system::Config>::BlockNumber::default())
+	schedule_unbond {
+		let s in 1 .. T::SubAccountIndexes::get().len() as u32;
+
+		let amount = 1_000_000_000_000;
+		for sub_account_index in T::SubAccountIndexes::get().into_iter().take(s as usize) {
+			SubAccountLedgers::<T>::insert(sub_account_index, SubAccountLedger { bonded: amount, unbonding: 0 });
+		}
+	}: _(RawOrigin::Root, amount * s as Balance, <T as frame_system::Config>::BlockNumber::default())
 
 	replace_schedule_unbond {}: _(RawOrigin::Root, vec![(1_000_000, <T as frame_system::Config>::BlockNumber::default()), (1_000_000_000, <T as frame_system::Config>::BlockNumber::default())])
 
@@ -104,9 +111,36 @@ benchmarks! {
 		let _ = crate::Pallet::<T>::process_redeem_requests_with_available_staking_balance(&caller);
 	}
 
-	xcm_unbond {}: {
-		let _ = crate::Pallet::<T>::process_scheduled_unbond(1_000_000_000_000_000);
+	xcm_unbond {
+		let s in 1 .. T::SubAccountIndexes::get().len() as u32;
+
+		let amount = 1_000_000_000_000_000;
+		for sub_account_index in T::SubAccountIndexes::get().into_iter().take(s as usize) {
+			SubAccountLedgers::<T>::insert(sub_account_index, SubAccountLedger { bonded: 0, unbonding: amount });
+		}
+	}: {
+		let _ = crate::Pallet::<T>::process_scheduled_unbond(amount * s as Balance);
 	}
+
+	fast_match_redeems {
+		let n in 0 .. T::MaximumRedeemRequestsForFastMatch::get();
+
+		let amount = 1_000_000_000_000_000;
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let mut requests = vec![];
+		for i in 0 .. n {
+			let redeemer: T::AccountId = account("redeemer", i, SEED);
+			<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &redeemer, amount)?;
+			let _ = crate::Pallet::<T>::request_redeem(RawOrigin::Signed(redeemer.clone()).into(), amount, Permill::from_percent(1));
+			requests.push(redeemer);
+		}
+		// the rest of the redeem requests are matched after the prioritized requests.
+		let redeemer: T::AccountId = account("redeemer", n, SEED);
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &redeemer, amount)?;
+		let _ = crate::Pallet::<T>::request_redeem(RawOrigin::Signed(redeemer).into(), amount, Permill::from_percent(1));
+
+		<T as module::Config>::Currency::deposit(T::StakingCurrencyId::get(), &caller, amount * (n as u128 + 1))?;
+	}: _(RawOrigin::Signed(caller), amount * (n as u128 + 1), Permill::from_percent(1), requests)
 }
 
 #[cfg(test)]
@@ -207,4 +241,10 @@ mod tests {
 			assert_ok!(Pallet::<Runtime>::test_benchmark_xcm_unbond());
 		});
 	}
+	#[test]
+	fn test_fast_match_redeems() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_fast_match_redeems());
+		});
+	}
 }
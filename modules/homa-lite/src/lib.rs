@@ -27,25 +27,32 @@ mod mock_no_fees;
 mod tests_no_fees;
 pub mod weights;
 
-use frame_support::{log, pallet_prelude::*, transactional, weights::Weight, BoundedVec};
+use frame_support::{
+	log, pallet_prelude::*, storage::storage_prefix, transactional, weights::Weight, BoundedVec, StorageHasher,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
 
-use module_support::{CallBuilder, ExchangeRate, ExchangeRateProvider, Ratio};
+use module_support::{CallBuilder, DEXManager, ExchangeRate, ExchangeRateProvider, Ratio};
 use orml_traits::{
-	arithmetic::Signed, BalanceStatus, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency, XcmTransfer,
+	arithmetic::Signed, BalanceStatus, Happened, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency,
+	XcmTransfer,
 };
 use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
 use sp_arithmetic::traits::CheckedRem;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Bounded, Saturating, Zero},
+	traits::{BlakeTwo256, BlockNumberProvider, Bounded, Convert, Saturating, UniqueSaturatedInto, Zero},
 	ArithmeticError, FixedPointNumber, Permill,
 };
 use sp_std::{
 	cmp::{min, Ordering},
+	collections::btree_map::BTreeMap,
 	convert::{From, TryFrom, TryInto},
 	ops::Mul,
 	prelude::*,
 };
+use sp_trie::{read_trie_value, Layout, StorageProof};
 use xcm::latest::prelude::*;
 
 pub use module::*;
@@ -63,6 +70,31 @@ pub mod module {
 	pub enum RedeemType<AccountId> {
 		WithAvailableStakingBalance,
 		WithMint(AccountId, Balance),
+		WithFastMatch(AccountId, Balance, Permill),
+	}
+
+	/// The leading fields of the staking ledger on the relaychain, the remaining fields are not
+	/// decoded.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq)]
+	pub struct RelayChainStakingLedger<AccountId> {
+		/// The stash account whose balance is actually locked and at stake.
+		pub stash: AccountId,
+		/// The total amount of the stash's balance that we are currently accounting for.
+		#[codec(compact)]
+		pub total: Balance,
+		/// The total amount of the stash's balance that will be at stake in any forthcoming
+		/// rounds.
+		#[codec(compact)]
+		pub active: Balance,
+	}
+
+	/// The ledger of a derivative sub-account on the relaychain.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, PartialEq, Eq, Default, TypeInfo)]
+	pub struct SubAccountLedger {
+		/// The amount of staking currency bonded by the sub-account.
+		pub bonded: Balance,
+		/// The amount of staking currency scheduled to be unbonded from the sub-account.
+		pub unbonding: Balance,
 	}
 
 	#[pallet::config]
@@ -101,14 +133,13 @@ pub mod module {
 		/// The Call builder for communicating with RelayChain via XCM messaging.
 		type RelayChainCallBuilder: CallBuilder<AccountId = Self::AccountId, Balance = Balance>;
 
-		/// The MultiLocation of the sovereign sub-account for where the staking currencies are sent
-		/// to.
-		#[pallet::constant]
-		type SovereignSubAccountLocation: Get<MultiLocation>;
+		/// Convert the index of a sovereign sub-account into the MultiLocation for where the
+		/// staking currencies are sent to.
+		type SovereignSubAccountLocation: Convert<u16, MultiLocation>;
 
-		/// The Index to the Homa Lite Sub-account
+		/// The indexes of the derivative sub-accounts used by Homa Lite for staking.
 		#[pallet::constant]
-		type SubAccountIndex: Get<u16>;
+		type SubAccountIndexes: Get<Vec<u16>>;
 
 		/// The default exchange rate for liquid currency to staking currency.
 		#[pallet::constant]
@@ -141,6 +172,11 @@ pub mod module {
 		#[pallet::constant]
 		type MaximumRedeemRequestMatchesForMint: Get<u32>;
 
+		/// The maximum number of prioritized redeem requests given to the "FastMatchRedeems"
+		/// extrinsic.
+		#[pallet::constant]
+		type MaximumRedeemRequestsForFastMatch: Get<u32>;
+
 		/// Unbonding slashing spans for unbonding on the relaychain.
 		#[pallet::constant]
 		type RelayChainUnbondingSlashingSpans: Get<u32>;
@@ -152,6 +188,46 @@ pub mod module {
 		/// The number of blocks to pass before TotalStakingCurrency is updated.
 		#[pallet::constant]
 		type StakingUpdateFrequency: Get<Self::BlockNumber>;
+
+		/// Convert the index of a sovereign sub-account into its account ID on the relaychain,
+		/// which is the controller of the staking ledger.
+		type SovereignSubAccountId: Convert<u16, Self::AccountId>;
+
+		/// The storage root of the relaychain block that the current block is based on. Kept for
+		/// `T::MaxRelayChainStateRootAge` relaychain blocks to verify the proof of the staking
+		/// ledger.
+		type RelayChainStateRoot: Get<Option<H256>>;
+
+		/// The number of relaychain blocks for which the relaychain state root is kept, so that
+		/// the staking ledger can be synced with a proof against a recent relaychain block rather
+		/// than exactly the one the current block is based on.
+		#[pallet::constant]
+		type MaxRelayChainStateRootAge: Get<u32>;
+
+		/// The maximum change of TotalStakingCurrency, and therefore of the exchange rate, allowed
+		/// by one staking ledger sync.
+		#[pallet::constant]
+		type MaxStakingLedgerSyncChange: Get<Permill>;
+
+		/// The minimum number of relaychain blocks between two staking ledger syncs, usually an
+		/// era of the relaychain.
+		#[pallet::constant]
+		type StakingLedgerSyncPeriod: Get<RelayChainBlockNumberOf<Self>>;
+
+		/// The number of relaychain blocks within which the XCM sent to the relaychain is expected
+		/// to be executed. The staking currency sent or withdrawn within this delay may not be
+		/// reflected in the staking ledger yet.
+		#[pallet::constant]
+		type XcmExecutionDelay: Get<u32>;
+
+		/// DEX to swap the liquid currency confiscated from validator insurance to staking
+		/// currency.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The maximum slippage allowed when swapping the confiscated liquid currency to staking
+		/// currency, relative to the exchange rate.
+		#[pallet::constant]
+		type MaxSlashCompensationSlippage: Get<Permill>;
 	}
 
 	#[pallet::error]
@@ -172,6 +248,18 @@ pub mod module {
 		TooManyScheduledUnbonds,
 		/// The xcm operation have failed
 		XcmFailed,
+		/// The relaychain state root of the relaychain block is not available.
+		RelayChainStateRootNotAvailable,
+		/// The proof of the staking ledger is invalid.
+		InvalidStakingLedgerProof,
+		/// The staking ledger has already been synced within the current sync period.
+		StakingLedgerAlreadySynced,
+		/// The staking ledger changes TotalStakingCurrency more than the maximum allowed.
+		ExceededMaxStakingLedgerSyncChange,
+		/// There are no derivative sub-accounts to stake with.
+		NoSubAccounts,
+		/// Too many redeem requests are given to be matched.
+		TooManyRedeemRequests,
 	}
 
 	#[pallet::event]
@@ -214,12 +302,37 @@ pub mod module {
 		///\[staking_amount_added\]
 		ScheduledUnbondWithdrew(Balance),
 
+		/// A scheduled Unbond has been distributed across the sub-accounts.
+		/// \[distribution: Vec<(sub_account_index, staking_amount)>\]
+		ScheduledUnbondDistributed(Vec<(u16, Balance)>),
+
+		/// The scheduled Unbond has been withdrew from the sub-accounts on the RelayChain.
+		/// \[distribution: Vec<(sub_account_index, staking_amount)>\]
+		ScheduledUnbondWithdrewFromSubAccounts(Vec<(u16, Balance)>),
+
 		/// Interest rate for TotalStakingCurrency is set
 		StakingInterestRatePerUpdateSet(Permill),
 
 		/// The amount of the staking currency available to be redeemed is set.
 		/// \[total_available_staking_balance\]
 		AvailableStakingBalanceSet(Balance),
+
+		/// The staking ledger on the relaychain has been synced with a verified proof.
+		/// \[relaychain_blocknumber, total_staking_currency\]
+		StakingLedgerSynced(RelayChainBlockNumberOf<T>, Balance),
+
+		/// A redeem request has been partially filled by a fast match.
+		/// \[redeemer, taker, liquid_amount_filled, liquid_amount_remaining\]
+		RedeemRequestPartiallyFilled(T::AccountId, T::AccountId, Balance, Balance),
+
+		/// The liquid currency confiscated from validator insurance has compensated the liquid
+		/// currency holders, by staking the swapped staking currency or burning it.
+		/// \[liquid_amount_confiscated, staking_amount_staked, liquid_amount_burned\]
+		SlashCompensated(Balance, Balance, Balance),
+
+		/// Failed to compensate the liquid currency holders with the confiscated liquid currency.
+		/// \[source, liquid_amount_confiscated\]
+		SlashCompensationFailed(T::AccountId, Balance),
 	}
 
 	/// The total amount of the staking currency on the relaychain.
@@ -260,7 +373,8 @@ pub mod module {
 	pub type ScheduledUnbond<T: Config> =
 		StorageValue<_, BoundedVec<(Balance, RelayChainBlockNumberOf<T>), T::MaxScheduledUnbonds>, ValueQuery>;
 
-	/// Every T::StakingUpdateFrequency blocks, TotalStakingCurrency gain interest by this rate.
+	/// Every T::StakingUpdateFrequency blocks, TotalStakingCurrency gain interest by this rate,
+	/// until the staking ledger is synced for the first time.
 	/// StakingInterestRatePerUpdate: Value: Permill
 	#[pallet::storage]
 	#[pallet::getter(fn staking_interest_rate_per_update)]
@@ -272,6 +386,35 @@ pub mod module {
 	#[pallet::getter(fn last_redeem_request_key_iterated)]
 	pub type LastRedeemRequestKeyIterated<T: Config> = StorageValue<_, Vec<u8>, ValueQuery>;
 
+	/// The relaychain block number at which the staking ledger is last synced.
+	/// LastStakingLedgerSync: Value: RelayChainBlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn last_staking_ledger_sync)]
+	pub type LastStakingLedgerSync<T: Config> = StorageValue<_, RelayChainBlockNumberOf<T>, ValueQuery>;
+
+	/// The staking currency sent to and withdrawn from the relaychain by XCM in the recent
+	/// relaychain blocks, which may not be reflected in the staking ledger yet. Indexed by the
+	/// relaychain block number modulo `T::XcmExecutionDelay + T::MaxRelayChainStateRootAge`.
+	/// InFlightStakingCurrency: Map: slot: u32 => (relaychain_blocknumber, sent, withdrawn)
+	#[pallet::storage]
+	#[pallet::getter(fn in_flight_staking_currency)]
+	pub type InFlightStakingCurrency<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (RelayChainBlockNumberOf<T>, Balance, Balance), ValueQuery>;
+
+	/// The ledgers of the derivative sub-accounts on the relaychain.
+	/// SubAccountLedgers: Map: sub_account_index: u16 => SubAccountLedger
+	#[pallet::storage]
+	#[pallet::getter(fn sub_account_ledgers)]
+	pub type SubAccountLedgers<T: Config> = StorageMap<_, Twox64Concat, u16, SubAccountLedger, ValueQuery>;
+
+	/// The state roots of the recent relaychain blocks that the blocks are based on. Indexed by
+	/// the relaychain block number modulo `T::MaxRelayChainStateRootAge`.
+	/// RelayChainStateRoots: Map: slot: u32 => (relaychain_blocknumber, state_root)
+	#[pallet::storage]
+	#[pallet::getter(fn relay_chain_state_roots)]
+	pub type RelayChainStateRoots<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (RelayChainBlockNumberOf<T>, H256), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -279,8 +422,9 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
 			let mut current_weight = 0;
+			let xcm_unbond_weight = <T as Config>::WeightInfo::xcm_unbond(T::SubAccountIndexes::get().len() as u32);
 			// If enough weight, process the next XCM unbond.
-			if remaining_weight > <T as Config>::WeightInfo::xcm_unbond() {
+			if remaining_weight > xcm_unbond_weight {
 				let mut scheduled_unbond = Self::scheduled_unbond();
 				if !scheduled_unbond.is_empty() {
 					let (staking_amount, block_number) = scheduled_unbond[0];
@@ -289,10 +433,16 @@ pub mod module {
 						log::debug!("{:?}", res);
 						debug_assert!(res.is_ok());
 
-						if res.is_ok() {
-							current_weight = <T as Config>::WeightInfo::xcm_unbond();
+						if let Ok(withdrew) = res {
+							current_weight = xcm_unbond_weight;
 
-							scheduled_unbond.remove(0);
+							// The amount not yet unbonding on the relaychain is withdrew later.
+							let remaining = staking_amount.saturating_sub(withdrew);
+							if remaining.is_zero() {
+								scheduled_unbond.remove(0);
+							} else {
+								scheduled_unbond[0].0 = remaining;
+							}
 							ScheduledUnbond::<T>::put(scheduled_unbond);
 						}
 					}
@@ -321,9 +471,14 @@ pub mod module {
 		}
 
 		fn on_initialize(n: T::BlockNumber) -> Weight {
+			// The relaychain state root is recorded in `on_finalize`.
+			let record_state_root_weight = T::DbWeight::get().reads_writes(1, 1);
+
 			// Update the total amount of Staking balance by accruing the interest periodically.
+			// The interest is an estimate, it's no longer applied once the staking ledger is synced.
 			let interest_rate = Self::staking_interest_rate_per_update();
-			if !interest_rate.is_zero()
+			let weight = if !interest_rate.is_zero()
+				&& Self::last_staking_ledger_sync().is_zero()
 				&& n.checked_rem(&T::StakingUpdateFrequency::get())
 					.map_or(false, |n| n.is_zero())
 			{
@@ -335,10 +490,23 @@ pub mod module {
 				<T as Config>::WeightInfo::on_initialize()
 			} else {
 				<T as Config>::WeightInfo::on_initialize_without_work()
+			};
+			weight.saturating_add(record_state_root_weight)
+		}
+
+		fn on_finalize(_n: T::BlockNumber) {
+			// The validation data of the relaychain is only available after the inherent is applied.
+			if let Some(state_root) = T::RelayChainStateRoot::get() {
+				let relaychain_block_number = T::RelayChainBlockNumber::current_block_number();
+				RelayChainStateRoots::<T>::insert(
+					Self::state_root_slot(relaychain_block_number),
+					(relaychain_block_number, state_root),
+				);
 			}
 		}
 
 		// ensure that minimum_mint_redeem_amount * (1 - withdraw fee) > xcm unbond fee
+		// ensure that there is at least one sub-account to stake with
 		fn integrity_test() {
 			sp_std::if_std! {
 				sp_io::TestExternalities::new_empty().execute_with(||
@@ -346,6 +514,7 @@ pub mod module {
 						Permill::one().saturating_sub(T::BaseWithdrawFee::get()).mul(
 						T::MinimumRedeemThreshold::get()) > T::XcmUnbondFee::get()
 					));
+				assert!(!T::SubAccountIndexes::get().is_empty());
 			}
 		}
 	}
@@ -558,13 +727,15 @@ pub mod module {
 		}
 
 		/// Request staking currencies to be unbonded from the RelayChain.
+		/// The unbond is distributed across the sub-accounts, starting from the one with the most
+		/// bonded amount.
 		///
 		/// Requires `T::GovernanceOrigin`
 		///
 		/// Parameters:
 		/// - `staking_amount`: The amount of staking currency to be unbonded.
 		/// - `unbond_block`: The relaychain block number to unbond.
-		#[pallet::weight(< T as Config >::WeightInfo::schedule_unbond())]
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_unbond(T::SubAccountIndexes::get().len() as u32))]
 		#[transactional]
 		pub fn schedule_unbond(
 			origin: OriginFor<T>,
@@ -580,6 +751,17 @@ pub mod module {
 			);
 			ScheduledUnbond::<T>::put(bounded_vec);
 
+			let mut bonded_list = Self::sub_account_amounts(|ledger| ledger.bonded);
+			bonded_list.sort_by(|a, b| b.1.cmp(&a.1));
+			let distribution = Self::distribute_decrement_with_remainder(bonded_list, staking_amount)?;
+			for (sub_account_index, amount) in distribution.iter() {
+				SubAccountLedgers::<T>::mutate(sub_account_index, |ledger| {
+					ledger.bonded = ledger.bonded.saturating_sub(*amount);
+					ledger.unbonding = ledger.unbonding.saturating_add(*amount);
+				});
+			}
+
+			Self::deposit_event(Event::<T>::ScheduledUnbondDistributed(distribution));
 			Self::deposit_event(Event::<T>::ScheduledUnbondAdded(staking_amount, unbond_block));
 			Ok(())
 		}
@@ -677,6 +859,145 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Sync TotalStakingCurrency with the staking ledgers of all sub-accounts on the
+		/// relaychain. The ledgers are verified by the storage proof against the state root of a
+		/// recent relaychain block, which is kept for `T::MaxRelayChainStateRootAge` relaychain
+		/// blocks, so this can be called by anyone.
+		///
+		/// The synced TotalStakingCurrency is the total amount of the ledgers, reconciled with the
+		/// staking currency in flight by XCM and the staking currency withdrew but not yet
+		/// redeemed. Its change is bounded by `T::MaxStakingLedgerSyncChange`, and the ledger can
+		/// be synced at most once for every `T::StakingLedgerSyncPeriod`.
+		///
+		/// Parameters:
+		/// - `relaychain_block_number`: The relaychain block of the staking ledgers.
+		/// - `proof`: The storage proof of the staking ledgers of the sovereign sub-accounts.
+		#[pallet::weight(< T as Config >::WeightInfo::sync_staking_ledger(
+			T::SubAccountIndexes::get().len() as u32,
+			Pallet::<T>::in_flight_slots(),
+		))]
+		#[transactional]
+		pub fn sync_staking_ledger(
+			origin: OriginFor<T>,
+			relaychain_block_number: RelayChainBlockNumberOf<T>,
+			proof: Vec<Vec<u8>>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let last_sync = Self::last_staking_ledger_sync();
+			ensure!(
+				last_sync.is_zero()
+					|| relaychain_block_number >= last_sync.saturating_add(T::StakingLedgerSyncPeriod::get()),
+				Error::<T>::StakingLedgerAlreadySynced
+			);
+
+			let ledgers = Self::read_staking_ledgers(relaychain_block_number, proof)?;
+			let total_in_ledgers = ledgers.iter().fold(Balance::zero(), |total, (_, ledger)| {
+				total.saturating_add(ledger.bonded).saturating_add(ledger.unbonding)
+			});
+			let (sent, withdrew) = Self::staking_currency_in_flight(relaychain_block_number);
+			let new_total = total_in_ledgers
+				.saturating_add(sent)
+				.saturating_sub(withdrew)
+				.saturating_add(Self::available_staking_balance());
+
+			let current_total = Self::total_staking_currency();
+			if !current_total.is_zero() {
+				let change = if new_total > current_total {
+					new_total.saturating_sub(current_total)
+				} else {
+					current_total.saturating_sub(new_total)
+				};
+				ensure!(
+					change <= T::MaxStakingLedgerSyncChange::get() * current_total,
+					Error::<T>::ExceededMaxStakingLedgerSyncChange
+				);
+			}
+
+			Self::update_total_staking_currency_storage(|_| Ok(new_total))?;
+
+			// The staking currency withdrew by XCM in flight is still unbonding in the ledgers.
+			let mut unbonding_list = ledgers
+				.iter()
+				.map(|(sub_account_index, ledger)| (*sub_account_index, ledger.unbonding))
+				.collect::<Vec<_>>();
+			unbonding_list.sort_by(|a, b| b.1.cmp(&a.1));
+			let withdrew_distribution = Self::distribute_decrement(unbonding_list, withdrew);
+			for (sub_account_index, ledger) in ledgers {
+				let withdrew_in_flight = withdrew_distribution
+					.iter()
+					.find(|(index, _)| *index == sub_account_index)
+					.map_or(Zero::zero(), |(_, amount)| *amount);
+				SubAccountLedgers::<T>::insert(
+					sub_account_index,
+					SubAccountLedger {
+						bonded: ledger.bonded,
+						unbonding: ledger.unbonding.saturating_sub(withdrew_in_flight),
+					},
+				);
+			}
+			LastStakingLedgerSync::<T>::put(relaychain_block_number);
+
+			Self::deposit_event(Event::<T>::StakingLedgerSynced(relaychain_block_number, new_total));
+			Ok(())
+		}
+
+		/// Fast match redeem requests, by paying Staking currency to the redeemers in exchange for
+		/// their Liquid currency. Only the redeem requests with an `additional_fee` no less than
+		/// `min_additional_fee` are matched, and the additional fee is kept by the taker.
+		/// The given redeem requests are matched with priority, then the rest of the redeem
+		/// requests are matched. Redeem requests can be partially filled.
+		///
+		/// Parameters:
+		/// - `liquid_amount`: The maximum amount of Liquid currency to be taken.
+		/// - `min_additional_fee`: The minimum additional fee of the redeem requests to be matched.
+		/// - `requests`: The redeem requests that are prioritized to match, at most
+		///   `MaximumRedeemRequestsForFastMatch`.
+		#[pallet::weight(< T as Config >::WeightInfo::fast_match_redeems(requests.len() as u32))]
+		#[transactional]
+		pub fn fast_match_redeems(
+			origin: OriginFor<T>,
+			#[pallet::compact] liquid_amount: Balance,
+			min_additional_fee: Permill,
+			requests: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let taker = ensure_signed(origin)?;
+
+			ensure!(
+				requests.len() as u32 <= T::MaximumRedeemRequestsForFastMatch::get(),
+				Error::<T>::TooManyRedeemRequests
+			);
+			ensure!(
+				Self::liquid_amount_is_above_minimum_threshold(liquid_amount),
+				Error::<T>::AmountBelowMinimumThreshold
+			);
+
+			let mut liquid_remaining = liquid_amount;
+
+			// Iterate through the prioritized requests first
+			for redeemer in requests {
+				if liquid_remaining.is_zero() {
+					break;
+				}
+				let actual_liquid_redeemed =
+					Self::fast_match_redeem_request(&taker, &redeemer, liquid_remaining, min_additional_fee)?;
+				liquid_remaining = liquid_remaining.saturating_sub(actual_liquid_redeemed);
+			}
+
+			// Iterate through the rest of the RedeemRequests
+			let redeem_requests_limit_remaining = T::MaximumRedeemRequestMatchesForMint::get();
+			if Self::liquid_amount_is_above_minimum_threshold(liquid_remaining)
+				&& !redeem_requests_limit_remaining.is_zero()
+			{
+				Self::redeem_from_previous_redeem_request(
+					RedeemType::WithFastMatch(taker, liquid_remaining, min_additional_fee),
+					redeem_requests_limit_remaining,
+				)?;
+			}
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -775,6 +1096,43 @@ pub mod module {
 			})
 		}
 
+		/// Match a redeem request with a fast match from the taker, if the additional fee of the
+		/// redeem request is no less than `min_additional_fee`.
+		/// Deposit "RedeemRequestPartiallyFilled" event if the redeem request is partially filled.
+		///
+		/// Param:
+		/// - `taker`: The AccountId taking the Liquid currency of the redeem request
+		/// - `redeemer`: The AccountId requested the Redeem
+		/// - `liquid_amount_to_redeem`: Only redeem up to this amount.
+		/// - `min_additional_fee`: The minimum additional fee accepted by the taker.
+		///
+		/// Return:
+		/// - `Balance`: Actual amount of liquid currency taken.
+		fn fast_match_redeem_request(
+			taker: &T::AccountId,
+			redeemer: &T::AccountId,
+			liquid_amount_to_redeem: Balance,
+			min_additional_fee: Permill,
+		) -> Result<Balance, DispatchError> {
+			match Self::redeem_requests(redeemer) {
+				Some((_, extra_fee)) if extra_fee >= min_additional_fee && taker != redeemer => {}
+				_ => return Ok(0),
+			}
+
+			let actual_liquid_amount = Self::match_mint_with_redeem_request(taker, redeemer, liquid_amount_to_redeem)?;
+			if !actual_liquid_amount.is_zero() {
+				if let Some((remaining, _)) = Self::redeem_requests(redeemer) {
+					Self::deposit_event(Event::<T>::RedeemRequestPartiallyFilled(
+						redeemer.clone(),
+						taker.clone(),
+						actual_liquid_amount,
+						remaining,
+					));
+				}
+			}
+			Ok(actual_liquid_amount)
+		}
+
 		/// Mint some Liquid currency, by locking up the given amount of Staking currency.
 		/// The redeem requests given in `requests` are prioritized to be matched. All other redeem
 		/// requests are matched after. The remaining amount is minted through Staking on the
@@ -853,14 +1211,25 @@ pub mod module {
 					Ok(new_total_staking_currency)
 				})?;
 
+				// Stake with the sub-account with the least bonded amount.
+				let mut bonded_list = Self::sub_account_amounts(|ledger| ledger.bonded);
+				bonded_list.sort_by_key(|(_, bonded)| *bonded);
+				let (sub_account_index, _) = Self::distribute_increment(bonded_list, staking_remaining)
+					.pop()
+					.ok_or(Error::<T>::NoSubAccounts)?;
+
 				// All checks pass. Proceed with Xcm transfer.
 				T::XcmTransfer::transfer(
 					minter.clone(),
 					staking_currency,
 					staking_remaining,
-					T::SovereignSubAccountLocation::get(),
+					T::SovereignSubAccountLocation::convert(sub_account_index),
 					Self::xcm_dest_weight(),
 				)?;
+				Self::record_staking_currency_in_flight(staking_remaining, Zero::zero());
+				SubAccountLedgers::<T>::mutate(sub_account_index, |ledger| {
+					ledger.bonded = ledger.bonded.saturating_add(staking_remaining)
+				});
 				T::Currency::deposit(T::LiquidCurrencyId::get(), minter, liquid_to_mint)?;
 
 				staking_remaining = Balance::zero();
@@ -878,23 +1247,52 @@ pub mod module {
 		/// Construct XCM message and sent it to the relaychain to withdraw_unbonded Staking
 		/// currency. The staking currency withdrew becomes available to be redeemed.
 		///
+		/// Once the staking ledgers have been synced, only the amount unbonding in the synced
+		/// ledgers is withdrew, and the rest is left to be withdrew later.
+		///
 		/// params:
 		/// 	- `staking_amount_unbonded`: amount of staking currency to withdraw unbond via XCM
+		///
+		/// return:
+		/// 	- `Result<staking_amount_withdrew, DispatchError>`
 		#[transactional]
-		pub fn process_scheduled_unbond(staking_amount_unbonded: Balance) -> DispatchResult {
-			let msg = Self::construct_xcm_unreserve_message(T::ParachainAccount::get(), staking_amount_unbonded);
+		pub fn process_scheduled_unbond(staking_amount_unbonded: Balance) -> Result<Balance, DispatchError> {
+			// Withdraw from the sub-accounts with the most unbonding amount first.
+			let mut unbonding_list = Self::sub_account_amounts(|ledger| ledger.unbonding);
+			unbonding_list.sort_by(|a, b| b.1.cmp(&a.1));
+			let distribution = if Self::last_staking_ledger_sync().is_zero() {
+				Self::distribute_decrement_with_remainder(unbonding_list, staking_amount_unbonded)?
+			} else {
+				Self::distribute_decrement(unbonding_list, staking_amount_unbonded)
+			};
+			let staking_amount_withdrew = distribution
+				.iter()
+				.fold(Balance::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			if staking_amount_withdrew.is_zero() {
+				return Ok(Zero::zero());
+			}
+
+			for (sub_account_index, amount) in distribution.iter() {
+				SubAccountLedgers::<T>::mutate(sub_account_index, |ledger| {
+					ledger.unbonding = ledger.unbonding.saturating_sub(*amount)
+				});
+			}
+
+			let msg = Self::construct_xcm_unreserve_message(T::ParachainAccount::get(), distribution.clone());
 
 			let res = pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, msg);
 			log::debug!("on_idle XCM result: {:?}", res);
 			ensure!(res.is_ok(), Error::<T>::XcmFailed);
+			Self::record_staking_currency_in_flight(Zero::zero(), staking_amount_withdrew);
 
 			// Update storage with the new available amount
 			AvailableStakingBalance::<T>::mutate(|current| {
-				*current = current.saturating_add(staking_amount_unbonded);
+				*current = current.saturating_add(staking_amount_withdrew);
 			});
 
-			Self::deposit_event(Event::<T>::ScheduledUnbondWithdrew(staking_amount_unbonded));
-			Ok(())
+			Self::deposit_event(Event::<T>::ScheduledUnbondWithdrewFromSubAccounts(distribution));
+			Self::deposit_event(Event::<T>::ScheduledUnbondWithdrew(staking_amount_withdrew));
+			Ok(staking_amount_withdrew)
 		}
 
 		/// Redeem the given requests with available_staking_balance.
@@ -977,30 +1375,236 @@ pub mod module {
 			liquid_amount > T::MinimumRedeemThreshold::get()
 		}
 
-		/// Helper function that construct an XCM message that:
-		/// 1. `withdraw_unbonded` from HomaLite sub-account.
+		/// Helper function that construct an XCM message that, for each of the HomaLite
+		/// sub-accounts:
+		/// 1. `withdraw_unbonded` from the sub-account.
 		/// 2. Transfer the withdrew fund into Sovereign account.
 		///
 		/// Param:
 		/// 	- `parachain_account` : sovereign account's AccountId
-		/// 	- `amount` : amount to withdraw from unbonded.
+		/// 	- `distribution` : (sub_account_index, amount) to withdraw from unbonded.
+		/// The XCM fee and the dest weight are paid for each of the sub-accounts.
+		///
 		/// Return:
 		/// 	Xcm<()>: the Xcm message constructed.
-		pub fn construct_xcm_unreserve_message(parachain_account: T::AccountId, amount: Balance) -> Xcm<()> {
-			let xcm_message = T::RelayChainCallBuilder::utility_as_derivative_call(
-				T::RelayChainCallBuilder::utility_batch_call(vec![
-					T::RelayChainCallBuilder::staking_withdraw_unbonded(T::RelayChainUnbondingSlashingSpans::get()),
-					T::RelayChainCallBuilder::balances_transfer_keep_alive(parachain_account, amount),
-				]),
-				T::SubAccountIndex::get(),
-			);
+		pub fn construct_xcm_unreserve_message(
+			parachain_account: T::AccountId,
+			distribution: Vec<(u16, Balance)>,
+		) -> Xcm<()> {
+			let num_sub_accounts = distribution.len();
+			let calls = distribution
+				.into_iter()
+				.map(|(sub_account_index, amount)| {
+					T::RelayChainCallBuilder::utility_as_derivative_call(
+						T::RelayChainCallBuilder::utility_batch_call(vec![
+							T::RelayChainCallBuilder::staking_withdraw_unbonded(
+								T::RelayChainUnbondingSlashingSpans::get(),
+							),
+							T::RelayChainCallBuilder::balances_transfer_keep_alive(parachain_account.clone(), amount),
+						]),
+						sub_account_index,
+					)
+				})
+				.collect();
+			let xcm_message = T::RelayChainCallBuilder::utility_batch_call(calls);
 			T::RelayChainCallBuilder::finalize_call_into_xcm_message(
 				xcm_message,
-				T::XcmUnbondFee::get(),
-				Self::xcm_dest_weight(),
+				T::XcmUnbondFee::get().saturating_mul(num_sub_accounts as Balance),
+				Self::xcm_dest_weight().saturating_mul(num_sub_accounts as Weight),
 			)
 		}
 
+		/// The storage key of the staking ledger of a sovereign sub-account on the relaychain.
+		pub fn staking_ledger_key(sub_account_index: u16) -> Vec<u8> {
+			let mut key = storage_prefix(b"Staking", b"Ledger").to_vec();
+			key.extend(Blake2_128Concat::hash(
+				&T::SovereignSubAccountId::convert(sub_account_index).encode(),
+			));
+			key
+		}
+
+		/// Read the staking ledgers of the sovereign sub-accounts from the storage proof, verified
+		/// against the kept state root of the relaychain block. A sub-account proven to have no
+		/// staking ledger has an empty ledger. The active amount is bonded, and the rest of the
+		/// total amount is unbonding.
+		///
+		/// Return:
+		/// - `Vec<(sub_account_index, ledger)>`
+		fn read_staking_ledgers(
+			relaychain_block_number: RelayChainBlockNumberOf<T>,
+			proof: Vec<Vec<u8>>,
+		) -> Result<Vec<(u16, SubAccountLedger)>, DispatchError> {
+			let state_root = Self::relay_chain_state_roots(Self::state_root_slot(relaychain_block_number))
+				.and_then(|(block_number, state_root)| {
+					if block_number == relaychain_block_number {
+						Some(state_root)
+					} else {
+						None
+					}
+				})
+				.ok_or(Error::<T>::RelayChainStateRootNotAvailable)?;
+			let db = StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+			T::SubAccountIndexes::get()
+				.into_iter()
+				.map(|sub_account_index| {
+					let key = Self::staking_ledger_key(sub_account_index);
+					let ledger = match read_trie_value::<Layout<BlakeTwo256>, _>(&db, &state_root, &key)
+						.map_err(|_| Error::<T>::InvalidStakingLedgerProof)?
+					{
+						Some(value) => {
+							let ledger = RelayChainStakingLedger::<T::AccountId>::decode(&mut &value[..])
+								.map_err(|_| Error::<T>::InvalidStakingLedgerProof)?;
+							SubAccountLedger {
+								bonded: ledger.active,
+								unbonding: ledger.total.saturating_sub(ledger.active),
+							}
+						}
+						None => Default::default(),
+					};
+					Ok((sub_account_index, ledger))
+				})
+				.collect()
+		}
+
+		/// The slot of `RelayChainStateRoots` for the relaychain block.
+		fn state_root_slot(relaychain_block_number: RelayChainBlockNumberOf<T>) -> u32 {
+			let block_number: u32 = relaychain_block_number.unique_saturated_into();
+			block_number % T::MaxRelayChainStateRootAge::get().max(1)
+		}
+
+		/// The number of slots of `InFlightStakingCurrency`. The staking currency in flight is
+		/// kept for `T::MaxRelayChainStateRootAge` more relaychain blocks than the execution delay,
+		/// so that it can be reconciled with the staking ledger of any relaychain block whose
+		/// state root is kept.
+		fn in_flight_slots() -> u32 {
+			T::XcmExecutionDelay::get()
+				.saturating_add(T::MaxRelayChainStateRootAge::get())
+				.max(1)
+		}
+
+		/// The slot of `InFlightStakingCurrency` for the relaychain block.
+		fn in_flight_slot(relaychain_block_number: RelayChainBlockNumberOf<T>) -> u32 {
+			let block_number: u32 = relaychain_block_number.unique_saturated_into();
+			block_number % Self::in_flight_slots()
+		}
+
+		/// Record the staking currency sent to or withdrawn from the relaychain by XCM at the
+		/// current relaychain block.
+		fn record_staking_currency_in_flight(sent: Balance, withdrew: Balance) {
+			let relaychain_block_number = T::RelayChainBlockNumber::current_block_number();
+			InFlightStakingCurrency::<T>::mutate(
+				Self::in_flight_slot(relaychain_block_number),
+				|(block_number, total_sent, total_withdrew)| {
+					// the slot of an earlier relaychain block is out of the execution delay
+					if *block_number != relaychain_block_number {
+						*block_number = relaychain_block_number;
+						*total_sent = Zero::zero();
+						*total_withdrew = Zero::zero();
+					}
+					*total_sent = total_sent.saturating_add(sent);
+					*total_withdrew = total_withdrew.saturating_add(withdrew);
+				},
+			);
+		}
+
+		/// The staking currency sent to and withdrawn from the relaychain by XCM within the
+		/// execution delay, which may not be reflected in the staking ledger of the relaychain
+		/// block yet.
+		///
+		/// Return:
+		/// - `(sent, withdrew)`
+		pub fn staking_currency_in_flight(relaychain_block_number: RelayChainBlockNumberOf<T>) -> (Balance, Balance) {
+			let delay = T::XcmExecutionDelay::get();
+			(0..Self::in_flight_slots()).fold(
+				(Balance::zero(), Balance::zero()),
+				|(total_sent, total_withdrew), slot| {
+					let (block_number, sent, withdrew) = Self::in_flight_staking_currency(slot);
+					if block_number.saturating_add(delay.into()) > relaychain_block_number {
+						(total_sent.saturating_add(sent), total_withdrew.saturating_add(withdrew))
+					} else {
+						(total_sent, total_withdrew)
+					}
+				},
+			)
+		}
+
+		/// The amounts of the sub-accounts, in the order of `T::SubAccountIndexes`.
+		fn sub_account_amounts(f: impl Fn(SubAccountLedger) -> Balance) -> Vec<(u16, Balance)> {
+			T::SubAccountIndexes::get()
+				.into_iter()
+				.map(|sub_account_index| (sub_account_index, f(Self::sub_account_ledgers(sub_account_index))))
+				.collect()
+		}
+
+		/// Pass the sorted list, pick the first item
+		pub fn distribute_increment(amount_list: Vec<(u16, Balance)>, increment: Balance) -> Vec<(u16, Balance)> {
+			if amount_list.is_empty() {
+				vec![]
+			} else {
+				vec![(amount_list[0].0, increment)]
+			}
+		}
+
+		/// Pass the sorted list, consume available by order.
+		pub fn distribute_decrement(amount_list: Vec<(u16, Balance)>, decrement: Balance) -> Vec<(u16, Balance)> {
+			let mut distribution: Vec<(u16, Balance)> = vec![];
+			let mut remain_decrement = decrement;
+
+			for (sub_account_index, available) in amount_list {
+				if remain_decrement.is_zero() {
+					break;
+				}
+				if available.is_zero() {
+					continue;
+				}
+				distribution.push((sub_account_index, min(available, remain_decrement)));
+				remain_decrement = remain_decrement.saturating_sub(available);
+			}
+
+			distribution
+		}
+
+		/// Pass the sorted list, consume available by order. The remainder that exceeds the total
+		/// available is attributed to the first sub-account of `T::SubAccountIndexes`, which holds
+		/// the stake not tracked by the ledgers.
+		fn distribute_decrement_with_remainder(
+			amount_list: Vec<(u16, Balance)>,
+			decrement: Balance,
+		) -> Result<Vec<(u16, Balance)>, DispatchError> {
+			let mut distribution = Self::distribute_decrement(amount_list, decrement);
+			let distributed = distribution
+				.iter()
+				.fold(Balance::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			let remainder = decrement.saturating_sub(distributed);
+			if !remainder.is_zero() {
+				let first_index = *T::SubAccountIndexes::get().first().ok_or(Error::<T>::NoSubAccounts)?;
+				match distribution.iter_mut().find(|(index, _)| *index == first_index) {
+					Some((_, amount)) => *amount = amount.saturating_add(remainder),
+					None => distribution.push((first_index, remainder)),
+				}
+			}
+			Ok(distribution)
+		}
+
+		/// The outstanding liquidity of the redeem requests, grouped by the additional fee and
+		/// sorted from the highest additional fee to the lowest.
+		///
+		/// Return:
+		/// - `Vec<(additional_fee, total_liquid_amount, num_requests)>`
+		pub fn get_redeem_liquidity() -> Vec<(Permill, Balance, u32)> {
+			let mut liquidity: BTreeMap<Permill, (Balance, u32)> = BTreeMap::new();
+			for (_, (liquid_amount, extra_fee)) in RedeemRequests::<T>::iter() {
+				let level = liquidity.entry(extra_fee).or_default();
+				level.0 = level.0.saturating_add(liquid_amount);
+				level.1 = level.1.saturating_add(1);
+			}
+			liquidity
+				.into_iter()
+				.rev()
+				.map(|(extra_fee, (liquid_amount, num_requests))| (extra_fee, liquid_amount, num_requests))
+				.collect()
+		}
+
 		/// Helper function that update the storage of total_staking_currency.
 		/// Ensures that the total staking amount would not become zero, and emit an event.
 		fn update_total_staking_currency_storage(
@@ -1015,8 +1619,8 @@ pub mod module {
 		}
 
 		/// Function that iterates `RedeemRequests` storage from `LastRedeemRequestKeyIterated`, and
-		/// redeem them 	depending on the redeem type. Either redeem from AvailableStakingBalance, or
-		/// from a specific minter.
+		/// redeem them 	depending on the redeem type. Either redeem from AvailableStakingBalance,
+		/// or from a specific minter.
 		/// If the item after `LastRedeemRequestKeyIterated` is the end of the iterator, then start
 		/// from the beginning.
 		///
@@ -1034,10 +1638,9 @@ pub mod module {
 			let starting_key = Self::last_redeem_request_key_iterated();
 			let mut iterator = RedeemRequests::<T>::iter_keys_from(starting_key);
 
-			let mut redeem_amount_remaining = if let RedeemType::WithMint(_, amount) = redeem_type {
-				amount
-			} else {
-				0
+			let mut redeem_amount_remaining = match redeem_type {
+				RedeemType::WithMint(_, amount) | RedeemType::WithFastMatch(_, amount, _) => amount,
+				RedeemType::WithAvailableStakingBalance => 0,
 			};
 
 			let mut total_amount_redeemed: Balance = 0;
@@ -1067,6 +1670,19 @@ pub mod module {
 							return Ok(true);
 						}
 					}
+					RedeemType::WithFastMatch(taker, _, min_additional_fee) => {
+						let amount_redeemed = Self::fast_match_redeem_request(
+							taker,
+							&redeemer,
+							redeem_amount_remaining,
+							*min_additional_fee,
+						)?;
+						total_amount_redeemed = total_amount_redeemed.saturating_add(amount_redeemed);
+						redeem_amount_remaining = redeem_amount_remaining.saturating_sub(amount_redeemed);
+						if !Self::liquid_amount_is_above_minimum_threshold(redeem_amount_remaining) {
+							return Ok(true);
+						}
+					}
 				}
 				Ok(false)
 			};
@@ -1094,6 +1710,75 @@ pub mod module {
 			LastRedeemRequestKeyIterated::<T>::put(iterator.last_raw_key());
 			Ok((total_amount_redeemed, num_matched))
 		}
+
+		/// Compensate liquid currency holders with the liquid currency confiscated from validator
+		/// insurance in `source`. The liquid currency is swapped to staking currency by DEX, which
+		/// is staked on the relaychain and added to TotalStakingCurrency, or burned if it can't be
+		/// staked. Both raise the exchange rate.
+		#[transactional]
+		pub fn compensate_slash(source: &T::AccountId, liquid_amount: Balance) -> DispatchResult {
+			if liquid_amount.is_zero() {
+				return Ok(());
+			}
+
+			let (staked, burned) = match Self::stake_slash_compensation(source, liquid_amount) {
+				Ok(staking_amount) => (staking_amount, Zero::zero()),
+				Err(_) => {
+					T::Currency::withdraw(T::LiquidCurrencyId::get(), source, liquid_amount)?;
+					(Zero::zero(), liquid_amount)
+				}
+			};
+			Self::deposit_event(Event::<T>::SlashCompensated(liquid_amount, staked, burned));
+			Ok(())
+		}
+
+		/// Swap the confiscated liquid currency to staking currency by DEX, and stake it with the
+		/// sub-account with the least bonded amount.
+		///
+		/// Return:
+		/// - `staking_amount`: the amount of staking currency staked.
+		#[transactional]
+		fn stake_slash_compensation(source: &T::AccountId, liquid_amount: Balance) -> Result<Balance, DispatchError> {
+			let staking_currency = T::StakingCurrencyId::get();
+			let expected_staking_amount = Self::convert_liquid_to_staking(liquid_amount)?;
+			let min_staking_amount = expected_staking_amount
+				.saturating_sub(T::MaxSlashCompensationSlippage::get() * expected_staking_amount);
+			let staking_amount = T::DEX::swap_with_exact_supply(
+				source,
+				&[T::LiquidCurrencyId::get(), staking_currency],
+				liquid_amount,
+				min_staking_amount,
+			)?;
+			ensure!(
+				staking_amount >= T::MinimumMintThreshold::get(),
+				Error::<T>::AmountBelowMinimumThreshold
+			);
+
+			Self::update_total_staking_currency_storage(|total_staking_currency| {
+				total_staking_currency
+					.checked_add(staking_amount)
+					.ok_or_else(|| ArithmeticError::Overflow.into())
+			})?;
+
+			let mut bonded_list = Self::sub_account_amounts(|ledger| ledger.bonded);
+			bonded_list.sort_by_key(|(_, bonded)| *bonded);
+			let (sub_account_index, _) = Self::distribute_increment(bonded_list, staking_amount)
+				.pop()
+				.ok_or(Error::<T>::NoSubAccounts)?;
+
+			T::XcmTransfer::transfer(
+				source.clone(),
+				staking_currency,
+				staking_amount,
+				T::SovereignSubAccountLocation::convert(sub_account_index),
+				Self::xcm_dest_weight(),
+			)?;
+			Self::record_staking_currency_in_flight(staking_amount, Zero::zero());
+			SubAccountLedgers::<T>::mutate(sub_account_index, |ledger| {
+				ledger.bonded = ledger.bonded.saturating_add(staking_amount)
+			});
+			Ok(staking_amount)
+		}
 	}
 
 	impl<T: Config> ExchangeRateProvider for Pallet<T> {
@@ -1111,3 +1796,19 @@ pub mod module {
 		}
 	}
 }
+
+/// Compensate the liquid currency holders with the liquid currency confiscated from validator
+/// insurance, by `Pallet::compensate_slash`.
+pub struct OnSlash<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Happened<(T::AccountId, Balance)> for OnSlash<T> {
+	fn happened((source, amount): &(T::AccountId, Balance)) {
+		if let Err(e) = Pallet::<T>::compensate_slash(source, *amount) {
+			log::warn!(
+				target: "homa-lite",
+				"compensate_slash: failed to compensate {:?} slashed insurance from {:?}: {:?}",
+				amount, source, e
+			);
+			Pallet::<T>::deposit_event(Event::SlashCompensationFailed(source.clone(), *amount));
+		}
+	}
+}
@@ -30,7 +30,7 @@ pub use module_relaychain::RelayChainCallBuilder;
 pub use module_support::mocks::MockAddressMapping;
 pub use orml_traits::{parameter_type_with_key, XcmTransfer};
 pub use primitives::{Amount, TokenSymbol};
-pub use sp_core::H256;
+pub use sp_core::{H160, H256};
 pub use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
 
 pub use cumulus_primitives_core::ParaId;
@@ -61,6 +61,7 @@ pub const MOCK_XCM_DESTINATION: MultiLocation = X1(Junction::AccountId32 {
 .into();
 pub const MOCK_XCM_ACCOUNT_ID: AccountId = AccountId32::new([255u8; 32]);
 pub const PARACHAIN_ID: u32 = 2000;
+pub const DEX_ACCOUNT: AccountId = AccountId32::new([99u8; 32]);
 
 /// For testing only. Does not check for overflow.
 pub fn dollar(b: Balance) -> Balance {
@@ -104,6 +105,122 @@ impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcm {
 		Ok(())
 	}
 }
+
+parameter_types! {
+	pub static MockSwapRate: Option<Ratio> = None;
+}
+
+/// A mock DEX swapping at `MockSwapRate`, fails if the rate is not set.
+pub struct MockDEX;
+impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
+	fn get_liquidity_pool(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> (Balance, Balance) {
+		unimplemented!()
+	}
+
+	fn get_liquidity_token_address(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> Option<H160> {
+		unimplemented!()
+	}
+
+	fn get_swap_target_amount(_path: &[CurrencyId], _supply_amount: Balance) -> Option<Balance> {
+		unimplemented!()
+	}
+
+	fn get_swap_supply_amount(_path: &[CurrencyId], _target_amount: Balance) -> Option<Balance> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_limit_orders(_who: &AccountId) -> Vec<(u64, CurrencyId, CurrencyId, Balance, Balance)> {
+		unimplemented!()
+	}
+
+	fn swap_with_exact_supply(
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let target_amount = MockSwapRate::get()
+			.ok_or(DispatchError::Other("no liquidity"))?
+			.saturating_mul_int(supply_amount);
+		ensure!(
+			target_amount >= min_target_amount,
+			DispatchError::Other("below min target amount")
+		);
+		<Currencies as MultiCurrency<AccountId>>::transfer(path[0], who, &DEX_ACCOUNT, supply_amount)?;
+		<Currencies as MultiCurrency<AccountId>>::deposit(path[path.len() - 1], who, target_amount)?;
+		Ok(target_amount)
+	}
+
+	fn swap_with_exact_target(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_max_supply_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn add_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_max_amount_a: Balance,
+		_max_amount_b: Balance,
+		_min_share_increment: Balance,
+		_stake_increment_share: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn remove_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_remove_share: Balance,
+		_min_withdrawn_a: Balance,
+		_min_withdrawn_b: Balance,
+		_by_unstake: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+/// Sub-account location for testing. The sub-account 0 is at `MOCK_XCM_DESTINATION`.
+pub struct MockSovereignSubAccountLocation;
+impl Convert<u16, MultiLocation> for MockSovereignSubAccountLocation {
+	fn convert(sub_account_index: u16) -> MultiLocation {
+		X1(Junction::AccountId32 {
+			network: NetworkId::Kusama,
+			id: [sub_account_index as u8 + 1; 32],
+		})
+		.into()
+	}
+}
+
+/// Sub-account ID for testing. The sub-account 0 is `MOCK_XCM_ACCOUNT_ID`.
+pub struct MockSovereignSubAccountId;
+impl Convert<u16, AccountId> for MockSovereignSubAccountId {
+	fn convert(sub_account_index: u16) -> AccountId {
+		AccountId32::new([255u8 - sub_account_index as u8; 32])
+	}
+}
+
 impl InvertLocation for MockXcm {
 	fn invert_location(l: &MultiLocation) -> Result<MultiLocation, ()> {
 		Ok(l.clone())
@@ -257,8 +374,6 @@ parameter_types! {
 	pub const LiquidCurrencyId: CurrencyId = LKSM;
 	pub MinimumMintThreshold: Balance = millicent(50000);
 	pub MinimumRedeemThreshold: Balance = dollar(5);
-	pub const MockXcmDestination: MultiLocation = MOCK_XCM_DESTINATION;
-	pub const MockXcmAccountId: AccountId = MOCK_XCM_ACCOUNT_ID;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub const MaxRewardPerEra: Permill = Permill::from_percent(1);
 	pub MintFee: Balance = millicent(1000);
@@ -266,12 +381,19 @@ parameter_types! {
 	pub XcmUnbondFee: Balance = dollar(1);
 	pub const ParachainAccount: AccountId = DAVE;
 	pub const MaximumRedeemRequestMatchesForMint: u32 = 2;
+	pub const MaximumRedeemRequestsForFastMatch: u32 = 3;
 	pub static MockRelayBlockNumberProvider: u64 = 0;
 	pub const RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub const MaxScheduledUnbonds: u32 = 14;
-	pub const SubAccountIndex: u16 = 0;
+	pub SubAccountIndexes: Vec<u16> = vec![0, 1, 2];
 	pub ParachainId: ParaId = ParaId::from(PARACHAIN_ID);
 	pub const StakingUpdateFrequency: BlockNumber = 100;
+	pub static MockRelayChainStateRoot: Option<H256> = None;
+	pub const MaxStakingLedgerSyncChange: Permill = Permill::from_percent(10);
+	pub const StakingLedgerSyncPeriod: BlockNumber = 10;
+	pub const XcmExecutionDelay: u32 = 3;
+	pub const MaxRelayChainStateRootAge: u32 = 10;
+	pub const MaxSlashCompensationSlippage: Permill = Permill::from_percent(10);
 }
 ord_parameter_types! {
 	pub const Root: AccountId = DAVE;
@@ -295,8 +417,8 @@ impl Config for Runtime {
 	type MinimumMintThreshold = MinimumMintThreshold;
 	type MinimumRedeemThreshold = MinimumRedeemThreshold;
 	type XcmTransfer = MockXcm;
-	type SovereignSubAccountLocation = MockXcmDestination;
-	type SubAccountIndex = SubAccountIndex;
+	type SovereignSubAccountLocation = MockSovereignSubAccountLocation;
+	type SubAccountIndexes = SubAccountIndexes;
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
@@ -306,9 +428,18 @@ impl Config for Runtime {
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type ParachainAccount = ParachainAccount;
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type MaximumRedeemRequestsForFastMatch = MaximumRedeemRequestsForFastMatch;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
 	type StakingUpdateFrequency = StakingUpdateFrequency;
+	type SovereignSubAccountId = MockSovereignSubAccountId;
+	type RelayChainStateRoot = MockRelayChainStateRoot;
+	type MaxRelayChainStateRootAge = MaxRelayChainStateRootAge;
+	type MaxStakingLedgerSyncChange = MaxStakingLedgerSyncChange;
+	type StakingLedgerSyncPeriod = StakingLedgerSyncPeriod;
+	type XcmExecutionDelay = XcmExecutionDelay;
+	type DEX = MockDEX;
+	type MaxSlashCompensationSlippage = MaxSlashCompensationSlippage;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
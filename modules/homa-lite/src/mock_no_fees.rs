@@ -206,8 +206,6 @@ parameter_types! {
 	pub const LiquidCurrencyId: CurrencyId = LKSM;
 	pub MinimumMintThreshold: Balance = millicent(1);
 	pub MinimumRedeemThreshold: Balance = millicent(1);
-	pub const MockXcmDestination: MultiLocation = MOCK_XCM_DESTINATION;
-	pub const MockXcmAccountId: AccountId = MOCK_XCM_ACCOUNT_ID;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub const MaxRewardPerEra: Permill = Permill::zero();
 	pub MintFee: Balance = 0;
@@ -215,12 +213,19 @@ parameter_types! {
 	pub XcmUnbondFee: Balance = 0;
 	pub const ParachainAccount: AccountId = DAVE;
 	pub const MaximumRedeemRequestMatchesForMint: u32 = 100;
+	pub const MaximumRedeemRequestsForFastMatch: u32 = 100;
 	pub static MockRelayBlockNumberProvider: u64 = 0;
 	pub const RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub const MaxScheduledUnbonds: u32 = 14;
-	pub const SubAccountIndex: u16 = 0;
+	pub SubAccountIndexes: Vec<u16> = vec![0];
 	pub ParachainId: ParaId = ParaId::from(PARACHAIN_ID);
 	pub const StakingUpdateFrequency: BlockNumber = 100;
+	pub static MockRelayChainStateRoot: Option<H256> = None;
+	pub const MaxStakingLedgerSyncChange: Permill = Permill::from_percent(10);
+	pub const StakingLedgerSyncPeriod: BlockNumber = 10;
+	pub const XcmExecutionDelay: u32 = 3;
+	pub const MaxRelayChainStateRootAge: u32 = 10;
+	pub const MaxSlashCompensationSlippage: Permill = Permill::from_percent(10);
 }
 
 ord_parameter_types! {
@@ -245,8 +250,8 @@ impl Config for NoFeeRuntime {
 	type MinimumMintThreshold = MinimumMintThreshold;
 	type MinimumRedeemThreshold = MinimumRedeemThreshold;
 	type XcmTransfer = MockXcm;
-	type SovereignSubAccountLocation = MockXcmDestination;
-	type SubAccountIndex = SubAccountIndex;
+	type SovereignSubAccountLocation = MockSovereignSubAccountLocation;
+	type SubAccountIndexes = SubAccountIndexes;
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
@@ -256,9 +261,18 @@ impl Config for NoFeeRuntime {
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type ParachainAccount = ParachainAccount;
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type MaximumRedeemRequestsForFastMatch = MaximumRedeemRequestsForFastMatch;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
 	type StakingUpdateFrequency = StakingUpdateFrequency;
+	type SovereignSubAccountId = MockSovereignSubAccountId;
+	type RelayChainStateRoot = MockRelayChainStateRoot;
+	type MaxRelayChainStateRootAge = MaxRelayChainStateRootAge;
+	type MaxStakingLedgerSyncChange = MaxStakingLedgerSyncChange;
+	type StakingLedgerSyncPeriod = StakingLedgerSyncPeriod;
+	type XcmExecutionDelay = XcmExecutionDelay;
+	type DEX = MockDEX;
+	type MaxSlashCompensationSlippage = MaxSlashCompensationSlippage;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<NoFeeRuntime>;
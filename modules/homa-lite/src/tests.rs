@@ -23,10 +23,36 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	dollar, millicent, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, Origin, Runtime, System,
-	ACALA, ALICE, BOB, CHARLIE, DAVE, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM,
+	dollar, millicent, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, MockRelayChainStateRoot,
+	MockSwapRate, Origin, Runtime, StakingUpdateFrequency, System, ACALA, ALICE, BOB, CHARLIE, DAVE, DEX_ACCOUNT,
+	INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM, MOCK_XCM_ACCOUNT_ID,
 };
 use sp_runtime::traits::BadOrigin;
+use sp_trie::{MemoryDB, TrieDBMut, TrieMut};
+
+/// Build a relaychain state trie with the staking ledger of the sovereign sub-account, record its
+/// root as the state root of the current relaychain block and return the storage proof.
+fn mock_staking_ledger_proof(active: Balance) -> Vec<Vec<u8>> {
+	mock_staking_ledger_proof_with_unbonding(active, 0)
+}
+
+fn mock_staking_ledger_proof_with_unbonding(active: Balance, unbonding: Balance) -> Vec<Vec<u8>> {
+	let ledger = RelayChainStakingLedger {
+		stash: MOCK_XCM_ACCOUNT_ID,
+		total: active + unbonding,
+		active,
+	};
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = H256::default();
+	{
+		let mut trie = TrieDBMut::<Layout<BlakeTwo256>>::new(&mut db, &mut root);
+		trie.insert(&HomaLite::staking_ledger_key(0), &ledger.encode()).unwrap();
+		trie.insert(b"other_key", &[1u8; 64]).unwrap();
+	}
+	MockRelayChainStateRoot::set(Some(root));
+	HomaLite::on_finalize(System::block_number());
+	db.drain().into_iter().map(|(_, (value, _))| value).collect()
+}
 
 #[test]
 fn mock_initialize_token_works() {
@@ -1470,7 +1496,7 @@ fn on_idle_matches_redeem_based_on_weights() {
 		));
 
 		// Get the currently benchmarked weight.
-		let xcm_weight = <Runtime as crate::Config>::WeightInfo::xcm_unbond();
+		let xcm_weight = <Runtime as crate::Config>::WeightInfo::xcm_unbond(SubAccountIndexes::get().len() as u32);
 		let redeem = <Runtime as crate::Config>::WeightInfo::redeem_with_available_staking_balance();
 
 		// on_idle does nothing with insufficient weight
@@ -1661,6 +1687,7 @@ fn available_staking_balances_can_handle_rounding_error_dust() {
 		assert_eq!(
 			events,
 			vec![
+				crate::Event::ScheduledUnbondWithdrewFromSubAccounts(vec![(0, 999_999_999_999)]),
 				crate::Event::ScheduledUnbondWithdrew(999_999_999_999),
 				crate::Event::TotalStakingCurrencySet(999_237_000_000_002),
 				crate::Event::Redeemed(ALICE, 0, 9_987_632_930_985),
@@ -1748,3 +1775,438 @@ fn mint_can_handle_rounding_error_dust() {
 		);
 	});
 }
+
+#[test]
+fn sync_staking_ledger_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockRelayBlockNumberProvider::set(1);
+		let proof = mock_staking_ledger_proof(dollar(1_050));
+		// The state root of relaychain block 2 isn't recorded.
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), 2, proof.clone()),
+			Error::<Runtime>::RelayChainStateRootNotAvailable
+		);
+
+		MockRelayChainStateRoot::set(Some(H256::repeat_byte(1)));
+		HomaLite::on_finalize(System::block_number());
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), 1, proof),
+			Error::<Runtime>::InvalidStakingLedgerProof
+		);
+
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+
+		// The change of the total staking currency is bounded.
+		let proof = mock_staking_ledger_proof(dollar(1_101));
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), MockRelayBlockNumberProvider::get(), proof),
+			Error::<Runtime>::ExceededMaxStakingLedgerSyncChange
+		);
+		let proof = mock_staking_ledger_proof(dollar(899));
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), MockRelayBlockNumberProvider::get(), proof),
+			Error::<Runtime>::ExceededMaxStakingLedgerSyncChange
+		);
+
+		let proof = mock_staking_ledger_proof(dollar(1_050));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof.clone()
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_050));
+		assert_eq!(HomaLite::last_staking_ledger_sync(), 1);
+		assert_eq!(HomaLite::sub_account_ledgers(0).bonded, dollar(1_050));
+		System::assert_has_event(Event::HomaLite(crate::Event::TotalStakingCurrencySet(dollar(1_050))));
+		System::assert_last_event(Event::HomaLite(crate::Event::StakingLedgerSynced(1, dollar(1_050))));
+
+		// Can only sync once for each sync period.
+		assert_noop!(
+			HomaLite::sync_staking_ledger(
+				Origin::signed(ALICE),
+				MockRelayBlockNumberProvider::get(),
+				proof.clone()
+			),
+			Error::<Runtime>::StakingLedgerAlreadySynced
+		);
+		MockRelayBlockNumberProvider::set(10);
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), MockRelayBlockNumberProvider::get(), proof),
+			Error::<Runtime>::StakingLedgerAlreadySynced
+		);
+
+		MockRelayBlockNumberProvider::set(11);
+		let proof = mock_staking_ledger_proof(dollar(950));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(BOB),
+			MockRelayBlockNumberProvider::get(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(950));
+		assert_eq!(HomaLite::last_staking_ledger_sync(), 11);
+	});
+}
+
+#[test]
+fn sync_staking_ledger_with_recent_relaychain_state_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), dollar(INITIAL_BALANCE)));
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+
+		// The proof is built against relaychain block 1 and submitted at relaychain block 10.
+		MockRelayBlockNumberProvider::set(1);
+		let proof = mock_staking_ledger_proof(dollar(1_050));
+		MockRelayBlockNumberProvider::set(5);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100)));
+		MockRelayBlockNumberProvider::set(10);
+		MockRelayChainStateRoot::set(Some(H256::repeat_byte(1)));
+		HomaLite::on_finalize(System::block_number());
+
+		// The staking currency minted after relaychain block 1 is in flight for its ledger.
+		assert_ok!(HomaLite::sync_staking_ledger(Origin::signed(ALICE), 1, proof));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_150));
+		assert_eq!(HomaLite::last_staking_ledger_sync(), 1);
+
+		// The state root is only kept for `MaxRelayChainStateRootAge` relaychain blocks.
+		MockRelayBlockNumberProvider::set(11);
+		let proof = mock_staking_ledger_proof(dollar(1_150));
+		MockRelayBlockNumberProvider::set(21);
+		HomaLite::on_finalize(System::block_number());
+		assert_noop!(
+			HomaLite::sync_staking_ledger(Origin::signed(ALICE), 11, proof),
+			Error::<Runtime>::RelayChainStateRootNotAvailable
+		);
+	});
+}
+
+#[test]
+fn sync_staking_ledger_reconciles_staking_currency_in_flight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), dollar(INITIAL_BALANCE)));
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+		AvailableStakingBalance::<Runtime>::put(dollar(50));
+
+		// The staking currency minted at relaychain block 10 is in flight until block 13.
+		MockRelayBlockNumberProvider::set(10);
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(100)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_100));
+		assert_eq!(HomaLite::staking_currency_in_flight(12), (dollar(100), 0));
+		assert_eq!(HomaLite::staking_currency_in_flight(13), (0, 0));
+
+		// The ledger doesn't include the minted amount yet.
+		MockRelayBlockNumberProvider::set(11);
+		let proof = mock_staking_ledger_proof(dollar(1_010));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_160));
+		System::assert_last_event(Event::HomaLite(crate::Event::StakingLedgerSynced(11, dollar(1_160))));
+
+		// The interest estimate is no longer applied once the staking ledger is synced.
+		assert_ok!(HomaLite::set_staking_interest_rate_per_update(
+			Origin::root(),
+			Permill::from_percent(1)
+		));
+		HomaLite::on_initialize(StakingUpdateFrequency::get());
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_160));
+
+		// The minted amount has been executed on the relaychain.
+		MockRelayBlockNumberProvider::set(21);
+		let proof = mock_staking_ledger_proof(dollar(1_120));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_170));
+	});
+}
+
+#[test]
+fn fast_match_redeems_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(LKSM, &ALICE, dollar(2_000)));
+		assert_ok!(Currencies::deposit(LKSM, &BOB, dollar(1_000)));
+		assert_ok!(Currencies::deposit(KSM, &CHARLIE, dollar(1_000)));
+
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(DAVE),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ALICE),
+			dollar(2_000),
+			Permill::from_percent(1)
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(BOB),
+			dollar(1_000),
+			Permill::from_percent(5)
+		));
+		assert_eq!(
+			HomaLite::get_redeem_liquidity(),
+			vec![
+				(Permill::from_percent(10), dollar(999), 1),
+				(Permill::from_percent(5), dollar(999), 1),
+				(Permill::from_percent(1), dollar(1_998), 1),
+			]
+		);
+
+		// Amount must be above the minimum threshold.
+		assert_noop!(
+			HomaLite::fast_match_redeems(Origin::signed(CHARLIE), dollar(5), Permill::zero(), vec![]),
+			Error::<Runtime>::AmountBelowMinimumThreshold
+		);
+
+		// At most `MaximumRedeemRequestsForFastMatch` redeem requests can be prioritized.
+		assert_noop!(
+			HomaLite::fast_match_redeems(
+				Origin::signed(CHARLIE),
+				dollar(1_500),
+				Permill::from_percent(5),
+				vec![ALICE, DAVE, BOB, CHARLIE]
+			),
+			Error::<Runtime>::TooManyRedeemRequests
+		);
+
+		// Requests with additional fee lower than `min_additional_fee` are not matched.
+		assert_ok!(HomaLite::fast_match_redeems(
+			Origin::signed(CHARLIE),
+			dollar(1_500),
+			Permill::from_percent(5),
+			vec![ALICE, DAVE, BOB]
+		));
+		assert_eq!(
+			RedeemRequests::<Runtime>::get(&ALICE),
+			Some((dollar(1_998), Permill::from_percent(1)))
+		);
+		assert_eq!(RedeemRequests::<Runtime>::get(&DAVE), None);
+		// BOB's request is partially filled.
+		assert_eq!(
+			RedeemRequests::<Runtime>::get(&BOB),
+			Some((dollar(498), Permill::from_percent(5)))
+		);
+		System::assert_has_event(Event::HomaLite(crate::Event::Redeemed(
+			DAVE,
+			dollar(8_991) / 100,
+			dollar(999),
+		)));
+		System::assert_last_event(Event::HomaLite(crate::Event::RedeemRequestPartiallyFilled(
+			BOB,
+			CHARLIE,
+			dollar(501),
+			dollar(498),
+		)));
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), dollar(1_500));
+		assert_eq!(Currencies::free_balance(KSM, &CHARLIE), dollar(862_495) / 1_000);
+		assert_eq!(Currencies::reserved_balance(LKSM, &BOB), dollar(498));
+
+		assert_eq!(
+			HomaLite::get_redeem_liquidity(),
+			vec![
+				(Permill::from_percent(5), dollar(498), 1),
+				(Permill::from_percent(1), dollar(1_998), 1),
+			]
+		);
+
+		// The rest of the redeem requests are matched after the prioritized requests.
+		assert_ok!(HomaLite::fast_match_redeems(
+			Origin::signed(CHARLIE),
+			dollar(5_000),
+			Permill::from_percent(1),
+			vec![]
+		));
+		assert_eq!(RedeemRequests::<Runtime>::get(&ALICE), None);
+		assert_eq!(RedeemRequests::<Runtime>::get(&BOB), None);
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), dollar(3_996));
+		assert_eq!(HomaLite::get_redeem_liquidity(), vec![]);
+	});
+}
+
+#[test]
+fn distribute_increment_and_decrement_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(HomaLite::distribute_increment(vec![], 1000), vec![]);
+		assert_eq!(
+			HomaLite::distribute_increment(vec![(2, 200), (1, 300), (0, 400)], 1000),
+			vec![(2, 1000)]
+		);
+
+		assert_eq!(HomaLite::distribute_decrement(vec![], 1000), vec![]);
+		assert_eq!(
+			HomaLite::distribute_decrement(vec![(0, 400), (1, 300), (2, 0), (3, 200)], 1000),
+			vec![(0, 400), (1, 300), (3, 200)]
+		);
+		assert_eq!(
+			HomaLite::distribute_decrement(vec![(0, 400), (1, 300), (2, 200)], 500),
+			vec![(0, 400), (1, 100)]
+		);
+	});
+}
+
+#[test]
+fn mint_and_unbond_are_distributed_across_sub_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ledger = |bonded, unbonding| SubAccountLedger { bonded, unbonding };
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), dollar(INITIAL_BALANCE)));
+
+		// Mints are staked with the sub-account with the least bonded amount.
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(1_000)));
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(500)));
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(200)));
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(400)));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(1_000), 0));
+		assert_eq!(HomaLite::sub_account_ledgers(1), ledger(dollar(500), 0));
+		assert_eq!(HomaLite::sub_account_ledgers(2), ledger(dollar(600), 0));
+
+		// Unbonds are distributed starting from the sub-account with the most bonded amount.
+		assert_ok!(HomaLite::schedule_unbond(Origin::root(), dollar(1_200), 0));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(0, dollar(1_000)));
+		assert_eq!(HomaLite::sub_account_ledgers(1), ledger(dollar(500), 0));
+		assert_eq!(HomaLite::sub_account_ledgers(2), ledger(dollar(400), dollar(200)));
+		System::assert_has_event(Event::HomaLite(crate::Event::ScheduledUnbondDistributed(vec![
+			(0, dollar(1_000)),
+			(2, dollar(200)),
+		])));
+
+		// The unbond exceeding the bonded amounts is attributed to the first sub-account.
+		assert_ok!(HomaLite::schedule_unbond(Origin::root(), dollar(1_000), 0));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(0, dollar(1_100)));
+		assert_eq!(HomaLite::sub_account_ledgers(1), ledger(0, dollar(500)));
+		assert_eq!(HomaLite::sub_account_ledgers(2), ledger(0, dollar(600)));
+
+		// Unbonded currencies are withdrawn from the sub-accounts with the most unbonding amount.
+		assert_eq!(HomaLite::process_scheduled_unbond(dollar(1_200)), Ok(dollar(1_200)));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(0, 0));
+		assert_eq!(HomaLite::sub_account_ledgers(1), ledger(0, dollar(500)));
+		assert_eq!(HomaLite::sub_account_ledgers(2), ledger(0, dollar(500)));
+		assert_eq!(HomaLite::available_staking_balance(), dollar(1_200));
+		System::assert_has_event(Event::HomaLite(crate::Event::ScheduledUnbondWithdrewFromSubAccounts(
+			vec![(0, dollar(1_100)), (2, dollar(100))],
+		)));
+
+		assert_ok!(HomaLite::process_scheduled_unbond(dollar(1_000)));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(0, 0));
+		assert_eq!(HomaLite::sub_account_ledgers(1), ledger(0, 0));
+		assert_eq!(HomaLite::sub_account_ledgers(2), ledger(0, 0));
+	});
+}
+
+#[test]
+fn scheduled_unbond_is_reconciled_with_synced_ledgers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ledger = |bonded, unbonding| SubAccountLedger { bonded, unbonding };
+		let weight = 1_000_000_000_000;
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+
+		MockRelayBlockNumberProvider::set(1);
+		let proof = mock_staking_ledger_proof_with_unbonding(dollar(900), dollar(100));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof
+		));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(900), dollar(100)));
+
+		// Only the amount unbonding in the synced ledgers is withdrew.
+		assert_ok!(HomaLite::replace_schedule_unbond(
+			Origin::root(),
+			vec![(dollar(300), 0)]
+		));
+		HomaLite::on_idle(MockRelayBlockNumberProvider::get(), weight);
+		assert_eq!(ScheduledUnbond::<Runtime>::get(), vec![(dollar(200), 0)]);
+		assert_eq!(HomaLite::available_staking_balance(), dollar(100));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(900), 0));
+		System::assert_has_event(Event::HomaLite(crate::Event::ScheduledUnbondWithdrewFromSubAccounts(
+			vec![(0, dollar(100))],
+		)));
+		System::assert_last_event(Event::HomaLite(crate::Event::ScheduledUnbondWithdrew(dollar(100))));
+
+		// Nothing is withdrew until more staking currency is unbonding.
+		HomaLite::on_idle(MockRelayBlockNumberProvider::get(), weight);
+		assert_eq!(ScheduledUnbond::<Runtime>::get(), vec![(dollar(200), 0)]);
+		assert_eq!(HomaLite::available_staking_balance(), dollar(100));
+
+		MockRelayBlockNumberProvider::set(11);
+		let proof = mock_staking_ledger_proof_with_unbonding(dollar(800), dollar(200));
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof.clone()
+		));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(800), dollar(200)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_100));
+
+		MockRelayBlockNumberProvider::set(21);
+		HomaLite::on_idle(MockRelayBlockNumberProvider::get(), weight);
+		assert_eq!(ScheduledUnbond::<Runtime>::get(), vec![]);
+		assert_eq!(HomaLite::available_staking_balance(), dollar(300));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(800), 0));
+
+		// The staking currency withdrew by XCM in flight is reconciled with the unbonding ledger,
+		// which is unchanged at relaychain block 21.
+		HomaLite::on_finalize(System::block_number());
+		assert_ok!(HomaLite::sync_staking_ledger(
+			Origin::signed(ALICE),
+			MockRelayBlockNumberProvider::get(),
+			proof
+		));
+		assert_eq!(HomaLite::sub_account_ledgers(0), ledger(dollar(800), 0));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_100));
+	});
+}
+
+#[test]
+fn compensate_slash_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+
+		// The confiscated liquid currency is burned if it can't be swapped.
+		OnSlash::<Runtime>::happened(&(DAVE, dollar(1_000)));
+		assert_eq!(Currencies::free_balance(LKSM, &DAVE), dollar(999_000));
+		assert_eq!(Currencies::total_issuance(LKSM), dollar(999_000));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+		System::assert_last_event(Event::HomaLite(crate::Event::SlashCompensated(
+			dollar(1_000),
+			0,
+			dollar(1_000),
+		)));
+
+		// The swapped staking currency is staked and added to TotalStakingCurrency.
+		MockSwapRate::set(Some(Ratio::saturating_from_rational(95, 1_000)));
+		OnSlash::<Runtime>::happened(&(DAVE, dollar(1_000)));
+		assert_eq!(Currencies::free_balance(LKSM, &DAVE), dollar(998_000));
+		assert_eq!(Currencies::free_balance(LKSM, &DEX_ACCOUNT), dollar(1_000));
+		assert_eq!(Currencies::free_balance(KSM, &DAVE), 0);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_095));
+		assert_eq!(HomaLite::staking_currency_in_flight(0), (dollar(95), 0));
+		System::assert_last_event(Event::HomaLite(crate::Event::SlashCompensated(
+			dollar(1_000),
+			dollar(95),
+			0,
+		)));
+
+		// The confiscated liquid currency is burned if the slippage is too large.
+		MockSwapRate::set(Some(Ratio::saturating_from_rational(80, 1_000)));
+		OnSlash::<Runtime>::happened(&(DAVE, dollar(1_000)));
+		assert_eq!(Currencies::free_balance(LKSM, &DAVE), dollar(997_000));
+		assert_eq!(Currencies::total_issuance(LKSM), dollar(998_000));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_095));
+		System::assert_last_event(Event::HomaLite(crate::Event::SlashCompensated(
+			dollar(1_000),
+			0,
+			dollar(1_000),
+		)));
+
+		// The compensation fails if the confiscated liquid currency is not in the source.
+		OnSlash::<Runtime>::happened(&(ALICE, dollar(1_000)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_095));
+		System::assert_last_event(Event::HomaLite(crate::Event::SlashCompensationFailed(
+			ALICE,
+			dollar(1_000),
+		)));
+	});
+}
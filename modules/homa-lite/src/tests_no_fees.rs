@@ -113,7 +113,9 @@ fn no_fee_runtime_has_no_fees() {
 				crate::Event::RedeemRequested(BOB, dollar(50_000), Permill::zero(), 0),
 				crate::Event::Redeemed(BOB, dollar(5000), dollar(50000)),
 				crate::Event::Minted(ALICE, dollar(5000), dollar(50000)),
+				crate::Event::ScheduledUnbondDistributed(vec![(0, dollar(50_000))]),
 				crate::Event::ScheduledUnbondAdded(dollar(50_000), 0),
+				crate::Event::ScheduledUnbondWithdrewFromSubAccounts(vec![(0, dollar(50_000))]),
 				crate::Event::ScheduledUnbondWithdrew(dollar(50_000)),
 				crate::Event::RedeemRequested(DAVE, dollar(100_000), Permill::zero(), 0),
 				crate::Event::TotalStakingCurrencySet(dollar(96_000)),
@@ -57,11 +57,13 @@ pub trait WeightInfo {
 	fn set_minting_cap() -> Weight;
 	fn set_xcm_dest_weight() -> Weight;
 	fn request_redeem() -> Weight;
-	fn schedule_unbond() -> Weight;
+	fn schedule_unbond(s: u32, ) -> Weight;
 	fn replace_schedule_unbond() -> Weight;
 	fn set_staking_interest_rate_per_update() -> Weight;
 	fn redeem_with_available_staking_balance() -> Weight;
-	fn xcm_unbond() -> Weight;
+	fn xcm_unbond(s: u32, ) -> Weight;
+	fn sync_staking_ledger(s: u32, d: u32) -> Weight;
+	fn fast_match_redeems(n: u32, ) -> Weight;
 }
 
 /// Weights for module_homa_lite using the Acala node and recommended hardware.
@@ -114,10 +116,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
-	fn schedule_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn schedule_unbond(s: u32, ) -> Weight {
 		(12_000_000 as Weight)
+			.saturating_add((3_512_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
 	fn replace_schedule_unbond() -> Weight {
 		(10_000_000 as Weight)
@@ -132,10 +138,34 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn xcm_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn xcm_unbond(s: u32, ) -> Weight {
 		(30_000_000 as Weight)
+			.saturating_add((6_268_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn sync_staking_ledger(s: u32, d: u32) -> Weight {
+		(62_413_000 as Weight)
+			.saturating_add((4_126_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((1_842_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fast_match_redeems(n: u32, ) -> Weight {
+		(132_000_000 as Weight)
+			.saturating_add((47_728_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
 	}
 }
 
@@ -188,10 +218,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
-	fn schedule_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn schedule_unbond(s: u32, ) -> Weight {
 		(12_000_000 as Weight)
+			.saturating_add((3_512_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
 	fn replace_schedule_unbond() -> Weight {
 		(10_000_000 as Weight)
@@ -206,9 +240,33 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	fn xcm_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn xcm_unbond(s: u32, ) -> Weight {
 		(30_000_000 as Weight)
+			.saturating_add((6_268_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn sync_staking_ledger(s: u32, d: u32) -> Weight {
+		(62_413_000 as Weight)
+			.saturating_add((4_126_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((1_842_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fast_match_redeems(n: u32, ) -> Weight {
+		(132_000_000 as Weight)
+			.saturating_add((47_728_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
 	}
 }
@@ -29,7 +29,12 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, traits::Contains, transactional};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{Contains, StorageVersion},
+	transactional,
+};
 use frame_system::pallet_prelude::*;
 use orml_traits::{BasicCurrency, BasicLockableCurrency, Happened, LockIdentifier};
 use primitives::Balance;
@@ -40,8 +45,8 @@ use sp_runtime::{
 	traits::{BlockNumberProvider, Bounded, MaybeDisplay, MaybeSerializeDeserialize, Member, Zero},
 	DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::{fmt::Debug, vec::Vec};
-use support::{ExchangeRateProvider, Ratio};
+use sp_std::{convert::TryInto, fmt::Debug, vec::Vec};
+use support::{ExchangeRateProvider, Ratio, ValidatorInsurance, ValidatorInsuranceProvider};
 
 mod mock;
 mod tests;
@@ -152,6 +157,15 @@ pub struct SlashInfo<Balance, RelaychainAccountId> {
 	relaychain_token_amount: Balance,
 }
 
+/// Slash history of a relay chain validator
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, Default, PartialEq, MaxEncodedLen, TypeInfo)]
+pub struct SlashRecord {
+	/// The number of slashes submitted for the validator
+	count: u32,
+	/// The total amount of tokens slashed from the validator's backing on the relay chain
+	total_relaychain_token_amount: Balance,
+}
+
 /// Validator insurance and frozen status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
 pub struct ValidatorBacking {
@@ -181,12 +195,16 @@ pub mod module {
 		#[pallet::constant]
 		/// The minimum amount of insurance a validator needs.
 		type ValidatorInsuranceThreshold: Get<Balance>;
+		#[pallet::constant]
+		/// The maximum number of validators whose insurance reaches the insurance threshold.
+		type MaxInsuredValidators: Get<u32>;
 		/// The AccountId that can perform a freeze.
 		type FreezeOrigin: EnsureOrigin<Self::Origin>;
 		/// The AccountId that can perform a slash.
 		type SlashOrigin: EnsureOrigin<Self::Origin>;
-		/// Callback to be called when a slash occurs.
-		type OnSlash: Happened<Balance>;
+		/// Callback to be called when a slash occurs, with `InsuranceCompensationAccount` and
+		/// the amount of insurance confiscated to it.
+		type OnSlash: Happened<(Self::AccountId, Balance)>;
 		/// Exchange rate between staked token and liquid token equivalent.
 		type LiquidStakingExchangeRateProvider: ExchangeRateProvider;
 		type WeightInfo: WeightInfo;
@@ -197,6 +215,9 @@ pub mod module {
 
 		// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		/// The account receiving the slashed insurance to compensate liquid token holders.
+		type InsuranceCompensationAccount: Get<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -204,6 +225,9 @@ pub mod module {
 		BelowMinBondAmount,
 		UnbondingExists,
 		FrozenValidator,
+		/// There are already `MaxInsuredValidators` validators whose insurance reaches the
+		/// insurance threshold.
+		TooManyInsuredValidators,
 	}
 
 	#[pallet::event]
@@ -247,11 +271,67 @@ pub mod module {
 	pub type ValidatorBackings<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, ValidatorBacking, OptionQuery>;
 
+	/// Slash history for validators.
+	///
+	/// SlashRecords: map RelaychainAccountId => Option<SlashRecord>
+	#[pallet::storage]
+	#[pallet::getter(fn slash_records)]
+	pub type SlashRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, SlashRecord, OptionQuery>;
+
+	/// The validators whose insurance reaches the insurance threshold.
+	///
+	/// InsuredValidators: Vec<RelaychainAccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn insured_validators)]
+	pub type InsuredValidators<T: Config> =
+		StorageValue<_, BoundedVec<T::RelaychainAccountId, T::MaxInsuredValidators>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+				return 0;
+			}
+
+			// index the insured validators, the most insured first.
+			let mut count: u64 = 0;
+			let mut insured_validators = ValidatorBackings::<T>::iter()
+				.inspect(|_| count += 1)
+				.filter(|(_, backing)| backing.total_insurance >= T::ValidatorInsuranceThreshold::get())
+				.map(|(validator, backing)| (validator, backing.total_insurance))
+				.collect::<Vec<_>>();
+			insured_validators.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+			let max_insured_validators = T::MaxInsuredValidators::get() as usize;
+			if insured_validators.len() > max_insured_validators {
+				log::warn!(
+					target: "homa-validator-list",
+					"on_runtime_upgrade: {:?} insured validators exceed MaxInsuredValidators, only the most insured are indexed",
+					insured_validators.len()
+				);
+				insured_validators.truncate(max_insured_validators);
+			}
+			InsuredValidators::<T>::put(
+				TryInto::<BoundedVec<_, T::MaxInsuredValidators>>::try_into(
+					insured_validators
+						.into_iter()
+						.map(|(validator, _)| validator)
+						.collect::<Vec<_>>(),
+				)
+				.unwrap_or_default(),
+			);
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(count.saturating_add(1), 2)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -437,6 +517,15 @@ pub mod module {
 				relaychain_token_amount,
 			} in slashes
 			{
+				SlashRecords::<T>::mutate(&validator, |maybe_record| {
+					let mut record = maybe_record.take().unwrap_or_default();
+					record.count = record.count.saturating_add(1);
+					record.total_relaychain_token_amount = record
+						.total_relaychain_token_amount
+						.saturating_add(relaychain_token_amount);
+					*maybe_record = Some(record);
+				});
+
 				let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
 				let insurance_loss = staking_liquid_exchange_rate
 					.saturating_mul_int(relaychain_token_amount)
@@ -463,7 +552,9 @@ pub mod module {
 				}
 			}
 
-			T::OnSlash::happened(&actual_total_slashing);
+			let compensation_account = T::InsuranceCompensationAccount::get();
+			T::LiquidTokenCurrency::deposit(&compensation_account, actual_total_slashing)?;
+			T::OnSlash::happened(&(compensation_account, actual_total_slashing));
 			Ok(())
 		}
 	}
@@ -511,6 +602,19 @@ impl<T: Config> Pallet<T> {
 										T::OnDecreaseGuarantee::happened(&(guarantor.clone(), validator.clone(), gap));
 									};
 
+									let is_insured = vb.total_insurance >= T::ValidatorInsuranceThreshold::get();
+									InsuredValidators::<T>::try_mutate(|insured_validators| -> DispatchResult {
+										let was_insured = insured_validators.contains(validator);
+										if is_insured && !was_insured {
+											insured_validators
+												.try_push(validator.clone())
+												.map_err(|_| Error::<T>::TooManyInsuredValidators)?;
+										} else if !is_insured && was_insured {
+											insured_validators.retain(|v| v != validator);
+										}
+										Ok(())
+									})?;
+
 									if tl.is_zero() {
 										*maybe_total_locked = None;
 										T::LiquidTokenCurrency::remove_lock(HOMA_VALIDATOR_LIST_ID, guarantor)?;
@@ -541,3 +645,39 @@ impl<T: Config> Contains<T::RelaychainAccountId> for Pallet<T> {
 			>= T::ValidatorInsuranceThreshold::get()
 	}
 }
+
+impl<T: Config> ValidatorInsuranceProvider<T::RelaychainAccountId, Balance> for Pallet<T> {
+	fn validators(max_validators: u32) -> Vec<(T::RelaychainAccountId, ValidatorInsurance<Balance>)> {
+		let mut validators = Self::insured_validators()
+			.into_iter()
+			.filter_map(|validator| {
+				Self::validator_backings(&validator)
+					.filter(|backing| backing.total_insurance >= T::ValidatorInsuranceThreshold::get())
+					.map(|backing| (validator, backing))
+			})
+			.collect::<Vec<_>>();
+
+		// sort by insurance descending, ties are broken by the validator id.
+		validators.sort_by(|a, b| {
+			b.1.total_insurance
+				.cmp(&a.1.total_insurance)
+				.then_with(|| a.0.cmp(&b.0))
+		});
+
+		validators
+			.into_iter()
+			.take(max_validators as usize)
+			.map(|(validator, backing)| {
+				let slash_count = Self::slash_records(&validator).unwrap_or_default().count;
+				(
+					validator,
+					ValidatorInsurance {
+						total_insurance: backing.total_insurance,
+						is_frozen: backing.is_frozen,
+						slash_count,
+					},
+				)
+			})
+			.collect()
+	}
+}
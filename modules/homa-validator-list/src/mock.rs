@@ -42,6 +42,7 @@ pub const BOB: AccountId = 1;
 pub const VALIDATOR_1: AccountId = 2;
 pub const VALIDATOR_2: AccountId = 3;
 pub const VALIDATOR_3: AccountId = 4;
+pub const INSURANCE_COMPENSATION: AccountId = 20;
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
 
@@ -139,8 +140,8 @@ thread_local! {
 }
 
 pub struct MockOnSlash;
-impl Happened<Balance> for MockOnSlash {
-	fn happened(amount: &Balance) {
+impl Happened<(AccountId, Balance)> for MockOnSlash {
+	fn happened((_, amount): &(AccountId, Balance)) {
 		ACCUMULATED_SLASH.with(|v| *v.borrow_mut() += amount);
 	}
 }
@@ -190,7 +191,9 @@ parameter_types! {
 	pub const MinBondAmount: Balance = 100;
 	pub const BondingDuration: BlockNumber = 100;
 	pub const ValidatorInsuranceThreshold: Balance = 200;
+	pub static MaxInsuredValidators: u32 = 3;
 	pub static MockBlockNumberProvider: u64 = 0;
+	pub const InsuranceCompensationAccount: AccountId = INSURANCE_COMPENSATION;
 }
 
 impl BlockNumberProvider for MockBlockNumberProvider {
@@ -212,6 +215,7 @@ impl Config for Runtime {
 	type MinBondAmount = MinBondAmount;
 	type BondingDuration = BondingDuration;
 	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
+	type MaxInsuredValidators = MaxInsuredValidators;
 	type FreezeOrigin = EnsureSignedBy<Admin, AccountId>;
 	type SlashOrigin = EnsureSignedBy<Admin, AccountId>;
 	type OnSlash = MockOnSlash;
@@ -220,6 +224,7 @@ impl Config for Runtime {
 	type OnIncreaseGuarantee = MockOnIncreaseGuarantee;
 	type OnDecreaseGuarantee = MockOnDecreaseGuarantee;
 	type BlockNumberProvider = MockBlockNumberProvider;
+	type InsuranceCompensationAccount = InsuranceCompensationAccount;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
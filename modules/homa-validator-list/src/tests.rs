@@ -757,6 +757,26 @@ fn slash_work() {
 			HomaValidatorListModule::total_locked_by_guarantor(BOB).unwrap_or_default(),
 			281
 		);
+
+		assert_eq!(
+			HomaValidatorListModule::slash_records(VALIDATOR_1),
+			Some(SlashRecord {
+				count: 1,
+				total_relaychain_token_amount: 90
+			})
+		);
+		assert_eq!(
+			HomaValidatorListModule::slash_records(VALIDATOR_2),
+			Some(SlashRecord {
+				count: 1,
+				total_relaychain_token_amount: 50
+			})
+		);
+		assert_eq!(HomaValidatorListModule::slash_records(VALIDATOR_3), None);
+
+		// the slashed insurance is confiscated to compensate liquid token holders.
+		assert_eq!(OrmlTokens::accounts(INSURANCE_COMPENSATION, LDOT).free, 59 + 119 + 100);
+		assert_eq!(ACCUMULATED_SLASH.with(|v| *v.borrow()), 59 + 119 + 100);
 	});
 }
 
@@ -784,3 +804,102 @@ fn contains_work() {
 		assert!(HomaValidatorListModule::contains(&VALIDATOR_1));
 	});
 }
+
+#[test]
+fn validator_insurance_provider_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockBlockNumberProvider::set(1);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(BOB), VALIDATOR_2, 300));
+		assert_eq!(
+			<HomaValidatorListModule as ValidatorInsuranceProvider<_, _>>::validators(10),
+			vec![(
+				VALIDATOR_2,
+				ValidatorInsurance {
+					total_insurance: 300,
+					is_frozen: false,
+					slash_count: 0,
+				}
+			)]
+		);
+
+		assert_ok!(HomaValidatorListModule::freeze(Origin::signed(10), vec![VALIDATOR_2]));
+		assert_ok!(HomaValidatorListModule::slash(
+			Origin::signed(10),
+			vec![SlashInfo {
+				validator: VALIDATOR_2,
+				relaychain_token_amount: 10
+			}]
+		));
+		assert_eq!(
+			<HomaValidatorListModule as ValidatorInsuranceProvider<_, _>>::validators(10),
+			vec![(
+				VALIDATOR_2,
+				ValidatorInsurance {
+					total_insurance: 280,
+					is_frozen: true,
+					slash_count: 1,
+				}
+			)]
+		);
+		assert_eq!(
+			<HomaValidatorListModule as ValidatorInsuranceProvider<_, _>>::validators(0),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn insured_validators_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockBlockNumberProvider::set(1);
+		MaxInsuredValidators::set(2);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_1, 100));
+		assert_eq!(HomaValidatorListModule::insured_validators().into_inner(), vec![]);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_2, 200));
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(BOB), VALIDATOR_1, 300));
+		assert_eq!(
+			HomaValidatorListModule::insured_validators().into_inner(),
+			vec![VALIDATOR_2, VALIDATOR_1]
+		);
+
+		// the most insured validators are provided first.
+		assert_eq!(
+			<HomaValidatorListModule as ValidatorInsuranceProvider<_, _>>::validators(1),
+			vec![(
+				VALIDATOR_1,
+				ValidatorInsurance {
+					total_insurance: 400,
+					is_frozen: false,
+					slash_count: 0,
+				}
+			)]
+		);
+
+		assert_noop!(
+			HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_3, 200),
+			Error::<Runtime>::TooManyInsuredValidators
+		);
+
+		// the validator is removed once its insurance is below the threshold.
+		assert_ok!(HomaValidatorListModule::unbond(Origin::signed(ALICE), VALIDATOR_2, 200));
+		MockBlockNumberProvider::set(101);
+		assert_ok!(HomaValidatorListModule::withdraw_unbonded(
+			Origin::signed(ALICE),
+			VALIDATOR_2
+		));
+		assert_eq!(
+			HomaValidatorListModule::insured_validators().into_inner(),
+			vec![VALIDATOR_1]
+		);
+
+		assert_ok!(HomaValidatorListModule::bond(Origin::signed(ALICE), VALIDATOR_3, 200));
+		assert_eq!(
+			HomaValidatorListModule::insured_validators().into_inner(),
+			vec![VALIDATOR_1, VALIDATOR_3]
+		);
+	});
+}
@@ -0,0 +1,240 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Homa validator selection Module
+//!
+//! ## Overview
+//!
+//! Scores relay chain validators by their insurance, frozen status and slash history from
+//! the homa validator list, together with the votes they received in nominees election.
+//! At every new era the validators with the highest scores are selected, and the homa
+//! sub-accounts nominate them on the relay chain through XCM.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{log, pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use module_support::{CallBuilder, NomineeVotesProvider, OnNewEra, ValidatorInsurance, ValidatorInsuranceProvider};
+use primitives::{Balance, EraIndex};
+use sp_runtime::{
+	traits::{MaybeDisplay, MaybeSerializeDeserialize, Member, Saturating, Zero},
+	Permill,
+};
+use sp_std::{fmt::Debug, prelude::*};
+use xcm::latest::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_xcm::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// The AccountId of a relay chain account.
+		type RelaychainAccountId: Parameter + Member + MaybeSerializeDeserialize + Debug + MaybeDisplay + Ord + Default;
+		/// The insurance status of the relay chain validators.
+		type ValidatorInsurance: ValidatorInsuranceProvider<Self::RelaychainAccountId, Balance>;
+		/// The votes received by the relay chain validators.
+		type NomineeVotes: NomineeVotesProvider<Self::RelaychainAccountId, Balance>;
+		#[pallet::constant]
+		/// The share of a validator's insurance counted in its score.
+		type InsuranceWeight: Get<Permill>;
+		#[pallet::constant]
+		/// The share of a validator's votes counted in its score.
+		type VoteWeight: Get<Permill>;
+		#[pallet::constant]
+		/// The share of the score a validator loses for every recorded slash.
+		type SlashPenalty: Get<Permill>;
+		#[pallet::constant]
+		/// The maximum number of validators to nominate.
+		type MaxNominations: Get<u32>;
+		#[pallet::constant]
+		/// The maximum number of validators scored for every selection.
+		type MaxValidatorCandidates: Get<u32>;
+		#[pallet::constant]
+		/// The indexes of the relay chain sub-accounts that nominate the selected validators.
+		type SubAccountIndexes: Get<Vec<u16>>;
+		/// The XCM call builder for relay chain calls.
+		type RelayChainCallBuilder: CallBuilder<AccountId = Self::RelaychainAccountId, Balance = Balance>;
+		#[pallet::constant]
+		/// The fee, in staking currency, to execute the nominate call of one sub-account on the
+		/// relay chain.
+		type XcmNominateFee: Get<Balance>;
+		#[pallet::constant]
+		/// The weight limit of the nominate call of one sub-account on the relay chain.
+		type XcmNominateWeight: Get<Weight>;
+		/// The origin that can force a new selection.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There is no validator eligible for nomination.
+		NoEligibleValidators,
+		/// Failed to send the XCM message to the relay chain.
+		XcmFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The selected validators are nominated on the relay chain. \[era, nominations\]
+		NominationsUpdated(EraIndex, Vec<T::RelaychainAccountId>),
+	}
+
+	/// The validators nominated on the relay chain.
+	///
+	/// Nominations: Vec<RelaychainAccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn nominations)]
+	pub type Nominations<T: Config> = StorageValue<_, Vec<T::RelaychainAccountId>, ValueQuery>;
+
+	/// Current era index.
+	///
+	/// CurrentEra: EraIndex
+	#[pallet::storage]
+	#[pallet::getter(fn current_era)]
+	pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn integrity_test() {
+			assert!(!T::MaxNominations::get().is_zero());
+			assert!(T::MaxValidatorCandidates::get() >= T::MaxNominations::get());
+			assert!(!T::SubAccountIndexes::get().is_empty());
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Select the validators and nominate them on the relay chain for the current era.
+		/// Ensures the caller is the governance origin.
+		#[pallet::weight(< T as Config >::WeightInfo::force_nominate(T::MaxValidatorCandidates::get()))]
+		#[transactional]
+		pub fn force_nominate(origin: OriginFor<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::select_and_nominate(Self::current_era())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The score of a validator. Frozen validators have no score, and every recorded slash
+	/// reduces the score by `SlashPenalty`.
+	pub fn score(insurance: &ValidatorInsurance<Balance>, votes: Balance) -> Balance {
+		if insurance.is_frozen {
+			return Zero::zero();
+		}
+
+		let score =
+			(T::InsuranceWeight::get() * insurance.total_insurance).saturating_add(T::VoteWeight::get() * votes);
+		Permill::one()
+			.saturating_sub(T::SlashPenalty::get())
+			.saturating_pow(insurance.slash_count as usize)
+			* score
+	}
+
+	/// The validators with the highest scores, at most `MaxNominations` of them. At most
+	/// `MaxValidatorCandidates` validators are scored.
+	pub fn select_validators() -> Vec<T::RelaychainAccountId> {
+		let mut scores = T::ValidatorInsurance::validators(T::MaxValidatorCandidates::get())
+			.into_iter()
+			.map(|(validator, insurance)| {
+				let score = Self::score(&insurance, T::NomineeVotes::votes(&validator));
+				(validator, score)
+			})
+			.filter(|(_, score)| !score.is_zero())
+			.collect::<Vec<_>>();
+
+		// sort by score descending, ties are broken by the validator id.
+		scores.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+		scores
+			.into_iter()
+			.take(T::MaxNominations::get() as usize)
+			.map(|(validator, _)| validator)
+			.collect()
+	}
+
+	/// Select the validators and send the nominate call to the relay chain.
+	fn select_and_nominate(era: EraIndex) -> DispatchResult {
+		let nominations = Self::select_validators();
+		ensure!(!nominations.is_empty(), Error::<T>::NoEligibleValidators);
+
+		let msg = Self::construct_xcm_nominate_message(nominations.clone());
+		let res = pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, msg);
+		log::debug!(target: "homa-validator-selection", "nominate XCM result: {:?}", res);
+		ensure!(res.is_ok(), Error::<T>::XcmFailed);
+
+		Nominations::<T>::put(&nominations);
+		Self::deposit_event(Event::NominationsUpdated(era, nominations));
+		Ok(())
+	}
+
+	/// Construct the XCM message that nominates `targets` from every sub-account. The fee and
+	/// the weight limit are paid for the nominate call of every sub-account.
+	pub fn construct_xcm_nominate_message(targets: Vec<T::RelaychainAccountId>) -> Xcm<()> {
+		let sub_account_indexes = T::SubAccountIndexes::get();
+		let num_sub_accounts = sub_account_indexes.len() as u32;
+		let calls = sub_account_indexes
+			.into_iter()
+			.map(|sub_account_index| {
+				T::RelayChainCallBuilder::utility_as_derivative_call(
+					T::RelayChainCallBuilder::staking_nominate(targets.clone()),
+					sub_account_index,
+				)
+			})
+			.collect();
+		T::RelayChainCallBuilder::finalize_call_into_xcm_message(
+			T::RelayChainCallBuilder::utility_batch_call(calls),
+			T::XcmNominateFee::get().saturating_mul(num_sub_accounts.into()),
+			T::XcmNominateWeight::get().saturating_mul(num_sub_accounts.into()),
+		)
+	}
+}
+
+impl<T: Config> OnNewEra<EraIndex> for Pallet<T> {
+	fn on_new_era(era: EraIndex) {
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::on_new_era(T::MaxValidatorCandidates::get()),
+			DispatchClass::Mandatory,
+		);
+
+		CurrentEra::<T>::put(era);
+		if let Err(e) = Self::select_and_nominate(era) {
+			log::warn!(
+				target: "homa-validator-selection",
+				"on_new_era: failed to nominate validators for era {:?}: {:?}",
+				era, e
+			);
+		}
+	}
+}
@@ -0,0 +1,286 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for homa validator selection module.
+
+#![cfg(test)]
+
+use super::*;
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
+};
+use frame_system::EnsureSignedBy;
+use module_relaychain::RelayChainCallBuilder;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use xcm_executor::traits::{InvertLocation, WeightBounds};
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 0;
+pub const ADMIN: AccountId = 10;
+pub const VALIDATOR_1: AccountId = 1;
+pub const VALIDATOR_2: AccountId = 2;
+pub const VALIDATOR_3: AccountId = 3;
+pub const VALIDATOR_4: AccountId = 4;
+
+mod homa_validator_selection {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub static MockXcmAvailable: bool = true;
+	pub static SentXcm: Vec<Xcm<()>> = vec![];
+}
+
+/// A mock XCM router. Records the messages sent to the relay chain, and fails when
+/// `MockXcmAvailable` is false.
+pub struct MockXcm;
+impl SendXcm for MockXcm {
+	fn send_xcm(dest: impl Into<MultiLocation>, msg: Xcm<()>) -> SendResult {
+		let dest = dest.into();
+		match dest {
+			MultiLocation {
+				parents: 1,
+				interior: Junctions::Here,
+			} if MockXcmAvailable::get() => {
+				let mut sent = SentXcm::get();
+				sent.push(msg);
+				SentXcm::set(sent);
+				Ok(())
+			}
+			_ => Err(SendError::CannotReachDestination(dest, msg)),
+		}
+	}
+}
+
+impl ExecuteXcm<Call> for MockXcm {
+	fn execute_xcm_in_credit(
+		_origin: impl Into<MultiLocation>,
+		mut _message: Xcm<Call>,
+		_weight_limit: Weight,
+		_weight_credit: Weight,
+	) -> Outcome {
+		Outcome::Complete(0)
+	}
+}
+
+impl InvertLocation for MockXcm {
+	fn invert_location(l: &MultiLocation) -> Result<MultiLocation, ()> {
+		Ok(l.clone())
+	}
+}
+
+pub struct MockEnsureXcmOrigin;
+impl EnsureOrigin<Origin> for MockEnsureXcmOrigin {
+	type Success = MultiLocation;
+	fn try_origin(_o: Origin) -> Result<Self::Success, Origin> {
+		Ok(MultiLocation::here())
+	}
+}
+
+pub struct MockWeigher;
+impl WeightBounds<Call> for MockWeigher {
+	fn weight(_message: &mut Xcm<Call>) -> Result<Weight, ()> {
+		Ok(0)
+	}
+
+	fn instr_weight(_message: &Instruction<Call>) -> Result<Weight, ()> {
+		Ok(0)
+	}
+}
+
+impl pallet_xcm::Config for Runtime {
+	type Event = Event;
+	type SendXcmOrigin = MockEnsureXcmOrigin;
+	type XcmRouter = MockXcm;
+	type ExecuteXcmOrigin = MockEnsureXcmOrigin;
+	type XcmExecuteFilter = Nothing;
+	type XcmExecutor = MockXcm;
+	type XcmTeleportFilter = Everything;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = MockWeigher;
+	type LocationInverter = MockXcm;
+	type Origin = Origin;
+	type Call = Call;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+}
+
+parameter_types! {
+	pub static MockValidators: Vec<(AccountId, ValidatorInsurance<Balance>)> = vec![];
+	pub static MockVotes: Vec<(AccountId, Balance)> = vec![];
+	pub static MaxValidatorCandidates: u32 = 10;
+}
+
+pub struct MockValidatorInsurance;
+impl ValidatorInsuranceProvider<AccountId, Balance> for MockValidatorInsurance {
+	fn validators(max_validators: u32) -> Vec<(AccountId, ValidatorInsurance<Balance>)> {
+		MockValidators::get()
+			.into_iter()
+			.take(max_validators as usize)
+			.collect()
+	}
+}
+
+pub struct MockNomineeVotes;
+impl NomineeVotesProvider<AccountId, Balance> for MockNomineeVotes {
+	fn votes(nominee: &AccountId) -> Balance {
+		MockVotes::get()
+			.into_iter()
+			.find(|(who, _)| who == nominee)
+			.map(|(_, votes)| votes)
+			.unwrap_or_default()
+	}
+}
+
+pub fn insurance(total_insurance: Balance, is_frozen: bool, slash_count: u32) -> ValidatorInsurance<Balance> {
+	ValidatorInsurance {
+		total_insurance,
+		is_frozen,
+		slash_count,
+	}
+}
+
+parameter_types! {
+	pub InsuranceWeight: Permill = Permill::one();
+	pub const VoteWeight: Permill = Permill::from_percent(50);
+	pub const SlashPenalty: Permill = Permill::from_percent(50);
+	pub const MaxNominations: u32 = 3;
+	pub SubAccountIndexes: Vec<u16> = vec![0, 1];
+	pub ParachainId: ParaId = ParaId::from(2000);
+	pub const XcmNominateFee: Balance = 1_000;
+	pub const XcmNominateWeight: Weight = 1_000_000;
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ADMIN;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type RelaychainAccountId = AccountId;
+	type ValidatorInsurance = MockValidatorInsurance;
+	type NomineeVotes = MockNomineeVotes;
+	type InsuranceWeight = InsuranceWeight;
+	type VoteWeight = VoteWeight;
+	type SlashPenalty = SlashPenalty;
+	type MaxNominations = MaxNominations;
+	type MaxValidatorCandidates = MaxValidatorCandidates;
+	type SubAccountIndexes = SubAccountIndexes;
+	type RelayChainCallBuilder = RelayChainCallBuilder<Runtime, ParachainId>;
+	type XcmNominateFee = XcmNominateFee;
+	type XcmNominateWeight = XcmNominateWeight;
+	type GovernanceOrigin = EnsureSignedBy<Admin, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		HomaValidatorSelection: homa_validator_selection::{Pallet, Call, Storage, Event<T>},
+		PalletXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin},
+	}
+);
+
+pub struct ExtBuilder {
+	validators: Vec<(AccountId, ValidatorInsurance<Balance>)>,
+	votes: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			validators: vec![
+				(VALIDATOR_1, insurance(1_000, false, 0)),
+				(VALIDATOR_2, insurance(2_000, false, 0)),
+				(VALIDATOR_3, insurance(3_000, false, 0)),
+				(VALIDATOR_4, insurance(500, false, 0)),
+			],
+			votes: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn validators(mut self, validators: Vec<(AccountId, ValidatorInsurance<Balance>)>) -> Self {
+		self.validators = validators;
+		self
+	}
+
+	pub fn votes(mut self, votes: Vec<(AccountId, Balance)>) -> Self {
+		self.votes = votes;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		MockValidators::set(self.validators);
+		MockVotes::set(self.votes);
+		MaxValidatorCandidates::set(10);
+		MockXcmAvailable::set(true);
+		SentXcm::set(vec![]);
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
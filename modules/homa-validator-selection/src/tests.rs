@@ -0,0 +1,205 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for homa validator selection module.
+
+#![cfg(test)]
+
+use super::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use module_relaychain::{RelayChainCall, StakingCall, UtilityCall};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn score_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(HomaValidatorSelection::score(&insurance(1_000, false, 0), 0), 1_000);
+		assert_eq!(HomaValidatorSelection::score(&insurance(1_000, false, 0), 400), 1_200);
+		assert_eq!(HomaValidatorSelection::score(&insurance(1_000, false, 1), 400), 600);
+		assert_eq!(HomaValidatorSelection::score(&insurance(1_000, false, 2), 400), 300);
+		assert_eq!(HomaValidatorSelection::score(&insurance(1_000, true, 0), 400), 0);
+	});
+}
+
+#[test]
+fn select_validators_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			HomaValidatorSelection::select_validators(),
+			vec![VALIDATOR_3, VALIDATOR_2, VALIDATOR_1]
+		);
+	});
+
+	ExtBuilder::default()
+		.votes(vec![(VALIDATOR_4, 4_000), (VALIDATOR_1, 2_000)])
+		.build()
+		.execute_with(|| {
+			// VALIDATOR_4: 500 + 2_000, VALIDATOR_1: 1_000 + 1_000
+			assert_eq!(
+				HomaValidatorSelection::select_validators(),
+				vec![VALIDATOR_3, VALIDATOR_4, VALIDATOR_1]
+			);
+		});
+
+	ExtBuilder::default()
+		.validators(vec![
+			(VALIDATOR_1, insurance(1_000, false, 0)),
+			(VALIDATOR_2, insurance(2_000, false, 2)),
+			(VALIDATOR_3, insurance(3_000, true, 0)),
+			(VALIDATOR_4, insurance(500, false, 0)),
+		])
+		.build()
+		.execute_with(|| {
+			// VALIDATOR_2 is slashed twice, VALIDATOR_3 is frozen.
+			assert_eq!(
+				HomaValidatorSelection::select_validators(),
+				vec![VALIDATOR_1, VALIDATOR_2, VALIDATOR_4]
+			);
+		});
+
+	ExtBuilder::default()
+		.validators(vec![
+			(VALIDATOR_2, insurance(1_000, false, 0)),
+			(VALIDATOR_1, insurance(1_000, false, 0)),
+			(VALIDATOR_3, insurance(0, false, 0)),
+		])
+		.build()
+		.execute_with(|| {
+			// ties are broken by the validator id, validators without score are excluded.
+			assert_eq!(
+				HomaValidatorSelection::select_validators(),
+				vec![VALIDATOR_1, VALIDATOR_2]
+			);
+		});
+}
+
+#[test]
+fn select_validators_is_bounded_by_max_validator_candidates() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxValidatorCandidates::set(2);
+		assert_eq!(
+			HomaValidatorSelection::select_validators(),
+			vec![VALIDATOR_2, VALIDATOR_1]
+		);
+	});
+}
+
+#[test]
+fn construct_xcm_nominate_message_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let msg = HomaValidatorSelection::construct_xcm_nominate_message(vec![VALIDATOR_3, VALIDATOR_2]);
+		let nominate = || RelayChainCall::<Runtime>::Staking(StakingCall::Nominate(vec![VALIDATOR_3, VALIDATOR_2]));
+		let call = RelayChainCall::<Runtime>::Utility(Box::new(UtilityCall::BatchAll(vec![
+			RelayChainCall::Utility(Box::new(UtilityCall::AsDerivative(0, nominate()))),
+			RelayChainCall::Utility(Box::new(UtilityCall::AsDerivative(1, nominate()))),
+		])));
+
+		// the fee and the weight limit are paid for both sub-accounts.
+		assert_eq!(
+			msg.0.get(0),
+			Some(&WithdrawAsset(
+				MultiAsset {
+					id: Concrete(MultiLocation::here()),
+					fun: Fungibility::Fungible(2 * XcmNominateFee::get()),
+				}
+				.into()
+			))
+		);
+		assert_eq!(
+			msg.0.get(2),
+			Some(&Transact {
+				origin_type: OriginKind::SovereignAccount,
+				require_weight_at_most: 2 * XcmNominateWeight::get(),
+				call: call.encode().into(),
+			})
+		);
+	});
+}
+
+#[test]
+fn on_new_era_nominates_validators() {
+	ExtBuilder::default().build().execute_with(|| {
+		HomaValidatorSelection::on_new_era(1);
+		assert_eq!(HomaValidatorSelection::current_era(), 1);
+		assert_eq!(
+			System::block_weight().get(DispatchClass::Mandatory),
+			&<() as WeightInfo>::on_new_era(MaxValidatorCandidates::get())
+		);
+		assert_eq!(
+			HomaValidatorSelection::nominations(),
+			vec![VALIDATOR_3, VALIDATOR_2, VALIDATOR_1]
+		);
+		System::assert_last_event(mock::Event::HomaValidatorSelection(crate::Event::NominationsUpdated(
+			1,
+			vec![VALIDATOR_3, VALIDATOR_2, VALIDATOR_1],
+		)));
+		assert_eq!(
+			SentXcm::get(),
+			vec![HomaValidatorSelection::construct_xcm_nominate_message(vec![
+				VALIDATOR_3,
+				VALIDATOR_2,
+				VALIDATOR_1
+			])]
+		);
+
+		// keep the previous nominations if the XCM message fails.
+		MockValidators::set(vec![(VALIDATOR_4, insurance(500, false, 0))]);
+		MockXcmAvailable::set(false);
+		HomaValidatorSelection::on_new_era(2);
+		assert_eq!(HomaValidatorSelection::current_era(), 2);
+		assert_eq!(
+			HomaValidatorSelection::nominations(),
+			vec![VALIDATOR_3, VALIDATOR_2, VALIDATOR_1]
+		);
+		assert_eq!(SentXcm::get().len(), 1);
+
+		MockXcmAvailable::set(true);
+		HomaValidatorSelection::on_new_era(3);
+		assert_eq!(HomaValidatorSelection::nominations(), vec![VALIDATOR_4]);
+		assert_eq!(SentXcm::get().len(), 2);
+	});
+}
+
+#[test]
+fn force_nominate_works() {
+	ExtBuilder::default().validators(vec![]).build().execute_with(|| {
+		assert_noop!(HomaValidatorSelection::force_nominate(Origin::signed(ALICE)), BadOrigin);
+		assert_noop!(
+			HomaValidatorSelection::force_nominate(Origin::signed(ADMIN)),
+			Error::<Runtime>::NoEligibleValidators
+		);
+
+		MockValidators::set(vec![(VALIDATOR_1, insurance(1_000, false, 0))]);
+		MockXcmAvailable::set(false);
+		assert_noop!(
+			HomaValidatorSelection::force_nominate(Origin::signed(ADMIN)),
+			Error::<Runtime>::XcmFailed
+		);
+
+		MockXcmAvailable::set(true);
+		assert_ok!(HomaValidatorSelection::force_nominate(Origin::signed(ADMIN)));
+		assert_eq!(HomaValidatorSelection::nominations(), vec![VALIDATOR_1]);
+		System::assert_last_event(mock::Event::HomaValidatorSelection(crate::Event::NominationsUpdated(
+			0,
+			vec![VALIDATOR_1],
+		)));
+		assert_eq!(SentXcm::get().len(), 1);
+	});
+}
@@ -22,22 +22,37 @@
 //!
 //! The entry of the Honzon protocol for users, user can manipulate their CDP
 //! position to loan/payback, and can also authorize others to manage the their
-//! CDP under specific collateral type.
+//! CDP under specific collateral type. The authorization can be limited to
+//! specific operations, with an optional spending cap and expiry block.
 //!
 //! After system shutdown, some operations will be restricted.
+//!
+//! Stable currency holders can redeem stable currency for collateral at face value against
+//! the CDPs with the lowest collateral ratio, minus a dynamic redemption fee. The fee
+//! increases with the redeemed proportion of the total debit and decays over time.
+//!
+//! Users can set an auto-deleverage rule for their CDP. When the collateral ratio falls below
+//! the trigger ratio, part of the collateral is sold on DEX to repay debit until the collateral
+//! ratio is back to the target ratio. Setting a rule reserves a deposit, which is returned when
+//! the rule is removed. The rules of each collateral type are swept by the idle scheduler at most
+//! once per block, and can also be triggered by anyone.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
+use codec::FullCodec;
+use enumflags2::BitFlags;
 use frame_support::{pallet_prelude::*, traits::NamedReservableCurrency, transactional};
 use frame_system::pallet_prelude::*;
-use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier};
+use loans::Position;
+use primitives::{task::TaskResult, Amount, Balance, CurrencyId, ReserveIdentifier};
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
 use sp_runtime::{
-	traits::{StaticLookup, Zero},
-	DispatchResult,
+	traits::{CheckedDiv, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::vec::Vec;
-use support::EmergencyShutdown;
+use sp_std::{fmt::Debug, prelude::*};
+use support::{CollateralFreeze, DispatchableTask, EmergencyShutdown, IdleScheduler, Price, Rate, Ratio};
 
 mod mock;
 mod tests;
@@ -46,11 +61,98 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+#[repr(u8)]
+#[derive(Encode, Decode, Clone, Copy, BitFlags, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum AuthorizationPermission {
+	/// Deposit collateral into the CDP
+	DepositCollateral = 0b00000001,
+	/// Withdraw collateral from the CDP
+	WithdrawCollateral = 0b00000010,
+	/// Issue debit of the CDP
+	IssueDebit = 0b00000100,
+	/// Repay debit of the CDP
+	RepayDebit = 0b00001000,
+	/// Close the CDP which has debit by DEX
+	CloseByDex = 0b00010000,
+	/// Transfer the whole CDP to the authorizee
+	TransferLoan = 0b00100000,
+}
+
+#[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
+pub struct Permissions(pub BitFlags<AuthorizationPermission>);
+
+impl Permissions {
+	/// All permissions, the same as an authorization without limits
+	pub fn all() -> Self {
+		Self(BitFlags::all())
+	}
+
+	/// Return whether all the permissions of `other` are included
+	pub fn contains(&self, other: Permissions) -> bool {
+		self.0.contains(other.0)
+	}
+}
+
+impl Eq for Permissions {}
+impl Encode for Permissions {
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		self.0.bits().using_encoded(f)
+	}
+}
+impl Decode for Permissions {
+	fn decode<I: codec::Input>(input: &mut I) -> sp_std::result::Result<Self, codec::Error> {
+		let field = u8::decode(input)?;
+		Ok(Self(
+			<BitFlags<AuthorizationPermission>>::from_bits(field as u8).map_err(|_| "invalid value")?,
+		))
+	}
+}
+
+impl TypeInfo for Permissions {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::builder()
+			.path(Path::new("BitFlags", module_path!()))
+			.type_params(vec![TypeParameter::new(
+				"T",
+				Some(meta_type::<AuthorizationPermission>()),
+			)])
+			.composite(Fields::unnamed().field(|f| f.ty::<u8>().type_name("AuthorizationPermission")))
+	}
+}
+
+/// The limits of an authorization. An authorization without limits has all
+/// permissions, no spending cap and never expires.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AuthorizationLimit<BlockNumber> {
+	/// The operations the authorizee is allowed to do
+	pub permissions: Permissions,
+	/// The remaining value in stable currency of collateral and debit the
+	/// authorizee can move, `None` means no cap
+	pub spending_cap: Option<Balance>,
+	/// The authorization is invalid since this block, `None` means never
+	/// expire
+	pub expiry: Option<BlockNumber>,
+}
+
+/// The rule to deleverage the CDP automatically.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AutoDeleverageRule {
+	/// Deleverage the CDP when its collateral ratio falls below this ratio
+	pub trigger_ratio: Ratio,
+	/// The collateral ratio of the CDP after deleverage
+	pub target_ratio: Ratio,
+	/// The reserved deposit for the rule
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Honzon;
+	pub const AUTO_DELEVERAGE_RESERVE_ID: ReserveIdentifier = ReserveIdentifier::HonzonAutoDeleverage;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + cdp_engine::Config {
@@ -67,6 +169,29 @@ pub mod module {
 		#[pallet::constant]
 		type DepositPerAuthorization: Get<Balance>;
 
+		/// The minimum fee rate of redemption.
+		#[pallet::constant]
+		type RedemptionFeeFloor: Get<Rate>;
+
+		/// The decay factor of redemption base rate per block.
+		#[pallet::constant]
+		type RedemptionBaseRateDecay: Get<Rate>;
+
+		/// The maximum number of CDPs with the lowest collateral ratio can be redeemed in one
+		/// redemption.
+		#[pallet::constant]
+		type MaxRedemptionCandidates: Get<u32>;
+
+		/// Reserved amount per auto-deleverage rule.
+		#[pallet::constant]
+		type DepositPerAutoDeleverageRule: Get<Balance>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask + FullCodec + Debug + Clone + PartialEq + TypeInfo + From<HonzonTask<Self>>;
+
+		/// Idle scheduler to check the auto-deleverage rules.
+		type IdleScheduler: IdleScheduler<Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -81,6 +206,28 @@ pub mod module {
 		AuthorizationNotExists,
 		// Have authorized already
 		AlreadyAuthorized,
+		// No CDP can be redeemed
+		NoRedeemablePosition,
+		// The CDPs are still being indexed for redemption
+		PositionsNotIndexed,
+		// The redemption fee rate exceeds the limit
+		RedemptionFeeTooHigh,
+		// Feed price is invalid
+		InvalidFeedPrice,
+		// The authorization has expired
+		AuthorizationExpired,
+		// The spending cap of the authorization is not enough
+		ExceedSpendingCap,
+		// The auto-deleverage rule not exists
+		NoAutoDeleverageRule,
+		// The trigger ratio must be above the liquidation ratio and below the target ratio
+		InvalidAutoDeleverageRatio,
+		// The collateral ratio is not below the trigger ratio
+		AutoDeleverageNotTriggered,
+		// The collateral is frozen
+		CollateralFrozen,
+		// The CDP has neither collateral nor debit
+		PositionNotOpen,
 	}
 
 	#[pallet::event]
@@ -94,6 +241,27 @@ pub mod module {
 		UnAuthorization(T::AccountId, T::AccountId, CurrencyId),
 		/// Cancel all authorization. \[authorizer\]
 		UnAuthorizationAll(T::AccountId),
+		/// Redeem stable currency for collateral.
+		/// \[redeemer, collateral_type, stable_amount, collateral_amount, fee_amount\]
+		Redemption(T::AccountId, CurrencyId, Balance, Balance, Balance),
+		/// The limits of authorization updated.
+		/// \[authorizer, authorizee, collateral_type, permissions, spending_cap, expiry\]
+		AuthorizationLimitUpdated(
+			T::AccountId,
+			T::AccountId,
+			CurrencyId,
+			Permissions,
+			Option<Balance>,
+			Option<T::BlockNumber>,
+		),
+		/// The auto-deleverage rule of CDP updated.
+		/// \[owner, collateral_type, trigger_ratio, target_ratio\]
+		AutoDeleverageRuleUpdated(T::AccountId, CurrencyId, Ratio, Ratio),
+		/// The auto-deleverage rule of CDP removed. \[owner, collateral_type\]
+		AutoDeleverageRuleRemoved(T::AccountId, CurrencyId),
+		/// The CDP has been deleveraged by its auto-deleverage rule.
+		/// \[owner, collateral_type\]
+		AutoDeleveraged(T::AccountId, CurrencyId),
 	}
 
 	/// The authorization relationship map from
@@ -112,11 +280,91 @@ pub mod module {
 		OptionQuery,
 	>;
 
+	/// The limits of the authorization, the authorization without limits has
+	/// all permissions.
+	///
+	/// AuthorizationLimits: double_map AccountId, (CurrencyId, T::AccountId) =>
+	/// Option<AuthorizationLimit>
+	#[pallet::storage]
+	#[pallet::getter(fn authorization_limits)]
+	pub type AuthorizationLimits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(CurrencyId, T::AccountId),
+		AuthorizationLimit<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The redemption base rate of specific collateral type, which decays over time.
+	///
+	/// RedemptionBaseRate: map CurrencyId => Rate
+	#[pallet::storage]
+	#[pallet::getter(fn redemption_base_rate)]
+	pub type RedemptionBaseRate<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Rate, ValueQuery>;
+
+	/// The block number of the last redemption of specific collateral type.
+	///
+	/// LastRedemptionBlock: map CurrencyId => BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn last_redemption_block)]
+	pub type LastRedemptionBlock<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, ValueQuery>;
+
+	/// The auto-deleverage rules of CDPs.
+	///
+	/// AutoDeleverageRules: double_map CurrencyId, AccountId => Option<AutoDeleverageRule>
+	#[pallet::storage]
+	#[pallet::getter(fn auto_deleverage_rules)]
+	pub type AutoDeleverageRules<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, AutoDeleverageRule, OptionQuery>;
+
+	/// The flag of whether the task sweeping the auto-deleverage rules of collateral
+	/// type is in the idle scheduler.
+	///
+	/// AutoDeleverageTaskScheduled: map CurrencyId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn auto_deleverage_task_scheduled)]
+	pub type AutoDeleverageTaskScheduled<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, bool, ValueQuery>;
+
+	/// The last key of the auto-deleverage rules iterated by the sweeping task of
+	/// collateral types
+	///
+	/// LastAutoDeleverageKeyIterated: map CurrencyId => Option<Vec<u8>>
+	#[pallet::storage]
+	pub type LastAutoDeleverageKeyIterated<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Vec<u8>, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Schedule the task to sweep the auto-deleverage rules of each collateral
+		/// type, unless the last sweep has not finished yet.
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 0;
+			if !T::EmergencyShutdown::is_shutdown() {
+				for currency_id in <T as cdp_engine::Config>::CollateralCurrencyIds::get() {
+					reads = reads.saturating_add(1);
+					if Self::auto_deleverage_task_scheduled(currency_id) {
+						continue;
+					}
+
+					reads = reads.saturating_add(1);
+					if AutoDeleverageRules::<T>::iter_prefix(currency_id).next().is_none() {
+						continue;
+					}
+
+					writes = writes.saturating_add(2);
+					if T::IdleScheduler::schedule(HonzonTask::<T>::AutoDeleverage { currency_id }.into()).is_ok() {
+						AutoDeleverageTaskScheduled::<T>::insert(currency_id, true);
+					}
+				}
+			}
+			<T as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -138,12 +386,7 @@ pub mod module {
 			debit_adjustment: Amount,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			// not allowed to adjust the debit after system shutdown
-			if !debit_adjustment.is_zero() {
-				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			}
-			<cdp_engine::Pallet<T>>::adjust_position(&who, currency_id, collateral_adjustment, debit_adjustment)?;
+			Self::do_adjust_loan(&who, &who, currency_id, collateral_adjustment, debit_adjustment)?;
 			Ok(())
 		}
 
@@ -168,13 +411,7 @@ pub mod module {
 			maybe_path: Option<Vec<CurrencyId>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			<cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(
-				who,
-				currency_id,
-				max_collateral_amount,
-				maybe_path.as_deref(),
-			)?;
+			Self::do_close_loan_has_debit_by_dex(&who, who.clone(), currency_id, max_collateral_amount, maybe_path)?;
 			Ok(())
 		}
 
@@ -184,7 +421,10 @@ pub mod module {
 		///
 		/// - `currency_id`: collateral currency id.
 		/// - `from`: authorizer account
-		#[pallet::weight(<T as Config>::WeightInfo::transfer_loan_from())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::transfer_loan_from()
+				.saturating_add(<T as Config>::WeightInfo::check_authorization())
+		)]
 		#[transactional]
 		pub fn transfer_loan_from(
 			origin: OriginFor<T>,
@@ -194,7 +434,15 @@ pub mod module {
 			let to = ensure_signed(origin)?;
 			let from = T::Lookup::lookup(from)?;
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			Self::check_authorization(&from, &to, currency_id)?;
+			let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &from);
+			Self::check_authorization(
+				&from,
+				&to,
+				currency_id,
+				Permissions(AuthorizationPermission::TransferLoan.into()),
+				collateral,
+				debit,
+			)?;
 			<loans::Pallet<T>>::transfer_loan(&from, &to, currency_id)?;
 			Ok(())
 		}
@@ -234,7 +482,11 @@ pub mod module {
 		///
 		/// - `currency_id`: collateral currency id.
 		/// - `to`: authorizee account
-		#[pallet::weight(<T as Config>::WeightInfo::unauthorize())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::unauthorize()
+				// remove the limits of the authorization
+				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1))
+		)]
 		#[transactional]
 		pub fn unauthorize(
 			origin: OriginFor<T>,
@@ -245,31 +497,689 @@ pub mod module {
 			let to = T::Lookup::lookup(to)?;
 			let reserved =
 				Authorization::<T>::take(&from, (currency_id, &to)).ok_or(Error::<T>::AuthorizationNotExists)?;
+			AuthorizationLimits::<T>::remove(&from, (currency_id, &to));
 			<T as Config>::Currency::unreserve_named(&RESERVE_ID, &from, reserved);
 			Self::deposit_event(Event::UnAuthorization(from, to, currency_id));
 			Ok(())
 		}
 
 		/// Cancel all authorization of caller
-		#[pallet::weight(<T as Config>::WeightInfo::unauthorize_all(<T as cdp_engine::Config>::CollateralCurrencyIds::get().len() as u32))]
+		#[pallet::weight({
+			let c = <T as cdp_engine::Config>::CollateralCurrencyIds::get().len() as u32;
+			<T as Config>::WeightInfo::unauthorize_all(c)
+				// remove the limits of the authorizations
+				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(c as Weight))
+		})]
 		#[transactional]
 		pub fn unauthorize_all(origin: OriginFor<T>) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			Authorization::<T>::remove_prefix(&from, None);
+			AuthorizationLimits::<T>::remove_prefix(&from, None);
 			<T as Config>::Currency::unreserve_all_named(&RESERVE_ID, &from);
 			Self::deposit_event(Event::UnAuthorizationAll(from));
 			Ok(())
 		}
+
+		/// Adjust the basket loan of caller, which is backed by multiple collateral types
+		/// at the same time.
+		///
+		/// - `collateral_adjustments`: signed amounts of each collateral currency, positive means
+		///   to deposit collateral currency into the basket, negative means withdraw collateral
+		///   currency from the basket.
+		/// - `debit_adjustment`: signed amount, positive means to issue some amount of stablecoin
+		///   to caller according to the debit adjustment, negative means caller will payback some
+		///   amount of stablecoin to the basket according to to the debit adjustment.
+		#[pallet::weight(<T as Config>::WeightInfo::adjust_basket_loan(collateral_adjustments.len() as u32))]
+		#[transactional]
+		pub fn adjust_basket_loan(
+			origin: OriginFor<T>,
+			collateral_adjustments: Vec<(CurrencyId, Amount)>,
+			debit_adjustment: Amount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// not allowed to adjust the debit after system shutdown
+			if !debit_adjustment.is_zero() {
+				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			}
+			<cdp_engine::Pallet<T>>::adjust_basket_position(&who, &collateral_adjustments, debit_adjustment)?;
+			Ok(())
+		}
+
+		/// Redeem stable currency for collateral at face value against the CDPs of
+		/// `currency_id` with the lowest collateral ratio, at most `MaxRedemptionCandidates`
+		/// of them. Unsafe CDPs are skipped. The redemption fee is kept by CDP treasury.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `stable_amount`: the max stable currency amount to redeem.
+		/// - `max_fee_rate`: the max acceptable redemption fee rate.
+		#[pallet::weight(<T as Config>::WeightInfo::redeem(T::MaxRedemptionCandidates::get()))]
+		#[transactional]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] stable_amount: Balance,
+			max_fee_rate: Rate,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				!<T as cdp_engine::Config>::CollateralFreeze::is_frozen(currency_id),
+				Error::<T>::CollateralFrozen
+			);
+			Self::do_redeem(&who, currency_id, stable_amount, max_fee_rate)?;
+			Ok(())
+		}
+
+		/// Authorize `to` to manipulate the loan under `currency_id` with limits, or update the
+		/// limits if `to` has been authorized.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `to`: authorizee account
+		/// - `permissions`: the operations `to` is allowed to do
+		/// - `spending_cap`: the max value in stable currency of collateral and debit `to` can
+		///   move, `None` means no cap
+		/// - `expiry`: the authorization is invalid since this block, `None` means never expire
+		#[pallet::weight(<T as Config>::WeightInfo::authorize_with_limits())]
+		#[transactional]
+		pub fn authorize_with_limits(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			to: <T::Lookup as StaticLookup>::Source,
+			permissions: Permissions,
+			spending_cap: Option<Balance>,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			if from == to {
+				return Ok(());
+			}
+
+			Authorization::<T>::try_mutate(&from, (currency_id, &to), |maybe_reserved| -> DispatchResult {
+				if maybe_reserved.is_none() {
+					let reserve_amount = T::DepositPerAuthorization::get();
+					<T as Config>::Currency::reserve_named(&RESERVE_ID, &from, reserve_amount)?;
+					*maybe_reserved = Some(reserve_amount);
+					Self::deposit_event(Event::Authorization(from.clone(), to.clone(), currency_id));
+				}
+				Ok(())
+			})?;
+			AuthorizationLimits::<T>::insert(
+				&from,
+				(currency_id, &to),
+				AuthorizationLimit {
+					permissions,
+					spending_cap,
+					expiry,
+				},
+			);
+			Self::deposit_event(Event::AuthorizationLimitUpdated(
+				from,
+				to,
+				currency_id,
+				permissions,
+				spending_cap,
+				expiry,
+			));
+			Ok(())
+		}
+
+		/// Adjust the loans of `owner` under `currency_id` by specific
+		/// `collateral_adjustment` and `debit_adjustment`, caller must have the
+		/// authorization of `owner` with the permissions of the adjustments.
+		/// The collateral and stable currency are transferred from/to `owner`.
+		///
+		/// - `owner`: authorizer account
+		/// - `currency_id`: collateral currency id.
+		/// - `collateral_adjustment`: signed amount, positive means to deposit collateral currency
+		///   into CDP, negative means withdraw collateral currency from CDP.
+		/// - `debit_adjustment`: signed amount, positive means to issue some amount of stablecoin
+		///   to owner according to the debit adjustment, negative means owner will payback some
+		///   amount of stablecoin to CDP according to to the debit adjustment.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::adjust_loan()
+				.saturating_add(<T as Config>::WeightInfo::check_authorization())
+		)]
+		#[transactional]
+		pub fn adjust_loan_on_behalf(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_adjust_loan(&who, &owner, currency_id, collateral_adjustment, debit_adjustment)?;
+			Ok(())
+		}
+
+		/// Close the CDP of `owner` which has debit but still in safe by use collateral to
+		/// swap stable token on DEX for clearing debit, caller must have the authorization of
+		/// `owner` with the permission to close by DEX. The remain collateral is refunded to
+		/// `owner`.
+		///
+		/// - `owner`: authorizer account
+		/// - `currency_id`: collateral currency id.
+		/// - `max_collateral_amount`: the max collateral amount which is used to swap enough
+		/// 	stable token to clear debit.
+		/// - `maybe_path`: the custom swap path.
+		#[pallet::weight(
+			match maybe_path {
+				Some(path) => <T as Config>::WeightInfo::close_loan_has_debit_by_dex(path.len() as u32),
+				None => <T as Config>::WeightInfo::close_loan_has_debit_by_dex_no_path(),
+			}.saturating_add(<T as Config>::WeightInfo::check_authorization())
+		)]
+		#[transactional]
+		pub fn close_loan_has_debit_by_dex_on_behalf(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			#[pallet::compact] max_collateral_amount: Balance,
+			maybe_path: Option<Vec<CurrencyId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_close_loan_has_debit_by_dex(&who, owner, currency_id, max_collateral_amount, maybe_path)?;
+			Ok(())
+		}
+
+		/// Set the auto-deleverage rule of caller's CDP. When the collateral ratio falls below
+		/// `trigger_ratio`, part of the collateral is sold on DEX to repay debit until the
+		/// collateral ratio is back to `target_ratio`. The CDP must be open, and a deposit is
+		/// reserved for the new rule.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `trigger_ratio`: must be above the liquidation ratio.
+		/// - `target_ratio`: must be above `trigger_ratio`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_deleverage())]
+		#[transactional]
+		pub fn set_auto_deleverage(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+			target_ratio: Ratio,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				<T as cdp_engine::Config>::CollateralCurrencyIds::get().contains(&currency_id),
+				cdp_engine::Error::<T>::InvalidCollateralType,
+			);
+			ensure!(
+				trigger_ratio > <cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id)
+					&& target_ratio > trigger_ratio,
+				Error::<T>::InvalidAutoDeleverageRatio
+			);
+			let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &who);
+			ensure!(!collateral.is_zero() || !debit.is_zero(), Error::<T>::PositionNotOpen);
+
+			// reserve the deposit for the new rule only
+			let deposit = match Self::auto_deleverage_rules(currency_id, &who) {
+				Some(rule) => rule.deposit,
+				None => {
+					let deposit = T::DepositPerAutoDeleverageRule::get();
+					<T as Config>::Currency::reserve_named(&AUTO_DELEVERAGE_RESERVE_ID, &who, deposit)?;
+					deposit
+				}
+			};
+			AutoDeleverageRules::<T>::insert(
+				currency_id,
+				&who,
+				AutoDeleverageRule {
+					trigger_ratio,
+					target_ratio,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::AutoDeleverageRuleUpdated(
+				who,
+				currency_id,
+				trigger_ratio,
+				target_ratio,
+			));
+			Ok(())
+		}
+
+		/// Remove the auto-deleverage rule of caller's CDP and return its deposit.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_auto_deleverage())]
+		#[transactional]
+		pub fn remove_auto_deleverage(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_auto_deleverage(who, currency_id)
+		}
+
+		/// Deleverage the CDP of `owner` by its auto-deleverage rule if the collateral ratio
+		/// falls below the trigger ratio. Anyone can trigger it.
+		///
+		/// - `owner`: CDP owner.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::trigger_auto_deleverage())]
+		#[transactional]
+		pub fn trigger_auto_deleverage(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_auto_deleverage(owner, currency_id)
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// Check if `from` has the authorization of `to` under `currency_id`
-	fn check_authorization(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+	/// The redemption base rate of `currency_id` after decay.
+	pub fn get_redemption_base_rate(currency_id: CurrencyId) -> Rate {
+		let elapsed: u32 = <frame_system::Pallet<T>>::block_number()
+			.saturating_sub(Self::last_redemption_block(currency_id))
+			.unique_saturated_into();
+		T::RedemptionBaseRateDecay::get()
+			.saturating_pow(elapsed as usize)
+			.saturating_mul(Self::redemption_base_rate(currency_id))
+	}
+
+	/// The redemption fee rate of `currency_id` with the `base_rate`.
+	pub fn get_redemption_fee_rate(base_rate: Rate) -> Rate {
+		T::RedemptionFeeFloor::get().saturating_add(base_rate).min(Rate::one())
+	}
+
+	fn do_redeem(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		stable_amount: Balance,
+		max_fee_rate: Rate,
+	) -> DispatchResult {
+		ensure!(
+			<loans::Pallet<T>>::sorted_positions_indexed(),
+			Error::<T>::PositionsNotIndexed
+		);
+
+		let stable_currency_id = <T as cdp_engine::Config>::GetStableCurrencyId::get();
+		let price = <cdp_engine::Pallet<T>>::get_relative_price(currency_id, stable_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let redemption_price: Price = price.reciprocal().ok_or(Error::<T>::InvalidFeedPrice)?;
+
+		let total_debit_value = <cdp_engine::Pallet<T>>::get_debit_value(
+			currency_id,
+			<loans::Pallet<T>>::total_positions(currency_id).debit,
+		);
+		ensure!(!total_debit_value.is_zero(), Error::<T>::NoRedeemablePosition);
+
+		let liquidation_ratio = <cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id);
+		let minimum_debit_value = <T as cdp_engine::Config>::MinimumDebitValue::get();
+		let debit_exchange_rate = <cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id);
+		let mut remain_stable_amount = stable_amount;
+		let mut total_redeemed_value: Balance = Zero::zero();
+		let mut redemptions: Vec<(T::AccountId, Balance, Balance)> = vec![];
+
+		// the candidates are the safe CDPs with the lowest collateral ratio, the sorted index
+		// is sought from the ratio of collateral to debit at the liquidation ratio.
+		let min_ratio = liquidation_ratio
+			.saturating_mul(debit_exchange_rate)
+			.checked_div(&price)
+			.unwrap_or_else(Ratio::max_value);
+		for owner in
+			<loans::Pallet<T>>::sorted_positions_from(currency_id, min_ratio, T::MaxRedemptionCandidates::get())
+		{
+			if remain_stable_amount.is_zero() {
+				break;
+			}
+
+			let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &owner);
+			// unsafe CDP should be liquidated rather than redeemed, the ratio of the index is
+			// rounded down
+			if <cdp_engine::Pallet<T>>::calculate_collateral_ratio(currency_id, collateral, debit, price)
+				< liquidation_ratio
+			{
+				continue;
+			}
+
+			let debit_value = <cdp_engine::Pallet<T>>::get_debit_value(currency_id, debit);
+			let mut redeem_value = remain_stable_amount.min(debit_value);
+
+			// the remaining debit value must be zero or above the minimum debit value
+			let remain_debit_value = debit_value.saturating_sub(redeem_value);
+			if !remain_debit_value.is_zero() && remain_debit_value < minimum_debit_value {
+				redeem_value = debit_value.saturating_sub(minimum_debit_value);
+			}
+
+			let debit_decrease = if redeem_value == debit_value {
+				debit
+			} else {
+				debit_exchange_rate
+					.reciprocal()
+					.unwrap_or_else(Rate::one)
+					.saturating_mul_int(redeem_value)
+					.min(debit)
+			};
+			if debit_decrease.is_zero() {
+				continue;
+			}
+			let redeem_value = <cdp_engine::Pallet<T>>::get_debit_value(currency_id, debit_decrease);
+			let collateral_amount = redemption_price.saturating_mul_int(redeem_value).min(collateral);
+
+			remain_stable_amount = remain_stable_amount.saturating_sub(redeem_value);
+			total_redeemed_value = total_redeemed_value.saturating_add(redeem_value);
+			redemptions.push((owner, collateral_amount, debit_decrease));
+		}
+		ensure!(!total_redeemed_value.is_zero(), Error::<T>::NoRedeemablePosition);
+
+		// the base rate increases by half of the redeemed proportion of the total debit
+		let redeemed_proportion = Ratio::checked_from_rational(total_redeemed_value, total_debit_value)
+			.unwrap_or_else(Ratio::max_value)
+			.min(Ratio::one());
+		let base_rate = Self::get_redemption_base_rate(currency_id)
+			.saturating_add(redeemed_proportion / Ratio::saturating_from_integer(2))
+			.min(Rate::one());
+		let fee_rate = Self::get_redemption_fee_rate(base_rate);
+		ensure!(fee_rate <= max_fee_rate, Error::<T>::RedemptionFeeTooHigh);
+
+		// the redeemer repays the debit of the CDPs, and receives the collateral except the
+		// fee kept by cdp treasury
+		let mut total_collateral: Balance = Zero::zero();
+		let mut total_fee: Balance = Zero::zero();
+		for (owner, collateral_amount, debit_decrease) in redemptions {
+			let fee_amount = fee_rate.saturating_mul_int(collateral_amount);
+			<loans::Pallet<T>>::redeem_position(
+				&owner,
+				who,
+				currency_id,
+				collateral_amount,
+				fee_amount,
+				debit_decrease,
+			)?;
+			total_collateral = total_collateral.saturating_add(collateral_amount);
+			total_fee = total_fee.saturating_add(fee_amount);
+		}
+
+		RedemptionBaseRate::<T>::insert(currency_id, base_rate);
+		LastRedemptionBlock::<T>::insert(currency_id, <frame_system::Pallet<T>>::block_number());
+
+		Self::deposit_event(Event::Redemption(
+			who.clone(),
+			currency_id,
+			total_redeemed_value,
+			total_collateral.saturating_sub(total_fee),
+			total_fee,
+		));
+		Ok(())
+	}
+
+	/// Adjust the loans of `owner` by `who`, the limits of the authorization
+	/// are enforced when `who` acts on behalf of `owner`.
+	fn do_adjust_loan(
+		who: &T::AccountId,
+		owner: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		// not allowed to adjust the debit after system shutdown
+		if !debit_adjustment.is_zero() {
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		}
+
+		let mut permissions: BitFlags<AuthorizationPermission> = BitFlags::empty();
+		if collateral_adjustment.is_positive() {
+			permissions |= AuthorizationPermission::DepositCollateral;
+		} else if collateral_adjustment.is_negative() {
+			permissions |= AuthorizationPermission::WithdrawCollateral;
+		}
+		if debit_adjustment.is_positive() {
+			permissions |= AuthorizationPermission::IssueDebit;
+		} else if debit_adjustment.is_negative() {
+			permissions |= AuthorizationPermission::RepayDebit;
+		}
+		Self::check_authorization(
+			owner,
+			who,
+			currency_id,
+			Permissions(permissions),
+			collateral_adjustment.unsigned_abs(),
+			debit_adjustment.unsigned_abs(),
+		)?;
+
+		<cdp_engine::Pallet<T>>::adjust_position(owner, currency_id, collateral_adjustment, debit_adjustment)
+	}
+
+	/// Close the CDP of `owner` by DEX by `who`, the limits of the
+	/// authorization are enforced when `who` acts on behalf of `owner`.
+	fn do_close_loan_has_debit_by_dex(
+		who: &T::AccountId,
+		owner: T::AccountId,
+		currency_id: CurrencyId,
+		max_collateral_amount: Balance,
+		maybe_path: Option<Vec<CurrencyId>>,
+	) -> DispatchResult {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+		if who != &owner {
+			// closing the CDP moves all of its collateral and debit
+			let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &owner);
+			Self::check_authorization(
+				&owner,
+				who,
+				currency_id,
+				Permissions(AuthorizationPermission::CloseByDex.into()),
+				collateral,
+				debit,
+			)?;
+		}
+
+		<cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(
+			owner,
+			currency_id,
+			max_collateral_amount,
+			maybe_path.as_deref(),
+		)
+	}
+
+	/// Check if `from` has the authorization of `to` under `currency_id` with
+	/// `permissions`, and consume the spending cap by the value of the
+	/// `collateral` and `debit` to move.
+	pub fn check_authorization(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		currency_id: CurrencyId,
+		permissions: Permissions,
+		collateral: Balance,
+		debit: Balance,
+	) -> DispatchResult {
+		if from == to {
+			return Ok(());
+		}
 		ensure!(
-			from == to || Authorization::<T>::contains_key(from, (currency_id, to)),
+			Authorization::<T>::contains_key(from, (currency_id, to)),
 			Error::<T>::NoPermission
 		);
+
+		AuthorizationLimits::<T>::try_mutate_exists(from, (currency_id, to), |maybe_limit| -> DispatchResult {
+			// the authorization without limits has all permissions
+			if let Some(limit) = maybe_limit {
+				ensure!(limit.permissions.contains(permissions), Error::<T>::NoPermission);
+				if let Some(expiry) = limit.expiry {
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() < expiry,
+						Error::<T>::AuthorizationExpired
+					);
+				}
+				if let Some(spending_cap) = limit.spending_cap.as_mut() {
+					let value = Self::get_spending_value(currency_id, collateral, debit)?;
+					*spending_cap = spending_cap.checked_sub(value).ok_or(Error::<T>::ExceedSpendingCap)?;
+				}
+			}
+			Ok(())
+		})
+	}
+
+	/// The value in stable currency of `collateral` and `debit` under
+	/// `currency_id`.
+	fn get_spending_value(
+		currency_id: CurrencyId,
+		collateral: Balance,
+		debit: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let collateral_value = if collateral.is_zero() {
+			Zero::zero()
+		} else {
+			let stable_currency_id = <T as cdp_engine::Config>::GetStableCurrencyId::get();
+			<cdp_engine::Pallet<T>>::get_relative_price(currency_id, stable_currency_id)
+				.ok_or(Error::<T>::InvalidFeedPrice)?
+				.saturating_mul_int(collateral)
+		};
+		let debit_value = <cdp_engine::Pallet<T>>::get_debit_value(currency_id, debit);
+		Ok(collateral_value.saturating_add(debit_value))
+	}
+
+	/// Remove the auto-deleverage rule of `who` and return its deposit.
+	fn do_remove_auto_deleverage(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		let rule = AutoDeleverageRules::<T>::take(currency_id, &who).ok_or(Error::<T>::NoAutoDeleverageRule)?;
+		<T as Config>::Currency::unreserve_named(&AUTO_DELEVERAGE_RESERVE_ID, &who, rule.deposit);
+		Self::deposit_event(Event::AutoDeleverageRuleRemoved(who, currency_id));
+		Ok(())
+	}
+
+	/// Deleverage the CDP of `who` by its auto-deleverage rule if the collateral
+	/// ratio falls below the trigger ratio.
+	#[transactional]
+	pub fn do_auto_deleverage(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		ensure!(
+			!<T as cdp_engine::Config>::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
+		let rule = Self::auto_deleverage_rules(currency_id, &who).ok_or(Error::<T>::NoAutoDeleverageRule)?;
+
+		let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &who);
+		ensure!(!debit.is_zero(), Error::<T>::AutoDeleverageNotTriggered);
+		let stable_currency_id = <T as cdp_engine::Config>::GetStableCurrencyId::get();
+		let price = <cdp_engine::Pallet<T>>::get_relative_price(currency_id, stable_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let collateral_ratio =
+			<cdp_engine::Pallet<T>>::calculate_collateral_ratio(currency_id, collateral, debit, price);
+		ensure!(
+			collateral_ratio < rule.trigger_ratio,
+			Error::<T>::AutoDeleverageNotTriggered
+		);
+
+		<cdp_engine::Pallet<T>>::deleverage_cdp_by_dex(who.clone(), currency_id, rule.target_ratio)?;
+		Self::deposit_event(Event::AutoDeleveraged(who, currency_id));
 		Ok(())
 	}
+
+	/// Sweep the auto-deleverage rules of `currency_id` from the last iterated key
+	/// within `weight`, deleverage the triggered CDPs and remove the rules of closed
+	/// CDPs. Returns the used weight and whether all rules have been swept.
+	pub fn sweep_auto_deleverage_rules(currency_id: CurrencyId, weight: Weight) -> (Weight, bool) {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let trigger_weight = <T as Config>::WeightInfo::trigger_auto_deleverage();
+		let remove_weight = <T as Config>::WeightInfo::remove_auto_deleverage();
+		// the rule and the position of the CDP
+		let iterate_weight = db_weight.reads(2);
+		// the shutdown flag and the last iterated key
+		let mut used_weight: Weight = db_weight.reads(2);
+
+		let mut finished_iteration = true;
+		if !T::EmergencyShutdown::is_shutdown() {
+			// If the last key is Some(value) continue iterating from that point in storage
+			// otherwise start iterating from the beginning of <AutoDeleverageRules<T>>
+			let mut iterator = match LastAutoDeleverageKeyIterated::<T>::get(currency_id) {
+				Some(key) => AutoDeleverageRules::<T>::iter_prefix_from(currency_id, key),
+				None => AutoDeleverageRules::<T>::iter_prefix(currency_id),
+			};
+
+			loop {
+				if used_weight
+					.saturating_add(iterate_weight)
+					.saturating_add(trigger_weight.max(remove_weight))
+					> weight
+				{
+					finished_iteration = false;
+					break;
+				}
+
+				match iterator.next() {
+					Some((who, _)) => {
+						used_weight = used_weight.saturating_add(iterate_weight);
+						let Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, &who);
+						if collateral.is_zero() && debit.is_zero() {
+							// the CDP has been closed, remove the rule and return the deposit.
+							used_weight = used_weight.saturating_add(remove_weight);
+							let _ = Self::do_remove_auto_deleverage(who, currency_id);
+							continue;
+						}
+
+						match Self::do_auto_deleverage(who, currency_id) {
+							// only the freeze flag, rule, position, price and debit exchange rate
+							// are read.
+							Err(e)
+								if e == Error::<T>::AutoDeleverageNotTriggered.into()
+									|| e == Error::<T>::CollateralFrozen.into() =>
+							{
+								used_weight = used_weight.saturating_add(db_weight.reads(6));
+							}
+							_ => {
+								used_weight = used_weight.saturating_add(trigger_weight);
+							}
+						}
+					}
+					None => break,
+				}
+			}
+
+			if !finished_iteration {
+				LastAutoDeleverageKeyIterated::<T>::insert(currency_id, iterator.last_raw_key());
+			}
+		}
+
+		// the task is rescheduled by `on_initialize` in the next block after finished
+		if finished_iteration {
+			LastAutoDeleverageKeyIterated::<T>::remove(currency_id);
+			AutoDeleverageTaskScheduled::<T>::remove(currency_id);
+		}
+
+		(used_weight.saturating_add(db_weight.writes(2)), finished_iteration)
+	}
+}
+
+/// The tasks of honzon dispatched by the idle scheduler.
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum HonzonTask<T: Config> {
+	/// Sweep the auto-deleverage rules of the collateral type, and deleverage the
+	/// triggered CDPs. The task is finished once all rules have been swept.
+	AutoDeleverage { currency_id: CurrencyId },
+	#[doc(hidden)]
+	#[codec(skip)]
+	__Ignore(PhantomData<T>),
+}
+
+impl<T: Config> DispatchableTask for HonzonTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			HonzonTask::AutoDeleverage { currency_id } => {
+				let (used_weight, finished) = Pallet::<T>::sweep_auto_deleverage_rules(currency_id, weight);
+				TaskResult {
+					result: Ok(()),
+					used_weight,
+					finished,
+				}
+			}
+			HonzonTask::__Ignore(_) => TaskResult {
+				result: Ok(()),
+				used_weight: 0,
+				finished: true,
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<HonzonTask<T>> for () {
+	fn from(_task: HonzonTask<T>) -> Self {
+		unimplemented!()
+	}
 }
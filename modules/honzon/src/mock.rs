@@ -29,14 +29,14 @@ use frame_support::{
 use frame_system::{offchain::SendTransactionTypes, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
 use primitives::{Balance, Moment, ReserveIdentifier, TokenSymbol};
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::{Header, TestXt},
-	traits::{AccountIdConversion, IdentityLookup, One as OneT},
+	traits::{AccountIdConversion, Identity, IdentityLookup, One as OneT},
 	FixedPointNumber,
 };
 use sp_std::cell::RefCell;
-use support::{AuctionManager, ExchangeRate, Price, PriceProvider, Rate, Ratio};
+use support::{AddressMapping, AuctionManager, ExchangeRate, Price, PriceProvider, Rate, Ratio};
 
 mod honzon {
 	pub use super::super::*;
@@ -172,6 +172,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -183,16 +200,29 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static FROZEN: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_freeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().push(currency_id))
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
@@ -200,6 +230,13 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockCollateralFreeze;
+impl CollateralFreeze<CurrencyId> for MockCollateralFreeze {
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		FROZEN.with(|v| v.borrow().contains(&currency_id))
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -208,6 +245,8 @@ parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const MaxAuctionsCount: u32 = 10_000;
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
+	pub const LiquidationContractGasLimit: u64 = 1_000_000;
 	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account();
 }
 
@@ -247,9 +286,34 @@ parameter_types! {
 	];
 }
 
+pub struct MockAddressMapping;
+impl AddressMapping<AccountId> for MockAddressMapping {
+	fn get_account_id(evm: &H160) -> AccountId {
+		evm.to_low_u64_be() as AccountId
+	}
+
+	fn get_evm_address(account_id: &AccountId) -> Option<H160> {
+		Some(H160::from_low_u64_be(*account_id as u64))
+	}
+
+	fn get_or_create_evm_address(account_id: &AccountId) -> H160 {
+		H160::from_low_u64_be(*account_id as u64)
+	}
+
+	fn get_default_evm_address(account_id: &AccountId) -> H160 {
+		H160::from_low_u64_be(*account_id as u64)
+	}
+
+	fn is_linked(account_id: &AccountId, evm: &H160) -> bool {
+		Self::get_account_id(evm) == *account_id
+	}
+}
+
 impl cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type FallbackPriceSource = ();
+	type StablePriceSource = ();
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -261,8 +325,16 @@ impl cdp_engine::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type CollateralFreeze = MockCollateralFreeze;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type PalletId = CDPEnginePalletId;
+	type LiquidationEvmBridge = ();
+	type LiquidationContractGasLimit = LiquidationContractGasLimit;
+	type GasToWeight = Identity;
+	type EvmAddressMapping = MockAddressMapping;
+	type Erc20InfoMapping = ();
+	type StabilityPool = ();
 	type WeightInfo = ();
 }
 
@@ -271,12 +343,33 @@ type Block = frame_system::mocking::MockBlock<Runtime>;
 
 parameter_types! {
 	pub const DepositPerAuthorization: Balance = 100;
+	pub RedemptionFeeFloor: Rate = Rate::saturating_from_rational(1, 100);
+	pub RedemptionBaseRateDecay: Rate = Rate::saturating_from_rational(1, 2);
+	pub static MaxRedemptionCandidates: u32 = 3;
+	pub const DepositPerAutoDeleverageRule: Balance = 50;
+	pub static ScheduledTasks: Vec<HonzonTask<Runtime>> = vec![];
+}
+
+pub struct MockIdleScheduler;
+impl IdleScheduler<HonzonTask<Runtime>> for MockIdleScheduler {
+	fn schedule(task: HonzonTask<Runtime>) -> DispatchResult {
+		let mut tasks = ScheduledTasks::get();
+		tasks.push(task);
+		ScheduledTasks::set(tasks);
+		Ok(())
+	}
 }
 
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = PalletBalances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type RedemptionFeeFloor = RedemptionFeeFloor;
+	type RedemptionBaseRateDecay = RedemptionBaseRateDecay;
+	type MaxRedemptionCandidates = MaxRedemptionCandidates;
+	type DepositPerAutoDeleverageRule = DepositPerAutoDeleverageRule;
+	type Task = HonzonTask<Runtime>;
+	type IdleScheduler = MockIdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
-use orml_traits::Change;
+use orml_traits::{Change, MultiCurrency};
 use sp_runtime::FixedPointNumber;
 use support::{Rate, Ratio};
 
@@ -35,7 +35,14 @@ fn authorize_should_work() {
 		assert_ok!(HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB));
 		assert_eq!(PalletBalances::reserved_balance(ALICE), DepositPerAuthorization::get());
 		System::assert_last_event(Event::HonzonModule(crate::Event::Authorization(ALICE, BOB, BTC)));
-		assert_ok!(HonzonModule::check_authorization(&ALICE, &BOB, BTC));
+		assert_ok!(HonzonModule::check_authorization(
+			&ALICE,
+			&BOB,
+			BTC,
+			Permissions::all(),
+			0,
+			0
+		));
 		assert_noop!(
 			HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB),
 			Error::<Runtime>::AlreadyAuthorized
@@ -49,13 +56,20 @@ fn unauthorize_should_work() {
 		System::set_block_number(1);
 		assert_ok!(HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB));
 		assert_eq!(PalletBalances::reserved_balance(ALICE), 100);
-		assert_ok!(HonzonModule::check_authorization(&ALICE, &BOB, BTC));
+		assert_ok!(HonzonModule::check_authorization(
+			&ALICE,
+			&BOB,
+			BTC,
+			Permissions::all(),
+			0,
+			0
+		));
 
 		assert_ok!(HonzonModule::unauthorize(Origin::signed(ALICE), BTC, BOB));
 		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
 		System::assert_last_event(Event::HonzonModule(crate::Event::UnAuthorization(ALICE, BOB, BTC)));
 		assert_noop!(
-			HonzonModule::check_authorization(&ALICE, &BOB, BTC),
+			HonzonModule::check_authorization(&ALICE, &BOB, BTC, Permissions::all(), 0, 0),
 			Error::<Runtime>::NoPermission
 		);
 		assert_noop!(
@@ -77,13 +91,82 @@ fn unauthorize_all_should_work() {
 		System::assert_last_event(Event::HonzonModule(crate::Event::UnAuthorizationAll(ALICE)));
 
 		assert_noop!(
-			HonzonModule::check_authorization(&ALICE, &BOB, BTC),
+			HonzonModule::check_authorization(&ALICE, &BOB, BTC, Permissions::all(), 0, 0),
 			Error::<Runtime>::NoPermission
 		);
 		assert_noop!(
-			HonzonModule::check_authorization(&ALICE, &BOB, DOT),
+			HonzonModule::check_authorization(&ALICE, &BOB, DOT, Permissions::all(), 0, 0),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn authorize_with_limits_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let permissions = Permissions(AuthorizationPermission::DepositCollateral | AuthorizationPermission::RepayDebit);
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			permissions,
+			Some(100),
+			Some(10),
+		));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), DepositPerAuthorization::get());
+		System::assert_has_event(Event::HonzonModule(crate::Event::Authorization(ALICE, BOB, BTC)));
+		System::assert_last_event(Event::HonzonModule(crate::Event::AuthorizationLimitUpdated(
+			ALICE,
+			BOB,
+			BTC,
+			permissions,
+			Some(100),
+			Some(10),
+		)));
+		assert_eq!(
+			HonzonModule::authorization_limits(ALICE, (BTC, BOB)),
+			Some(AuthorizationLimit {
+				permissions,
+				spending_cap: Some(100),
+				expiry: Some(10),
+			})
+		);
+		assert_ok!(HonzonModule::check_authorization(
+			&ALICE,
+			&BOB,
+			BTC,
+			Permissions(AuthorizationPermission::DepositCollateral.into()),
+			0,
+			0
+		));
+		assert_noop!(
+			HonzonModule::check_authorization(&ALICE, &BOB, BTC, Permissions::all(), 0, 0),
 			Error::<Runtime>::NoPermission
 		);
+
+		// update the limits without reserving again
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions::all(),
+			None,
+			None,
+		));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), DepositPerAuthorization::get());
+		assert_ok!(HonzonModule::check_authorization(
+			&ALICE,
+			&BOB,
+			BTC,
+			Permissions::all(),
+			0,
+			0
+		));
+
+		assert_ok!(HonzonModule::unauthorize(Origin::signed(ALICE), BTC, BOB));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+		assert_eq!(HonzonModule::authorization_limits(ALICE, (BTC, BOB)), None);
 	});
 }
 
@@ -98,6 +181,7 @@ fn transfer_loan_from_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_ok!(HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB));
@@ -117,6 +201,66 @@ fn transfer_unauthorization_loans_should_not_work() {
 	});
 }
 
+#[test]
+fn transfer_loan_from_with_limits_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::DepositCollateral.into()),
+			None,
+			None,
+		));
+		assert_noop!(
+			HonzonModule::transfer_loan_from(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::NoPermission,
+		);
+
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::TransferLoan.into()),
+			Some(100),
+			None,
+		));
+		assert_noop!(
+			HonzonModule::transfer_loan_from(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::ExceedSpendingCap,
+		);
+
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::TransferLoan.into()),
+			Some(150),
+			None,
+		));
+		assert_ok!(HonzonModule::transfer_loan_from(Origin::signed(BOB), BTC, ALICE));
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 50);
+		assert_eq!(
+			HonzonModule::authorization_limits(ALICE, (BTC, BOB))
+				.unwrap()
+				.spending_cap,
+			Some(0)
+		);
+	});
+}
+
 #[test]
 fn adjust_loan_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -128,6 +272,7 @@ fn adjust_loan_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -135,6 +280,92 @@ fn adjust_loan_should_work() {
 	});
 }
 
+#[test]
+fn adjust_loan_on_behalf_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, 50, 0),
+			Error::<Runtime>::NoPermission,
+		);
+
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::DepositCollateral | AuthorizationPermission::RepayDebit),
+			Some(100),
+			Some(10),
+		));
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, -10, 0),
+			Error::<Runtime>::NoPermission,
+		);
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, 0, 10),
+			Error::<Runtime>::NoPermission,
+		);
+
+		// the collateral and stable currency are transferred from the owner
+		assert_ok!(HonzonModule::adjust_loan_on_behalf(
+			Origin::signed(BOB),
+			ALICE,
+			BTC,
+			50,
+			-20
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 150);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 30);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 850);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 1000);
+		assert_eq!(
+			HonzonModule::authorization_limits(ALICE, (BTC, BOB))
+				.unwrap()
+				.spending_cap,
+			Some(30)
+		);
+
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, 40, 0),
+			Error::<Runtime>::ExceedSpendingCap,
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, 10, 0),
+			Error::<Runtime>::AuthorizationExpired,
+		);
+
+		// the owner is not limited by the authorization
+		assert_ok!(HonzonModule::adjust_loan_on_behalf(
+			Origin::signed(ALICE),
+			ALICE,
+			BTC,
+			-10,
+			10
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, -10, 10));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 130);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+		assert_eq!(
+			HonzonModule::authorization_limits(ALICE, (BTC, BOB))
+				.unwrap()
+				.spending_cap,
+			Some(30)
+		);
+	});
+}
+
 #[test]
 fn on_emergency_shutdown_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -151,6 +382,35 @@ fn on_emergency_shutdown_should_work() {
 			HonzonModule::close_loan_has_debit_by_dex(Origin::signed(ALICE), BTC, 100, None),
 			Error::<Runtime>::AlreadyShutdown,
 		);
+		assert_noop!(
+			HonzonModule::adjust_basket_loan(Origin::signed(ALICE), vec![(BTC, 100), (DOT, 100)], 50),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_noop!(
+			HonzonModule::redeem(Origin::signed(ALICE), BTC, 100, Rate::one()),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_noop!(
+			HonzonModule::adjust_loan_on_behalf(Origin::signed(BOB), ALICE, BTC, 100, 50),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_noop!(
+			HonzonModule::close_loan_has_debit_by_dex_on_behalf(Origin::signed(BOB), ALICE, BTC, 100, None),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_noop!(
+			HonzonModule::set_auto_deleverage(
+				Origin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_integer(2),
+				Ratio::saturating_from_integer(3)
+			),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_noop!(
+			HonzonModule::trigger_auto_deleverage(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AlreadyShutdown,
+		);
 	});
 }
 
@@ -165,6 +425,7 @@ fn close_loan_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -180,3 +441,471 @@ fn close_loan_has_debit_by_dex_work() {
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 	});
 }
+
+#[test]
+fn close_loan_has_debit_by_dex_on_behalf_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::RepayDebit.into()),
+			None,
+			None,
+		));
+		assert_noop!(
+			HonzonModule::close_loan_has_debit_by_dex_on_behalf(Origin::signed(BOB), ALICE, BTC, 100, None),
+			Error::<Runtime>::NoPermission,
+		);
+
+		assert_ok!(HonzonModule::authorize_with_limits(
+			Origin::signed(ALICE),
+			BTC,
+			BOB,
+			Permissions(AuthorizationPermission::CloseByDex.into()),
+			None,
+			None,
+		));
+		assert_ok!(HonzonModule::close_loan_has_debit_by_dex_on_behalf(
+			Origin::signed(BOB),
+			ALICE,
+			BTC,
+			100,
+			None
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 1000);
+	});
+}
+
+#[test]
+fn set_auto_deleverage_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_noop!(
+			HonzonModule::set_auto_deleverage(
+				Origin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_rational(3, 2),
+				Ratio::saturating_from_integer(3)
+			),
+			Error::<Runtime>::InvalidAutoDeleverageRatio,
+		);
+		assert_noop!(
+			HonzonModule::set_auto_deleverage(
+				Origin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_integer(3),
+				Ratio::saturating_from_integer(2)
+			),
+			Error::<Runtime>::InvalidAutoDeleverageRatio,
+		);
+		assert_noop!(
+			HonzonModule::set_auto_deleverage(
+				Origin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_integer(2),
+				Ratio::saturating_from_integer(3)
+			),
+			Error::<Runtime>::PositionNotOpen,
+		);
+
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 0));
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(2),
+			Ratio::saturating_from_integer(3)
+		));
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoDeleverageRuleUpdated(
+			ALICE,
+			BTC,
+			Ratio::saturating_from_integer(2),
+			Ratio::saturating_from_integer(3),
+		)));
+		assert_eq!(
+			HonzonModule::auto_deleverage_rules(BTC, ALICE),
+			Some(AutoDeleverageRule {
+				trigger_ratio: Ratio::saturating_from_integer(2),
+				target_ratio: Ratio::saturating_from_integer(3),
+				deposit: 50,
+			})
+		);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+
+		// update the rule without reserving again
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(4),
+			Ratio::saturating_from_integer(5)
+		));
+		assert_eq!(
+			HonzonModule::auto_deleverage_rules(BTC, ALICE),
+			Some(AutoDeleverageRule {
+				trigger_ratio: Ratio::saturating_from_integer(4),
+				target_ratio: Ratio::saturating_from_integer(5),
+				deposit: 50,
+			})
+		);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+
+		assert_ok!(HonzonModule::remove_auto_deleverage(Origin::signed(ALICE), BTC));
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoDeleverageRuleRemoved(ALICE, BTC)));
+		assert_eq!(HonzonModule::auto_deleverage_rules(BTC, ALICE), None);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+		assert_noop!(
+			HonzonModule::remove_auto_deleverage(Origin::signed(ALICE), BTC),
+			Error::<Runtime>::NoAutoDeleverageRule,
+		);
+	});
+}
+
+#[test]
+fn schedule_auto_deleverage_task_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		// no rules, no task
+		HonzonModule::on_initialize(1);
+		assert!(ScheduledTasks::get().is_empty());
+
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 200, 50));
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(3),
+			Ratio::saturating_from_integer(6)
+		));
+		HonzonModule::on_initialize(2);
+		assert_eq!(
+			ScheduledTasks::get(),
+			vec![HonzonTask::<Runtime>::AutoDeleverage { currency_id: BTC }]
+		);
+		assert!(HonzonModule::auto_deleverage_task_scheduled(BTC));
+
+		// the task is scheduled once until the sweep finished, whatever the rules are
+		// re-added
+		assert_ok!(HonzonModule::remove_auto_deleverage(Origin::signed(ALICE), BTC));
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(3),
+			Ratio::saturating_from_integer(6)
+		));
+		HonzonModule::on_initialize(3);
+		assert_eq!(ScheduledTasks::get().len(), 1);
+
+		assert!(
+			HonzonTask::<Runtime>::AutoDeleverage { currency_id: BTC }
+				.dispatch(Weight::max_value())
+				.finished
+		);
+		assert!(!HonzonModule::auto_deleverage_task_scheduled(BTC));
+		HonzonModule::on_initialize(4);
+		assert_eq!(ScheduledTasks::get().len(), 2);
+	});
+}
+
+#[test]
+fn auto_deleverage_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 200, 50));
+		assert_noop!(
+			HonzonModule::trigger_auto_deleverage(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::NoAutoDeleverageRule,
+		);
+
+		// the collateral ratio is 4
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(3),
+			Ratio::saturating_from_integer(6)
+		));
+		assert_noop!(
+			HonzonModule::trigger_auto_deleverage(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AutoDeleverageNotTriggered,
+		);
+
+		let task = HonzonTask::<Runtime>::AutoDeleverage { currency_id: BTC };
+		let result = task.clone().dispatch(Weight::max_value());
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+
+		assert_ok!(HonzonModule::set_auto_deleverage(
+			Origin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_integer(5),
+			Ratio::saturating_from_integer(6)
+		));
+
+		// not enough weight
+		let result = task.clone().dispatch(0);
+		assert!(!result.finished);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+
+		// repay 25 debit to bring the collateral ratio back to 6
+		let result = task.clone().dispatch(Weight::max_value());
+		assert_eq!(result.result, Ok(()));
+		assert_eq!(result.used_weight, <() as WeightInfo>::trigger_auto_deleverage());
+		assert!(result.finished);
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoDeleveraged(ALICE, BTC)));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 200);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 25);
+
+		assert_noop!(
+			HonzonModule::trigger_auto_deleverage(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AutoDeleverageNotTriggered,
+		);
+
+		// the rule of the closed CDP is removed by the task
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, -200, -25));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+		assert!(task.dispatch(Weight::max_value()).finished);
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoDeleverageRuleRemoved(ALICE, BTC)));
+		assert_eq!(HonzonModule::auto_deleverage_rules(BTC, ALICE), None);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn auto_deleverage_task_sweep_by_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(PalletBalances::transfer(Origin::signed(ALICE), BOB, 100));
+		for who in [ALICE, BOB] {
+			assert_ok!(HonzonModule::adjust_loan(Origin::signed(who), BTC, 200, 50));
+			assert_ok!(HonzonModule::set_auto_deleverage(
+				Origin::signed(who),
+				BTC,
+				Ratio::saturating_from_integer(5),
+				Ratio::saturating_from_integer(6)
+			));
+		}
+
+		// only one CDP can be deleveraged with the weight
+		let task = HonzonTask::<Runtime>::AutoDeleverage { currency_id: BTC };
+		let result = task.clone().dispatch(<() as WeightInfo>::trigger_auto_deleverage());
+		assert_eq!(result.used_weight, <() as WeightInfo>::trigger_auto_deleverage());
+		assert!(!result.finished);
+		assert!(LastAutoDeleverageKeyIterated::<Runtime>::contains_key(BTC));
+		assert_eq!(
+			LoansModule::positions(BTC, ALICE).debit + LoansModule::positions(BTC, BOB).debit,
+			75
+		);
+
+		// continue from the last iterated rule
+		let result = task.dispatch(Weight::max_value());
+		assert!(result.finished);
+		assert!(!LastAutoDeleverageKeyIterated::<Runtime>::contains_key(BTC));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 25);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 25);
+	});
+}
+
+#[test]
+fn adjust_basket_loan_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_basket_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_basket_loan(
+			Origin::signed(ALICE),
+			vec![(BTC, 100), (DOT, 100)],
+			100
+		));
+		assert_eq!(
+			LoansModule::basket_positions(ALICE).collateral_amounts(),
+			vec![(DOT, 100), (BTC, 100)]
+		);
+		assert_eq!(LoansModule::basket_positions(ALICE).debit, 100);
+
+		assert_ok!(HonzonModule::adjust_basket_loan(
+			Origin::signed(ALICE),
+			vec![(DOT, -100)],
+			-50
+		));
+		assert_eq!(
+			LoansModule::basket_positions(ALICE).collateral_amounts(),
+			vec![(BTC, 100)]
+		);
+		assert_eq!(LoansModule::basket_positions(ALICE).debit, 50);
+	});
+}
+
+#[test]
+fn redeem_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_noop!(
+			HonzonModule::redeem(Origin::signed(CAROL), BTC, 100, Rate::one()),
+			Error::<Runtime>::NoRedeemablePosition,
+		);
+
+		// the collateral ratio of alice is 200%, bob is 300%
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 200, 100));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(BOB), BTC, 300, 100));
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), CAROL, AUSD, 100));
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), CAROL, AUSD, 50));
+
+		// fee rate = 1% + (150 / 200) / 2 = 38.5%
+		assert_noop!(
+			HonzonModule::redeem(Origin::signed(CAROL), BTC, 150, Rate::saturating_from_rational(38, 100)),
+			Error::<Runtime>::RedemptionFeeTooHigh,
+		);
+
+		// redeem against alice first, who has the lowest collateral ratio
+		assert_ok!(HonzonModule::redeem(
+			Origin::signed(CAROL),
+			BTC,
+			150,
+			Rate::saturating_from_rational(385, 1000)
+		));
+		System::assert_last_event(Event::HonzonModule(crate::Event::Redemption(CAROL, BTC, 150, 93, 57)));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 250);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 50);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 0);
+		assert_eq!(Currencies::free_balance(BTC, &CAROL), 93);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 57);
+
+		// the base rate decays over time
+		assert_eq!(
+			HonzonModule::redemption_base_rate(BTC),
+			Rate::saturating_from_rational(375, 1000)
+		);
+		assert_eq!(HonzonModule::last_redemption_block(BTC), 1);
+		System::set_block_number(3);
+		assert_eq!(
+			HonzonModule::get_redemption_base_rate(BTC),
+			Rate::saturating_from_rational(375, 4000)
+		);
+
+		// the base rate increases by the actually redeemed amount, bob keeps the minimum
+		// debit value.
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), CAROL, AUSD, 50));
+		assert_ok!(HonzonModule::redeem(Origin::signed(CAROL), BTC, 49, Rate::one()));
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 202);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 2);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 2);
+		assert_eq!(
+			HonzonModule::redemption_base_rate(BTC),
+			Rate::saturating_from_rational(375, 4000) + Rate::saturating_from_rational(48, 100)
+		);
+	});
+}
+
+#[test]
+fn redeem_should_skip_unsafe_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MaxRedemptionCandidates::set(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+
+		// the collateral ratio of alice is 200%, bob is 300%
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 200, 100));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(BOB), BTC, 300, 100));
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), CAROL, AUSD, 50));
+
+		// alice is unsafe at the liquidation ratio of 250%, and isn't a candidate
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 2))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::redeem(Origin::signed(CAROL), BTC, 50, Rate::one()));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 100);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 50);
+
+		// the positions can't be redeemed until they are all indexed
+		frame_support::traits::StorageVersion::new(0).put::<LoansModule>();
+		assert_noop!(
+			HonzonModule::redeem(Origin::signed(CAROL), BTC, 50, Rate::one()),
+			Error::<Runtime>::PositionsNotIndexed,
+		);
+	});
+}
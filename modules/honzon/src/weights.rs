@@ -54,6 +54,13 @@ pub trait WeightInfo {
 	fn transfer_loan_from() -> Weight;
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight;
 	fn close_loan_has_debit_by_dex_no_path() -> Weight;
+	fn adjust_basket_loan(c: u32, ) -> Weight;
+	fn redeem(c: u32, ) -> Weight;
+	fn authorize_with_limits() -> Weight;
+	fn check_authorization() -> Weight;
+	fn set_auto_deleverage() -> Weight;
+	fn remove_auto_deleverage() -> Weight;
+	fn trigger_auto_deleverage() -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -100,6 +107,52 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(32 as Weight))
 			.saturating_add(T::DbWeight::get().writes(14 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_basket_loan(c: u32, ) -> Weight {
+		(251_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn redeem(c: u32, ) -> Weight {
+		(98_451_000 as Weight)
+			.saturating_add((41_358_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn authorize_with_limits() -> Weight {
+		(55_372_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn check_authorization() -> Weight {
+		(27_916_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_auto_deleverage() -> Weight {
+		(39_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_auto_deleverage() -> Weight {
+		(24_718_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn trigger_auto_deleverage() -> Weight {
+		(412_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(38 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -145,4 +198,50 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(32 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_basket_loan(c: u32, ) -> Weight {
+		(132_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn redeem(c: u32, ) -> Weight {
+		(98_451_000 as Weight)
+			.saturating_add((41_358_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn authorize_with_limits() -> Weight {
+		(55_372_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn check_authorization() -> Weight {
+		(27_916_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_auto_deleverage() -> Weight {
+		(39_210_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_auto_deleverage() -> Weight {
+		(24_718_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn trigger_auto_deleverage() -> Weight {
+		(412_530_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(38 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
 }
@@ -29,6 +29,8 @@
 //! Pool types:
 //! 1. Loans: record the shares and rewards for users of Loans(Honzon protocol).
 //! 2. Dex: record the shares and rewards for DEX makers who staking LP token.
+//! 3. StabilityPool: record the shares and rewards for depositors of the stability pool, the
+//! collateral absorbed by the stability pool is also distributed to depositors as rewards.
 //!
 //! Rewards accumulation:
 //! 1. Incentives: periodicly(AccumulatePeriod), accumulate fixed amount according to Incentive.
@@ -52,7 +54,7 @@ use sp_runtime::{
 	DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate};
+use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate, StabilityPoolIncentives};
 
 mod mock;
 mod tests;
@@ -69,6 +71,9 @@ pub enum PoolId {
 
 	/// Rewards and shares pool for DEX makers who stake LP token(LPCurrencyId)
 	Dex(CurrencyId),
+
+	/// Rewards and shares pool for users who deposit stable currency into stability pool
+	StabilityPool,
 }
 
 #[frame_support::pallet]
@@ -199,7 +204,7 @@ pub mod module {
 					if !pool_info.total_shares.is_zero() {
 						match pool_id {
 							// do not accumulate incentives for PoolId::Loans after shutdown
-							PoolId::Loans(_) if !shutdown => {
+							PoolId::Loans(_) | PoolId::StabilityPool if !shutdown => {
 								count += 1;
 								Self::accumulate_incentives(pool_id);
 							}
@@ -275,7 +280,12 @@ pub mod module {
 			<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
 
 			let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(&pool_id, &who);
-			let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
+			// the collateral gain of stability pool depositors is the compensation for absorbed
+			// liquidations, it's never deducted.
+			let deduction_rate = match pool_id {
+				PoolId::StabilityPool => Rate::zero(),
+				_ => Self::claim_reward_deduction_rates(&pool_id),
+			};
 
 			for (currency_id, pending_reward) in pending_multi_rewards {
 				if pending_reward.is_zero() {
@@ -392,8 +402,12 @@ pub mod module {
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			for (pool_id, deduction_rate) in updates {
-				if let PoolId::Dex(currency_id) = pool_id {
-					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
+				match pool_id {
+					PoolId::Dex(currency_id) => {
+						ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
+					}
+					PoolId::StabilityPool => return Err(Error::<T>::InvalidPoolId.into()),
+					_ => {}
 				}
 				ensure!(deduction_rate <= Rate::one(), Error::<T>::InvalidRate);
 				ClaimRewardDeductionRates::<T>::mutate_exists(&pool_id, |maybe_rate| {
@@ -537,6 +551,23 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 	}
 }
 
+impl<T: Config> StabilityPoolIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn set_stability_pool_share(who: &T::AccountId, share_amount: Balance) {
+		<orml_rewards::Pallet<T>>::set_share(who, &PoolId::StabilityPool, share_amount);
+	}
+
+	fn distribute_collateral_gain(from: &T::AccountId, currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		ensure!(
+			!<orml_rewards::Pallet<T>>::pool_infos(PoolId::StabilityPool)
+				.total_shares
+				.is_zero(),
+			Error::<T>::NotEnough
+		);
+		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)?;
+		<orml_rewards::Pallet<T>>::accumulate_reward(&PoolId::StabilityPool, currency_id, amount)
+	}
+}
+
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpdateLoan<T> {
 	fn happened(info: &(T::AccountId, CurrencyId, Amount, Balance)) {
@@ -150,6 +150,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
 		unimplemented!()
 	}
@@ -183,6 +187,18 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_swap_path_with_exact_supply(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_limit_orders(_: &AccountId) -> Vec<(u64, CurrencyId, CurrencyId, Balance, Balance)> {
+		unimplemented!()
+	}
+
 	fn swap_with_exact_supply(
 		_: &AccountId,
 		_: &[CurrencyId],
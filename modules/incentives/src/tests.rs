@@ -342,6 +342,13 @@ fn update_claim_reward_deduction_rates_works() {
 			),
 			Error::<Runtime>::InvalidRate,
 		);
+		assert_noop!(
+			IncentivesModule::update_claim_reward_deduction_rates(
+				Origin::signed(ROOT::get()),
+				vec![(PoolId::StabilityPool, Rate::saturating_from_rational(1, 100))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
 
 		assert_eq!(
 			IncentivesModule::claim_reward_deduction_rates(PoolId::Dex(DOT_AUSD_LP)),
@@ -491,6 +498,67 @@ fn on_update_loan_works() {
 	});
 }
 
+#[test]
+fn stability_pool_incentives_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(RewardsModule::pool_infos(PoolId::StabilityPool), PoolInfo::default());
+		assert_ok!(TokensModule::deposit(BTC, &BOB::get(), 1000));
+
+		// no shares in the pool, collateral can not be distributed
+		assert_noop!(
+			IncentivesModule::distribute_collateral_gain(&BOB::get(), BTC, 100),
+			Error::<Runtime>::NotEnough
+		);
+
+		IncentivesModule::set_stability_pool_share(&ALICE::get(), 100);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StabilityPool, ALICE::get()),
+			(100, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::StabilityPool),
+			PoolInfo {
+				total_shares: 100,
+				..Default::default()
+			}
+		);
+
+		assert_ok!(IncentivesModule::distribute_collateral_gain(&BOB::get(), BTC, 100));
+		assert_eq!(TokensModule::free_balance(BTC, &BOB::get()), 900);
+		assert_eq!(TokensModule::free_balance(BTC, &VAULT::get()), 100);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::StabilityPool),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(BTC, (100, 0))].into_iter().collect(),
+			}
+		);
+
+		// the deduction rate is never applied to the collateral gain of stability pool
+		ClaimRewardDeductionRates::<Runtime>::insert(PoolId::StabilityPool, Rate::saturating_from_rational(50, 100));
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::StabilityPool
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			PoolId::StabilityPool,
+			BTC,
+			100,
+			0,
+		)));
+		assert_eq!(TokensModule::free_balance(BTC, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(BTC, &VAULT::get()), 0);
+
+		IncentivesModule::set_stability_pool_share(&ALICE::get(), 0);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::StabilityPool, ALICE::get()),
+			Default::default()
+		);
+	});
+}
+
 #[test]
 fn payout_works() {
 	ExtBuilder::default().build().execute_with(|| {
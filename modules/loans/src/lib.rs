@@ -21,23 +21,27 @@
 //! ## Overview
 //!
 //! Loans module manages CDP's collateral assets and the debits backed by these
-//! assets.
+//! assets. The positions with debit are indexed by the ratio of collateral to
+//! debit, which gives the positions of a collateral type sorted by collateral
+//! ratio.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{
+	log, pallet_prelude::*, storage::StoragePrefixedMap, traits::StorageVersion, transactional, PalletId, StorageHasher,
+};
 use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
 use primitives::{Amount, Balance, CurrencyId};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AccountIdConversion, Convert, Zero},
-	ArithmeticError, DispatchResult, RuntimeDebug,
+	ArithmeticError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::{convert::TryInto, result};
-use support::{CDPTreasury, RiskManager};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*, result};
+use support::{CDPTreasury, Ratio, RiskManager};
 
 mod mock;
 mod tests;
@@ -53,6 +57,25 @@ pub struct Position {
 	pub debit: Balance,
 }
 
+/// A collateralized debit position backed by a basket of collaterals.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, Default, TypeInfo)]
+pub struct BasketPosition {
+	/// The amounts of collaterals.
+	pub collaterals: BTreeMap<CurrencyId, Balance>,
+	/// The amount of debit.
+	pub debit: Balance,
+}
+
+impl BasketPosition {
+	/// The list of collateral types and amounts in the basket.
+	pub fn collateral_amounts(&self) -> Vec<(CurrencyId, Balance)> {
+		self.collaterals
+			.iter()
+			.map(|(currency_id, amount)| (*currency_id, *amount))
+			.collect()
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -106,6 +129,12 @@ pub mod module {
 		ConfiscateCollateralAndDebit(T::AccountId, CurrencyId, Balance, Balance),
 		/// Transfer loan. \[from, to, currency_id\]
 		TransferLoan(T::AccountId, T::AccountId, CurrencyId),
+		/// Basket position updated. \[owner, collateral_adjustments,
+		/// debit_adjustment\]
+		BasketPositionUpdated(T::AccountId, Vec<(CurrencyId, Amount)>, Amount),
+		/// Confiscate basket CDP's collateral assets and eliminate its debit.
+		/// \[owner, confiscated_collateral_amounts, deduct_debit_amount\]
+		ConfiscateBasketCollateralsAndDebit(T::AccountId, Vec<(CurrencyId, Balance)>, Balance),
 	}
 
 	/// The collateralized debit positions, map from
@@ -125,11 +154,58 @@ pub mod module {
 	#[pallet::getter(fn total_positions)]
 	pub type TotalPositions<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Position, ValueQuery>;
 
+	/// The collateralized debit positions backed by a basket of collaterals,
+	/// map from Owner -> BasketPosition
+	///
+	/// BasketPositions: map AccountId => BasketPosition
+	#[pallet::storage]
+	#[pallet::getter(fn basket_positions)]
+	pub type BasketPositions<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BasketPosition, ValueQuery>;
+
+	/// The total basket collateralized debit positions
+	///
+	/// TotalBasketPositions: BasketPosition
+	#[pallet::storage]
+	#[pallet::getter(fn total_basket_positions)]
+	pub type TotalBasketPositions<T: Config> = StorageValue<_, BasketPosition, ValueQuery>;
+
+	/// The index of the positions with debit, sorted by the ratio of collateral
+	/// to debit in ascending order. The key is the big-endian bytes of the ratio,
+	/// so the positions are iterated in order. The positions of a collateral type
+	/// share the price and debit exchange rate, the order is the same as by
+	/// collateral ratio.
+	///
+	/// SortedPositions: double_map CurrencyId, ([u8; 16], AccountId) => ()
+	#[pallet::storage]
+	pub type SortedPositions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Identity, ([u8; 16], T::AccountId), (), OptionQuery>;
+
+	/// The raw key of the last position indexed into `SortedPositions` by the
+	/// storage migration, which indexes the existing positions over multiple
+	/// blocks.
+	///
+	/// LastIndexedPositionKey: Vec<u8>
+	#[pallet::storage]
+	pub type LastIndexedPositionKey<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Index the existing positions with debit into `SortedPositions` within
+		/// the remaining weight of the block, until all of them are indexed.
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let check_weight = T::DbWeight::get().reads(1);
+			if remaining_weight < check_weight || Self::sorted_positions_indexed() {
+				return check_weight.min(remaining_weight);
+			}
+			Self::index_sorted_positions(remaining_weight.saturating_sub(check_weight)).saturating_add(check_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
@@ -232,6 +308,132 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Redeem `debit_decrease` debit of the position of `who` by `redeemer` at face value.
+	/// `redeemer` repays the debit and receives `collateral_redeemed` collateral from the
+	/// position, except `collateral_fee` kept by cdp treasury.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn redeem_position(
+		who: &T::AccountId,
+		redeemer: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_redeemed: Balance,
+		collateral_fee: Balance,
+		debit_decrease: Balance,
+	) -> DispatchResult {
+		// convert balance type to amount type
+		let collateral_adjustment = Self::amount_try_from_balance(collateral_redeemed)?.saturating_neg();
+		let debit_adjustment = Self::amount_try_from_balance(debit_decrease)?.saturating_neg();
+
+		Self::update_loan(who, currency_id, collateral_adjustment, debit_adjustment)?;
+
+		// repay debit by redeemer
+		T::CDPTreasury::burn_debit(redeemer, T::Convert::convert((currency_id, debit_decrease)))?;
+
+		// the fee is kept by cdp treasury, the rest goes to redeemer
+		let module_account = Self::account_id();
+		T::CDPTreasury::deposit_collateral(&module_account, currency_id, collateral_fee)?;
+		T::Currency::transfer(
+			currency_id,
+			&module_account,
+			redeemer,
+			collateral_redeemed.saturating_sub(collateral_fee),
+		)?;
+
+		Self::deposit_event(Event::PositionUpdated(
+			who.clone(),
+			currency_id,
+			collateral_adjustment,
+			debit_adjustment,
+		));
+		Ok(())
+	}
+
+	/// The owners of at most `count` positions of `currency_id` with debit, sorted by the
+	/// ratio of collateral to debit in ascending order.
+	pub fn sorted_positions(currency_id: CurrencyId, count: u32) -> Vec<T::AccountId> {
+		SortedPositions::<T>::iter_prefix(currency_id)
+			.take(count as usize)
+			.map(|((_, who), _)| who)
+			.collect()
+	}
+
+	/// The owners of at most `count` positions of `currency_id` with debit whose
+	/// ratio of collateral to debit is at least `min_ratio`, sorted by the ratio
+	/// in ascending order.
+	pub fn sorted_positions_from(currency_id: CurrencyId, min_ratio: Ratio, count: u32) -> Vec<T::AccountId> {
+		// the keys of the positions with `min_ratio` are extended by the owner, so
+		// they are all after the ratio.
+		let mut starting_raw_key = SortedPositions::<T>::final_prefix().to_vec();
+		starting_raw_key.extend(Twox64Concat::hash(&currency_id.encode()));
+		starting_raw_key.extend(&min_ratio.into_inner().to_be_bytes());
+		SortedPositions::<T>::iter_prefix_from(currency_id, starting_raw_key)
+			.take(count as usize)
+			.map(|((_, who), _)| who)
+			.collect()
+	}
+
+	/// Whether all the positions with debit are indexed in `SortedPositions`.
+	pub fn sorted_positions_indexed() -> bool {
+		StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION
+	}
+
+	/// Index the positions with debit into `SortedPositions` from the last
+	/// indexed key within `weight`. Return the used weight.
+	fn index_sorted_positions(weight: Weight) -> Weight {
+		let iterate_weight = T::DbWeight::get().reads_writes(1, 1);
+		let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+		if used_weight > weight {
+			return 0;
+		}
+
+		// If the last key is Some(value) continue iterating from that point in storage otherwise
+		// start iterating from the beginning of <Positions<T>>
+		let mut iterator = match LastIndexedPositionKey::<T>::get() {
+			Some(key) => Positions::<T>::iter_from(key),
+			None => Positions::<T>::iter(),
+		};
+
+		let mut finished_iteration = true;
+		loop {
+			if used_weight.saturating_add(iterate_weight) > weight {
+				finished_iteration = false;
+				break;
+			}
+
+			match iterator.next() {
+				Some((currency_id, who, Position { collateral, debit })) => {
+					used_weight = used_weight.saturating_add(iterate_weight);
+					if !debit.is_zero() {
+						SortedPositions::<T>::insert(
+							currency_id,
+							(Self::sorted_position_key(collateral, debit), who),
+							(),
+						);
+					}
+				}
+				None => break,
+			}
+		}
+
+		if finished_iteration {
+			LastIndexedPositionKey::<T>::kill();
+			STORAGE_VERSION.put::<Pallet<T>>();
+		} else {
+			LastIndexedPositionKey::<T>::put(iterator.last_raw_key());
+		}
+		used_weight
+	}
+
+	/// The key of the position in `SortedPositions`.
+	fn sorted_position_key(collateral: Balance, debit: Balance) -> [u8; 16] {
+		Ratio::checked_from_rational(collateral, debit)
+			.unwrap_or_else(Ratio::max_value)
+			.into_inner()
+			.to_be_bytes()
+	}
+
 	/// transfer whole loan of `from` to `to`
 	pub fn transfer_loan(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		// get `from` position data
@@ -312,6 +514,22 @@ impl<T: Config> Pallet<T> {
 			// and the data will been messed up, before migration or calibration,
 			// it is forbidden to turn on incentives for pool LoansIncentive(KSM).
 			T::OnUpdateLoan::happened(&(who.clone(), currency_id, collateral_adjustment, p.collateral));
+
+			// update the sorted index
+			if !p.debit.is_zero() {
+				SortedPositions::<T>::remove(
+					currency_id,
+					(Self::sorted_position_key(p.collateral, p.debit), who.clone()),
+				);
+			}
+			if !new_debit.is_zero() {
+				SortedPositions::<T>::insert(
+					currency_id,
+					(Self::sorted_position_key(new_collateral, new_debit), who.clone()),
+					(),
+				);
+			}
+
 			p.collateral = new_collateral;
 			p.debit = new_debit;
 
@@ -356,6 +574,191 @@ impl<T: Config> Pallet<T> {
 			Ok(())
 		})
 	}
+
+	/// confiscate collaterals and debit of basket position to cdp treasury.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn confiscate_basket_collaterals_and_debit(
+		who: &T::AccountId,
+		collaterals_confiscate: &[(CurrencyId, Balance)],
+		debit_decrease: Balance,
+	) -> DispatchResult {
+		// convert balance type to amount type
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		for (currency_id, collateral_confiscate) in collaterals_confiscate {
+			let collateral_adjustment = Self::amount_try_from_balance(*collateral_confiscate)?;
+			collateral_adjustments.push((*currency_id, collateral_adjustment.saturating_neg()));
+		}
+		let debit_adjustment = Self::amount_try_from_balance(debit_decrease)?;
+
+		// transfer collaterals to cdp treasury
+		for (currency_id, collateral_confiscate) in collaterals_confiscate {
+			T::CDPTreasury::deposit_collateral(&Self::account_id(), *currency_id, *collateral_confiscate)?;
+		}
+
+		// deposit debit to cdp treasury
+		let bad_debt_value = T::RiskManager::get_basket_debit_value(debit_decrease);
+		T::CDPTreasury::on_system_debit(bad_debt_value)?;
+
+		// update basket loan
+		Self::update_basket_loan(who, &collateral_adjustments, debit_adjustment.saturating_neg())?;
+
+		Self::deposit_event(Event::ConfiscateBasketCollateralsAndDebit(
+			who.clone(),
+			collaterals_confiscate.to_vec(),
+			debit_decrease,
+		));
+		Ok(())
+	}
+
+	/// adjust the basket position.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn adjust_basket_position(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		// mutate collaterals and debit
+		// Note: if a new position, will inc consumer
+		Self::update_basket_loan(who, collateral_adjustments, debit_adjustment)?;
+
+		let module_account = Self::account_id();
+		for (currency_id, collateral_adjustment) in collateral_adjustments {
+			let collateral_balance_adjustment = Self::balance_try_from_amount_abs(*collateral_adjustment)?;
+
+			if collateral_adjustment.is_positive() {
+				T::Currency::transfer(*currency_id, who, &module_account, collateral_balance_adjustment)?;
+			} else if collateral_adjustment.is_negative() {
+				T::Currency::transfer(*currency_id, &module_account, who, collateral_balance_adjustment)?;
+			}
+		}
+
+		let debit_balance_adjustment = Self::balance_try_from_amount_abs(debit_adjustment)?;
+		if debit_adjustment.is_positive() {
+			// check debit cap when increase debit
+			T::RiskManager::check_basket_debit_cap(Self::total_basket_positions().debit)?;
+
+			// issue debit with collateral backed by cdp treasury
+			T::CDPTreasury::issue_debit(
+				who,
+				T::RiskManager::get_basket_debit_value(debit_balance_adjustment),
+				true,
+			)?;
+		} else if debit_adjustment.is_negative() {
+			// repay debit
+			// burn debit by cdp treasury
+			T::CDPTreasury::burn_debit(who, T::RiskManager::get_basket_debit_value(debit_balance_adjustment))?;
+		}
+
+		// ensure pass risk check
+		let position = Self::basket_positions(who);
+		T::RiskManager::check_basket_position_valid(
+			&position.collateral_amounts(),
+			position.debit,
+			collateral_adjustments
+				.iter()
+				.any(|(_, collateral_adjustment)| collateral_adjustment.is_negative())
+				|| debit_adjustment.is_positive(),
+		)?;
+
+		Self::deposit_event(Event::BasketPositionUpdated(
+			who.clone(),
+			collateral_adjustments.to_vec(),
+			debit_adjustment,
+		));
+		Ok(())
+	}
+
+	/// mutate records of basket collaterals and debits
+	///
+	/// NOTE: the collaterals of basket position are not used as the shares for
+	/// Loans incentives.
+	fn update_basket_loan(
+		who: &T::AccountId,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		<BasketPositions<T>>::try_mutate_exists(who, |may_be_position| -> DispatchResult {
+			let mut p = may_be_position.take().unwrap_or_default();
+			let is_new_position = p.collaterals.is_empty() && p.debit.is_zero();
+			Self::apply_basket_adjustments(&mut p, collateral_adjustments, debit_adjustment)?;
+
+			// increase account ref if new position
+			if is_new_position {
+				if frame_system::Pallet::<T>::inc_consumers(who).is_err() {
+					// No providers for the locks. This is impossible under normal circumstances
+					// since the funds that are under the lock will themselves be stored in the
+					// account and therefore will need a reference.
+					log::warn!(
+						"Warning: Attempt to introduce lock consumer reference, yet no providers. \
+						This is unexpected but should be safe."
+					);
+				}
+			}
+
+			if p.collaterals.is_empty() && p.debit.is_zero() {
+				// decrease account ref if zero position
+				frame_system::Pallet::<T>::dec_consumers(who);
+
+				// remove position storage if zero position
+				*may_be_position = None;
+			} else {
+				*may_be_position = Some(p);
+			}
+
+			Ok(())
+		})?;
+
+		TotalBasketPositions::<T>::try_mutate(|total_basket_positions| -> DispatchResult {
+			Self::apply_basket_adjustments(total_basket_positions, collateral_adjustments, debit_adjustment)
+		})
+	}
+
+	/// apply the adjustments to the basket position, the collateral will be
+	/// removed from the basket if its amount is zero.
+	fn apply_basket_adjustments(
+		position: &mut BasketPosition,
+		collateral_adjustments: &[(CurrencyId, Amount)],
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		for (currency_id, collateral_adjustment) in collateral_adjustments {
+			let collateral_balance = Self::balance_try_from_amount_abs(*collateral_adjustment)?;
+			let collateral = position.collaterals.get(currency_id).copied().unwrap_or_default();
+			let new_collateral = if collateral_adjustment.is_positive() {
+				collateral
+					.checked_add(collateral_balance)
+					.ok_or(ArithmeticError::Overflow)
+			} else {
+				collateral
+					.checked_sub(collateral_balance)
+					.ok_or(ArithmeticError::Underflow)
+			}?;
+
+			if new_collateral.is_zero() {
+				position.collaterals.remove(currency_id);
+			} else {
+				position.collaterals.insert(*currency_id, new_collateral);
+			}
+		}
+
+		let debit_balance = Self::balance_try_from_amount_abs(debit_adjustment)?;
+		position.debit = if debit_adjustment.is_positive() {
+			position
+				.debit
+				.checked_add(debit_balance)
+				.ok_or(ArithmeticError::Overflow)
+		} else {
+			position
+				.debit
+				.checked_sub(debit_balance)
+				.ok_or(ArithmeticError::Underflow)
+		}?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -36,7 +36,7 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 use std::collections::HashMap;
-use support::{AuctionManager, RiskManager};
+use support::{AuctionManager, Rate, RiskManager};
 
 pub type AccountId = u128;
 pub type AuctionId = u32;
@@ -76,7 +76,7 @@ impl frame_system::Config for Runtime {
 	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type BaseCallFilter = Everything;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
@@ -145,6 +145,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_dutch_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -156,6 +173,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 ord_parameter_types! {
@@ -197,6 +222,10 @@ impl RiskManager<AccountId, CurrencyId, Balance, Balance> for MockRiskManager {
 		MockConvert::convert((currency_id, debit_balance))
 	}
 
+	fn get_liquidation_penalty(_currency_id: CurrencyId) -> Rate {
+		Rate::zero()
+	}
+
 	fn check_position_valid(
 		currency_id: CurrencyId,
 		_collateral_balance: Balance,
@@ -225,6 +254,35 @@ impl RiskManager<AccountId, CurrencyId, Balance, Balance> for MockRiskManager {
 			(_, _) => Ok(()),
 		}
 	}
+
+	fn get_basket_debit_value(debit_balance: Balance) -> Balance {
+		debit_balance / Balance::from(2u64)
+	}
+
+	fn check_basket_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: Balance,
+		check_required_ratio: bool,
+	) -> DispatchResult {
+		if collaterals.iter().all(|(currency_id, _)| *currency_id == DOT) && !debit_balance.is_zero() {
+			if check_required_ratio {
+				Err(sp_runtime::DispatchError::Other(
+					"mock below required collateral ratio error",
+				))
+			} else {
+				Err(sp_runtime::DispatchError::Other("mock below liquidation ratio error"))
+			}
+		} else {
+			Ok(())
+		}
+	}
+
+	fn check_basket_debit_cap(total_debit_balance: Balance) -> DispatchResult {
+		match total_debit_balance {
+			1000 => Err(sp_runtime::DispatchError::Other("mock exceed debit value cap error")),
+			_ => Ok(()),
+		}
+	}
 }
 
 thread_local! {
@@ -204,6 +204,102 @@ fn confiscate_collateral_and_debit_work() {
 	});
 }
 
+#[test]
+fn redeem_position_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LoansModule::adjust_position(&ALICE, BTC, 500, 300));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 150);
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, AUSD, 100));
+
+		assert_ok!(LoansModule::redeem_position(&ALICE, &BOB, BTC, 200, 20, 200));
+		assert_eq!(LoansModule::positions(BTC, &ALICE).debit, 100);
+		assert_eq!(LoansModule::positions(BTC, &ALICE).collateral, 300);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 100);
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 300);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 1180);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 300);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(BTC), 20);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		System::assert_last_event(Event::LoansModule(crate::Event::PositionUpdated(
+			ALICE, BTC, -200, -200,
+		)));
+
+		// have no sufficient stable currency to repay
+		assert!(LoansModule::redeem_position(&ALICE, &BOB, BTC, 100, 0, 100).is_err());
+	});
+}
+
+#[test]
+fn sorted_positions_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![]);
+
+		// the ratio of alice is 2, bob is 3
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 200, 100));
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 300, 100));
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![ALICE, BOB]);
+		assert_eq!(LoansModule::sorted_positions(BTC, 1), vec![ALICE]);
+		assert_eq!(LoansModule::sorted_positions(DOT, 10), vec![]);
+		assert_eq!(
+			LoansModule::sorted_positions_from(BTC, Ratio::saturating_from_integer(2), 10),
+			vec![ALICE, BOB]
+		);
+		assert_eq!(
+			LoansModule::sorted_positions_from(BTC, Ratio::saturating_from_rational(5, 2), 10),
+			vec![BOB]
+		);
+		assert_eq!(
+			LoansModule::sorted_positions_from(BTC, Ratio::saturating_from_integer(4), 10),
+			vec![]
+		);
+
+		// the ratio of alice is 4
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 200, 0));
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![BOB, ALICE]);
+
+		// the position without debit is not indexed
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 0, -100));
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![ALICE]);
+
+		assert_ok!(LoansModule::transfer_loan(&ALICE, &BOB, BTC));
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![BOB]);
+	});
+}
+
+#[test]
+fn sorted_positions_should_be_indexed_over_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 200, 100));
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 300, 100));
+		assert_ok!(LoansModule::update_loan(&ALICE, DOT, 100, 0));
+		assert!(LoansModule::sorted_positions_indexed());
+
+		// the positions before the index existed
+		SortedPositions::<Runtime>::remove_prefix(BTC, None);
+		StorageVersion::new(0).put::<LoansModule>();
+		assert!(!LoansModule::sorted_positions_indexed());
+
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let weight = db_weight.reads(1) + 3 * db_weight.reads_writes(1, 1);
+		assert_eq!(LoansModule::on_idle(1, weight), weight);
+		assert!(!LoansModule::sorted_positions_indexed());
+		assert!(LastIndexedPositionKey::<Runtime>::get().is_some());
+
+		assert_eq!(
+			LoansModule::on_idle(2, weight),
+			db_weight.reads(1) + 2 * db_weight.reads_writes(1, 1)
+		);
+		assert!(LoansModule::sorted_positions_indexed());
+		assert_eq!(LastIndexedPositionKey::<Runtime>::get(), None);
+		assert_eq!(LoansModule::sorted_positions(BTC, 10), vec![ALICE, BOB]);
+		assert_eq!(LoansModule::sorted_positions(DOT, 10), vec![]);
+
+		assert_eq!(LoansModule::on_idle(3, weight), db_weight.reads(1));
+	});
+}
+
 #[test]
 fn loan_updated_updated_when_adjust_collateral() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -219,3 +315,138 @@ fn loan_updated_updated_when_adjust_collateral() {
 		assert_eq!(DOT_SHARES.with(|v| *v.borrow().get(&BOB).unwrap_or(&0)), 200);
 	});
 }
+
+#[test]
+fn adjust_basket_position_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// balance too low
+		assert_noop!(
+			LoansModule::adjust_basket_position(&ALICE, &[(BTC, 2000)], 0),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		// mock can't pass required ratio check
+		assert_noop!(
+			LoansModule::adjust_basket_position(&ALICE, &[(DOT, 500)], 1),
+			sp_runtime::DispatchError::Other("mock below required collateral ratio error")
+		);
+
+		// mock exceed debit value cap
+		assert_noop!(
+			LoansModule::adjust_basket_position(&ALICE, &[(BTC, 500), (DOT, 500)], 1000),
+			sp_runtime::DispatchError::Other("mock exceed debit value cap error")
+		);
+
+		// success
+		assert_ok!(LoansModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 500), (DOT, 200)],
+			300
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 500);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 800);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 500);
+		assert_eq!(Currencies::free_balance(DOT, &LoansModule::account_id()), 200);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 150);
+		assert_eq!(
+			LoansModule::basket_positions(&ALICE).collaterals,
+			vec![(DOT, 200), (BTC, 500)].into_iter().collect()
+		);
+		assert_eq!(LoansModule::basket_positions(&ALICE).debit, 300);
+		assert_eq!(
+			LoansModule::total_basket_positions(),
+			LoansModule::basket_positions(&ALICE)
+		);
+		assert_eq!(LoansModule::positions(BTC, &ALICE), Default::default());
+		assert_eq!(LoansModule::total_positions(BTC), Default::default());
+		System::assert_last_event(Event::LoansModule(crate::Event::BasketPositionUpdated(
+			ALICE,
+			vec![(BTC, 500), (DOT, 200)],
+			300,
+		)));
+
+		// mock can't pass required ratio check after withdraw all BTC
+		assert_noop!(
+			LoansModule::adjust_basket_position(&ALICE, &[(BTC, -500)], 0),
+			sp_runtime::DispatchError::Other("mock below required collateral ratio error")
+		);
+
+		assert_ok!(LoansModule::adjust_basket_position(&ALICE, &[(BTC, -400)], 0));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(
+			LoansModule::basket_positions(&ALICE).collaterals,
+			vec![(DOT, 200), (BTC, 100)].into_iter().collect()
+		);
+
+		// close the basket position
+		let alice_ref_count = System::consumers(&ALICE);
+		assert_ok!(LoansModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, -100), (DOT, -200)],
+			-300
+		));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1000);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1000);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
+		assert!(!<BasketPositions<Runtime>>::contains_key(&ALICE));
+		assert_eq!(LoansModule::total_basket_positions(), Default::default());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count - 1);
+	});
+}
+
+#[test]
+fn check_update_basket_loan_underflow_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// collateral underflow
+		assert_noop!(
+			LoansModule::update_basket_loan(&ALICE, &[(BTC, -100)], 0),
+			ArithmeticError::Underflow,
+		);
+
+		// debit underflow
+		assert_noop!(
+			LoansModule::update_basket_loan(&ALICE, &[], -100),
+			ArithmeticError::Underflow,
+		);
+	});
+}
+
+#[test]
+fn confiscate_basket_collaterals_and_debit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LoansModule::adjust_basket_position(
+			&ALICE,
+			&[(BTC, 500), (DOT, 200)],
+			300
+		));
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(BTC), 0);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(DOT), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+
+		assert_ok!(LoansModule::confiscate_basket_collaterals_and_debit(
+			&ALICE,
+			&[(BTC, 300), (DOT, 200)],
+			200
+		));
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(BTC), 300);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(DOT), 200);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(
+			LoansModule::basket_positions(&ALICE).collaterals,
+			vec![(BTC, 200)].into_iter().collect()
+		);
+		assert_eq!(LoansModule::basket_positions(&ALICE).debit, 100);
+		assert_eq!(
+			LoansModule::total_basket_positions(),
+			LoansModule::basket_positions(&ALICE)
+		);
+		System::assert_last_event(Event::LoansModule(crate::Event::ConfiscateBasketCollateralsAndDebit(
+			ALICE,
+			vec![(BTC, 300), (DOT, 200)],
+			200,
+		)));
+	});
+}
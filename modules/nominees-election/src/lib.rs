@@ -35,7 +35,7 @@ use sp_runtime::{
 	RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
-use support::{NomineesProvider, OnNewEra};
+use support::{NomineeVotesProvider, NomineesProvider, OnNewEra};
 
 mod mock;
 mod tests;
@@ -444,6 +444,12 @@ impl<T: Config<I>, I: 'static> NomineesProvider<T::NomineeId> for Pallet<T, I> {
 	}
 }
 
+impl<T: Config<I>, I: 'static> NomineeVotesProvider<T::NomineeId, Balance> for Pallet<T, I> {
+	fn votes(nominee: &T::NomineeId) -> Balance {
+		Votes::<T, I>::get(nominee)
+	}
+}
+
 impl<T: Config<I>, I: 'static> OnNewEra<EraIndex> for Pallet<T, I> {
 	fn on_new_era(era: EraIndex) {
 		CurrentEra::<T, I>::put(era);
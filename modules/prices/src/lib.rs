@@ -24,7 +24,8 @@
 //! process and feed prices for Acala. Process include:
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
-//!   - lock/unlock the price data get from oracle
+//!   - lock/unlock the price data get from oracle, the locked price of a frozen collateral can not
+//!     be changed until it's unfrozen
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -36,7 +37,9 @@ use primitives::{Balance, CurrencyId};
 use sp_core::U256;
 use sp_runtime::{traits::CheckedMul, FixedPointNumber};
 use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{DEXManager, Erc20InfoMapping, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use support::{
+	CollateralFreeze, DEXManager, Erc20InfoMapping, ExchangeRateProvider, LockablePrice, Price, PriceProvider,
+};
 
 mod mock;
 mod tests;
@@ -88,6 +91,9 @@ pub mod module {
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20.
 		type Erc20InfoMapping: Erc20InfoMapping;
 
+		/// The freeze state of collateral types.
+		type CollateralFreeze: CollateralFreeze<CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -98,6 +104,8 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The collateral is frozen
+		CollateralFrozen,
 	}
 
 	#[pallet::event]
@@ -198,6 +206,10 @@ impl<T: Config> Pallet<T> {
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	/// Record the real-time price from oracle as the locked price
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult {
+		ensure!(
+			!T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
 		let price = Self::access_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
 		LockedPrice::<T>::insert(currency_id, price);
 		Pallet::<T>::deposit_event(Event::LockPrice(currency_id, price));
@@ -206,6 +218,10 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 
 	/// Unlock the locked price
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult {
+		ensure!(
+			!T::CollateralFreeze::is_frozen(currency_id),
+			Error::<T>::CollateralFrozen
+		);
 		let _ = LockedPrice::<T>::take(currency_id).ok_or(Error::<T>::NoLockedPrice)?;
 		Pallet::<T>::deposit_event(Event::UnlockPrice(currency_id));
 		Ok(())
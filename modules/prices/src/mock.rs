@@ -85,6 +85,7 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static FROZEN: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_oracle_update() {
@@ -122,6 +123,17 @@ impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	}
 }
 
+pub fn mock_freeze_collateral(currency_id: CurrencyId) {
+	FROZEN.with(|v| v.borrow_mut().push(currency_id))
+}
+
+pub struct MockCollateralFreeze;
+impl CollateralFreeze<CurrencyId> for MockCollateralFreeze {
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		FROZEN.with(|v| v.borrow().contains(&currency_id))
+	}
+}
+
 pub struct MockLiquidStakingExchangeProvider;
 impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
 	fn get_exchange_rate() -> ExchangeRate {
@@ -154,6 +166,26 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_limit_orders(_who: &AccountId) -> Vec<(u64, CurrencyId, CurrencyId, Balance, Balance)> {
+		unimplemented!()
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -238,6 +270,7 @@ impl Config for Runtime {
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type Erc20InfoMapping = MockErc20InfoMapping;
+	type CollateralFreeze = MockCollateralFreeze;
 	type WeightInfo = ();
 }
 
@@ -307,6 +307,28 @@ fn unlock_price_work() {
 	});
 }
 
+#[test]
+fn lock_and_unlock_price_of_frozen_collateral_failed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), BTC));
+		mock_freeze_collateral(BTC);
+		mock_freeze_collateral(DOT);
+
+		assert_noop!(
+			PricesModule::lock_price(Origin::signed(1), DOT),
+			Error::<Runtime>::CollateralFrozen
+		);
+		assert_noop!(
+			PricesModule::unlock_price(Origin::signed(1), BTC),
+			Error::<Runtime>::CollateralFrozen
+		);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(BTC),
+			Some(Price::saturating_from_integer(500000000000000u128))
+		);
+	});
+}
+
 #[test]
 fn price_providers_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -51,9 +51,11 @@ pub enum UtilityCall<RelayChainCall> {
 }
 
 #[derive(Encode, Decode, RuntimeDebug)]
-pub enum StakingCall {
+pub enum StakingCall<T: Config> {
 	#[codec(index = 3)]
 	WithdrawUnbonded(u32),
+	#[codec(index = 5)]
+	Nominate(Vec<<T::Lookup as StaticLookup>::Source>),
 }
 
 #[cfg(feature = "kusama")]
@@ -67,7 +69,7 @@ mod kusama {
 		#[codec(index = 4)]
 		Balances(BalancesCall<T>),
 		#[codec(index = 6)]
-		Staking(StakingCall),
+		Staking(StakingCall<T>),
 		#[codec(index = 24)]
 		Utility(Box<UtilityCall<Self>>),
 	}
@@ -84,7 +86,7 @@ mod polkadot {
 		#[codec(index = 5)]
 		Balances(BalancesCall<T>),
 		#[codec(index = 7)]
-		Staking(StakingCall),
+		Staking(StakingCall<T>),
 		#[codec(index = 26)]
 		Utility(Box<UtilityCall<Self>>),
 	}
@@ -119,6 +121,12 @@ where
 		RelayChainCall::Staking(StakingCall::WithdrawUnbonded(num_slashing_spans))
 	}
 
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelayChainCall {
+		RelayChainCall::Staking(StakingCall::Nominate(
+			targets.into_iter().map(T::Lookup::unlookup).collect(),
+		))
+	}
+
 	fn balances_transfer_keep_alive(to: Self::AccountId, amount: Self::Balance) -> Self::RelayChainCall {
 		RelayChainCall::Balances(BalancesCall::TransferKeepAlive(T::Lookup::unlookup(to), amount))
 	}
@@ -0,0 +1,306 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Stability Pool Module
+//!
+//! ## Overview
+//!
+//! Users deposit stable currency into the stability pool to absorb the bad debt of unsafe CDPs
+//! immediately when they are liquidated. The pooled stable currency is paid to CDP treasury to
+//! burn the debit of the liquidated CDP at once, and the confiscated collateral is distributed to
+//! depositors pro-rata to their shares of the pool, which are claimable through incentives module.
+//!
+//! Every depositor owns shares of the pool, the stable currency deposit of depositor decreases
+//! proportionally when liquidations are absorbed by the pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, Zero},
+	ArithmeticError, DispatchResult, FixedPointNumber,
+};
+use support::{CDPTreasury, EmergencyShutdown, LiquidationStrategy, PriceProvider, StabilityPoolIncentives};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency for transfer assets
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The stable currency id
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// CDP treasury to pay stable currency and receive collateral
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// The price source to value the collateral absorbed by the pool
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// Incentives to record the shares and distribute the collateral gain of depositors
+		type Incentives: StabilityPoolIncentives<Self::AccountId, CurrencyId, Balance>;
+
+		/// The minimum stable currency amount a depositor must keep in the pool
+		#[pallet::constant]
+		type MinimumDeposit: Get<Balance>;
+
+		/// Emergency shutdown.
+		type EmergencyShutdown: EmergencyShutdown;
+
+		/// The stability pool's module id, keep the deposited stable currency.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The deposit of the depositor is below the minimum deposit
+		BelowMinimumDeposit,
+		/// The depositor has no deposit in the pool
+		NoDeposit,
+		/// The deposit of the pool is not enough to absorb the liquidation
+		InsufficientPoolDeposit,
+		/// The system has been shutdown
+		AlreadyShutdown,
+		/// The feed price of the collateral is invalid
+		InvalidFeedPrice,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Deposit stable currency into the pool. \[who, deposit_amount, share_amount\]
+		Deposit(T::AccountId, Balance, Balance),
+		/// Withdraw stable currency from the pool. \[who, withdraw_amount, share_amount\]
+		Withdraw(T::AccountId, Balance, Balance),
+		/// The liquidation of CDP is absorbed by the pool. \[owner, collateral_type,
+		/// collateral_amount, stable_amount, refund_collateral_amount\]
+		LiquidationAbsorbed(T::AccountId, CurrencyId, Balance, Balance, Balance),
+	}
+
+	/// The total stable currency deposit of the pool.
+	///
+	/// TotalDeposit: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_deposit)]
+	pub type TotalDeposit<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The total shares of the pool.
+	///
+	/// TotalShares: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_shares)]
+	pub type TotalShares<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The shares of depositors.
+	///
+	/// Shares: map AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn shares)]
+	pub type Shares<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposit stable currency into the stability pool.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `amount`: the stable currency amount to deposit.
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::do_deposit(&who, amount)
+		}
+
+		/// Withdraw stable currency from the stability pool. If the remaining deposit is below
+		/// `MinimumDeposit`, the whole deposit will be withdrawn.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `amount`: the stable currency amount to withdraw.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw(&who, amount)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// The stable currency deposit of `who` in the pool.
+	pub fn deposit_of(who: &T::AccountId) -> Balance {
+		let total_shares = Self::total_shares();
+		if total_shares.is_zero() {
+			return Zero::zero();
+		}
+		multiply_by_rational(Self::shares(who), Self::total_deposit(), total_shares).unwrap_or_default()
+	}
+
+	fn do_deposit(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		let total_deposit = Self::total_deposit();
+		let total_shares = Self::total_shares();
+		let share_amount = if total_shares.is_zero() {
+			amount
+		} else {
+			multiply_by_rational(amount, total_shares, total_deposit).map_err(|_| ArithmeticError::Overflow)?
+		};
+		ensure!(!share_amount.is_zero(), Error::<T>::BelowMinimumDeposit);
+
+		T::Currency::transfer(T::GetStableCurrencyId::get(), who, &Self::account_id(), amount)?;
+		TotalDeposit::<T>::put(total_deposit.checked_add(amount).ok_or(ArithmeticError::Overflow)?);
+		TotalShares::<T>::put(
+			total_shares
+				.checked_add(share_amount)
+				.ok_or(ArithmeticError::Overflow)?,
+		);
+		let shares = Shares::<T>::try_mutate(who, |shares| -> Result<Balance, DispatchError> {
+			*shares = shares.checked_add(share_amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(*shares)
+		})?;
+		ensure!(
+			Self::deposit_of(who) >= T::MinimumDeposit::get(),
+			Error::<T>::BelowMinimumDeposit
+		);
+
+		T::Incentives::set_stability_pool_share(who, shares);
+		Self::deposit_event(Event::Deposit(who.clone(), amount, share_amount));
+		Ok(())
+	}
+
+	fn do_withdraw(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		let shares = Self::shares(who);
+		ensure!(!shares.is_zero(), Error::<T>::NoDeposit);
+
+		let total_deposit = Self::total_deposit();
+		let total_shares = Self::total_shares();
+		let deposit = Self::deposit_of(who);
+
+		// withdraw the whole deposit if the remaining is below the minimum deposit
+		let (withdraw_amount, share_amount) =
+			if amount >= deposit || deposit.saturating_sub(amount) < T::MinimumDeposit::get() {
+				(deposit, shares)
+			} else {
+				// round the shares up, in favor of the pool
+				let mut share_amount =
+					multiply_by_rational(amount, total_shares, total_deposit).map_err(|_| ArithmeticError::Overflow)?;
+				if multiply_by_rational(share_amount, total_deposit, total_shares).unwrap_or_default() < amount {
+					share_amount = share_amount.saturating_add(1);
+				}
+				(amount, share_amount.min(shares))
+			};
+
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), who, withdraw_amount)?;
+		TotalDeposit::<T>::put(total_deposit.saturating_sub(withdraw_amount));
+		TotalShares::<T>::put(total_shares.saturating_sub(share_amount));
+		let remaining_shares = shares.saturating_sub(share_amount);
+		if remaining_shares.is_zero() {
+			Shares::<T>::remove(who);
+		} else {
+			Shares::<T>::insert(who, remaining_shares);
+		}
+
+		T::Incentives::set_stability_pool_share(who, remaining_shares);
+		Self::deposit_event(Event::Withdraw(who.clone(), withdraw_amount, share_amount));
+		Ok(())
+	}
+}
+
+impl<T: Config> LiquidationStrategy<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	type Params = ();
+	type Outcome = ();
+
+	/// Absorb the liquidation by paying `target_stable_amount` of pooled stable currency to CDP
+	/// treasury. `target_stable_amount` already includes the liquidation penalty, the pool takes
+	/// the collateral worth it, which is distributed to depositors, and the remain collateral is
+	/// refunded to `who`.
+	#[transactional]
+	fn liquidate(
+		_params: (),
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		// the pool must keep at least the minimum deposit for the remaining shares
+		let total_deposit = Self::total_deposit();
+		let remaining_deposit = total_deposit
+			.checked_sub(target_stable_amount)
+			.ok_or(Error::<T>::InsufficientPoolDeposit)?;
+		ensure!(
+			remaining_deposit >= T::MinimumDeposit::get(),
+			Error::<T>::InsufficientPoolDeposit
+		);
+
+		let price = T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let collateral_gain = price.saturating_mul_int(target_stable_amount).min(amount);
+		let refund_amount = amount.saturating_sub(collateral_gain);
+
+		let pool_account = Self::account_id();
+		T::CDPTreasury::withdraw_collateral(&pool_account, currency_id, collateral_gain)?;
+		if !refund_amount.is_zero() {
+			T::CDPTreasury::withdraw_collateral(who, currency_id, refund_amount)?;
+		}
+		T::CDPTreasury::deposit_surplus(&pool_account, target_stable_amount)?;
+		TotalDeposit::<T>::put(remaining_deposit);
+		T::Incentives::distribute_collateral_gain(&pool_account, currency_id, collateral_gain)?;
+
+		Self::deposit_event(Event::LiquidationAbsorbed(
+			who.clone(),
+			currency_id,
+			collateral_gain,
+			target_stable_amount,
+			refund_amount,
+		));
+		Ok(())
+	}
+}
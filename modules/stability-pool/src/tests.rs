@@ -0,0 +1,189 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the stability pool module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use support::Price;
+
+#[test]
+fn deposit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			StabilityPoolModule::deposit(Origin::signed(ALICE), 5),
+			Error::<Runtime>::BelowMinimumDeposit
+		);
+		assert_noop!(
+			StabilityPoolModule::deposit(Origin::signed(ALICE), 1001),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 100));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Deposit(ALICE, 100, 100)));
+		assert_eq!(StabilityPoolModule::total_deposit(), 100);
+		assert_eq!(StabilityPoolModule::total_shares(), 100);
+		assert_eq!(StabilityPoolModule::shares(ALICE), 100);
+		assert_eq!(StabilityPoolModule::deposit_of(&ALICE), 100);
+		assert_eq!(MockIncentives::shares(&ALICE), 100);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(AUSD, &StabilityPoolModule::account_id()), 100);
+
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 50));
+		assert_eq!(StabilityPoolModule::total_deposit(), 150);
+		assert_eq!(StabilityPoolModule::total_shares(), 150);
+		assert_eq!(MockIncentives::shares(&BOB), 50);
+
+		mock_shutdown();
+		assert_noop!(
+			StabilityPoolModule::deposit(Origin::signed(ALICE), 100),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
+#[test]
+fn withdraw_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			StabilityPoolModule::withdraw(Origin::signed(ALICE), 100),
+			Error::<Runtime>::NoDeposit
+		);
+
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 100));
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(ALICE), 40));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Withdraw(ALICE, 40, 40)));
+		assert_eq!(StabilityPoolModule::deposit_of(&ALICE), 60);
+		assert_eq!(StabilityPoolModule::total_deposit(), 60);
+		assert_eq!(StabilityPoolModule::total_shares(), 60);
+		assert_eq!(MockIncentives::shares(&ALICE), 60);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 940);
+
+		// the remaining deposit is below the minimum deposit, withdraw all
+		mock_shutdown();
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(ALICE), 55));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Withdraw(ALICE, 60, 60)));
+		assert_eq!(StabilityPoolModule::shares(ALICE), 0);
+		assert_eq!(StabilityPoolModule::total_deposit(), 0);
+		assert_eq!(StabilityPoolModule::total_shares(), 0);
+		assert_eq!(MockIncentives::shares(&ALICE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1000);
+	});
+}
+
+#[test]
+fn liquidate_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 300));
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 100));
+
+		assert_noop!(
+			<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate((), &CAROL, BTC, 100, 500),
+			Error::<Runtime>::InsufficientPoolDeposit
+		);
+		assert_noop!(
+			<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate((), &CAROL, BTC, 100, 395),
+			Error::<Runtime>::InsufficientPoolDeposit
+		);
+
+		BtcPrice::set(None);
+		assert_noop!(
+			<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate((), &CAROL, BTC, 100, 200),
+			Error::<Runtime>::InvalidFeedPrice
+		);
+		BtcPrice::set(Some(Price::saturating_from_integer(10)));
+
+		// the target 200 already includes the liquidation penalty, the pool takes the collateral
+		// worth 200, the remain is refunded to CAROL
+		assert_ok!(<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate(
+			(),
+			&CAROL,
+			BTC,
+			100,
+			200
+		));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::LiquidationAbsorbed(
+			CAROL, BTC, 20, 200, 80,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 200);
+		assert_eq!(Tokens::free_balance(BTC, &CDP_TREASURY), 900);
+		assert_eq!(Tokens::free_balance(BTC, &INCENTIVES), 20);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 80);
+		assert_eq!(Tokens::free_balance(AUSD, &StabilityPoolModule::account_id()), 200);
+		assert_eq!(StabilityPoolModule::total_deposit(), 200);
+		assert_eq!(StabilityPoolModule::total_shares(), 400);
+		assert_eq!(StabilityPoolModule::deposit_of(&ALICE), 150);
+		assert_eq!(StabilityPoolModule::deposit_of(&BOB), 50);
+
+		// new depositor gets the shares at the current share price
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(CAROL), 100));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Deposit(CAROL, 100, 200)));
+		assert_eq!(StabilityPoolModule::deposit_of(&CAROL), 100);
+		assert_eq!(MockIncentives::shares(&CAROL), 200);
+
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(ALICE), 1000));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Withdraw(ALICE, 150, 300)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 850);
+		assert_eq!(StabilityPoolModule::total_deposit(), 150);
+		assert_eq!(StabilityPoolModule::total_shares(), 300);
+
+		// the collateral is not enough, the pool takes all of it
+		assert_ok!(<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate(
+			(),
+			&CAROL,
+			BTC,
+			10,
+			100
+		));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::LiquidationAbsorbed(
+			CAROL, BTC, 10, 100, 0,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &INCENTIVES), 30);
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 80);
+	});
+}
+
+#[test]
+fn liquidate_charges_liquidation_penalty_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 300));
+
+		// CDP engine passes the debit value 100 plus 10% liquidation penalty, the owner loses
+		// the collateral worth 110 as other liquidation strategies, and gets the remain refunded
+		assert_ok!(<StabilityPoolModule as LiquidationStrategy<_, _, _>>::liquidate(
+			(),
+			&CAROL,
+			BTC,
+			100,
+			110
+		));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::LiquidationAbsorbed(
+			CAROL, BTC, 11, 110, 89,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 89);
+		assert_eq!(Tokens::free_balance(BTC, &INCENTIVES), 11);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 110);
+	});
+}
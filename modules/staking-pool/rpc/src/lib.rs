@@ -21,7 +21,7 @@
 use codec::Codec;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use module_staking_pool_rpc_runtime_api::BalanceInfo;
+use module_staking_pool_rpc_runtime_api::{BalanceInfo, SlashCompensationInfo};
 use module_support::ExchangeRate;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
@@ -35,12 +35,15 @@ pub use self::gen_client::Client as StakingPoolClient;
 pub use module_staking_pool_rpc_runtime_api::StakingPoolApi as StakingPoolRuntimeApi;
 
 #[rpc]
-pub trait StakingPoolApi<BlockHash, AccountId, ResponseType> {
+pub trait StakingPoolApi<BlockHash, AccountId, ResponseType, SlashCompensationType> {
 	#[rpc(name = "stakingPool_getAvailableUnbonded")]
 	fn get_available_unbonded(&self, account: AccountId, at: Option<BlockHash>) -> Result<ResponseType>;
 
 	#[rpc(name = "stakingPool_getLiquidStakingExchangeRate")]
 	fn get_liquid_staking_exchange_rate(&self, at: Option<BlockHash>) -> Result<ExchangeRate>;
+
+	#[rpc(name = "stakingPool_getSlashCompensation")]
+	fn get_slash_compensation(&self, era: u32, at: Option<BlockHash>) -> Result<SlashCompensationType>;
 }
 
 /// A struct that implements the [`StakingPoolApi`].
@@ -71,7 +74,8 @@ impl From<Error> for i64 {
 	}
 }
 
-impl<C, Block, AccountId, Balance> StakingPoolApi<<Block as BlockT>::Hash, AccountId, BalanceInfo<Balance>>
+impl<C, Block, AccountId, Balance>
+	StakingPoolApi<<Block as BlockT>::Hash, AccountId, BalanceInfo<Balance>, SlashCompensationInfo<Balance>>
 	for StakingPool<C, Block>
 where
 	Block: BlockT,
@@ -111,4 +115,22 @@ where
 			data: Some(format!("{:?}", e).into()),
 		})
 	}
+
+	fn get_slash_compensation(
+		&self,
+		era: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<SlashCompensationInfo<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_slash_compensation(&at, era).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get slash compensation.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
 }
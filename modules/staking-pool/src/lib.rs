@@ -19,7 +19,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::{Change, Happened, MultiCurrency};
 use primitives::{Balance, CurrencyId, EraIndex};
@@ -32,8 +32,8 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	ExchangeRate, HomaProtocol, NomineesProvider, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState,
-	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, Rate, Ratio,
+	DEXManager, ExchangeRate, HomaProtocol, NomineesProvider, OnNewEra, PolkadotBridge, PolkadotBridgeCall,
+	PolkadotBridgeState, PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, Rate, Ratio,
 };
 
 mod mock;
@@ -124,6 +124,18 @@ impl Ledger {
 	}
 }
 
+/// The compensation to liquid currency holders from slashed validator insurance in an era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, Default, TypeInfo)]
+pub struct SlashCompensation {
+	/// The amount of liquid currency confiscated from validator insurance.
+	pub confiscated_liquid: Balance,
+	/// The amount of staking currency swapped from the confiscated liquid currency and
+	/// credited to the staking pool.
+	pub compensated_staking: Balance,
+	/// The amount of confiscated liquid currency burned because it could not be swapped.
+	pub burned_liquid: Balance,
+}
+
 /// Fee rate calculater.
 pub trait FeeModel<Balance> {
 	fn get_fee(
@@ -187,6 +199,14 @@ pub mod module {
 
 		/// The currency for managing assets related to Homa protocol.
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// DEX to swap the confiscated validator insurance to staking currency.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The maximum slippage, compared to the liquid exchange rate, when swapping the
+		/// confiscated validator insurance to staking currency.
+		#[pallet::constant]
+		type MaxSlashCompensationSlippage: Get<Ratio>;
 	}
 
 	#[pallet::error]
@@ -220,6 +240,13 @@ pub mod module {
 		/// the unbonding_to_free of specific era. \[who, target_era,
 		/// fee_in_staking, liquid_amount_burned, staking_amount_redeemed\]
 		RedeemByClaimUnbonding(T::AccountId, EraIndex, Balance, Balance, Balance),
+		/// Compensate liquid currency(LDOT) holders with the slashed validator insurance.
+		/// \[era, liquid_amount_confiscated, staking_amount_compensated,
+		/// liquid_amount_burned\]
+		SlashCompensated(EraIndex, Balance, Balance, Balance),
+		/// Failed to compensate liquid currency(LDOT) holders with the slashed validator
+		/// insurance, which is kept by the source account. \[source, liquid_amount\]
+		SlashCompensationFailed(T::AccountId, Balance),
 	}
 
 	/// Current era index on Relaychain.
@@ -269,6 +296,13 @@ pub mod module {
 	#[pallet::getter(fn rebalance_phase)]
 	pub type RebalancePhase<T: Config> = StorageValue<_, Phase, ValueQuery>;
 
+	/// The records of slash compensation by era.
+	///
+	/// SlashCompensations: map EraIndex => SlashCompensation
+	#[pallet::storage]
+	#[pallet::getter(fn slash_compensations)]
+	pub type SlashCompensations<T: Config> = StorageMap<_, Twox64Concat, EraIndex, SlashCompensation, ValueQuery>;
+
 	/// The params of staking pool.
 	///
 	/// StakingPoolParams: Params
@@ -589,6 +623,56 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Compensate liquid currency holders with the liquid currency confiscated from validator
+	/// insurance, which is transferred from `source` to the module account. The liquid currency
+	/// is swapped to staking currency by DEX and added to the free pool, or burned if the swap
+	/// fails. Both raise the liquid exchange rate.
+	#[transactional]
+	pub fn compensate_slash(source: &T::AccountId, liquid_amount: Balance) -> DispatchResult {
+		if liquid_amount.is_zero() {
+			return Ok(());
+		}
+
+		let module_account = Self::account_id();
+		if *source != module_account {
+			T::Currency::transfer(T::LiquidCurrencyId::get(), source, &module_account, liquid_amount)?;
+		}
+		let expected_staking_amount = Self::liquid_exchange_rate().saturating_mul_int(liquid_amount);
+		let min_target_amount = expected_staking_amount
+			.saturating_sub(T::MaxSlashCompensationSlippage::get().saturating_mul_int(expected_staking_amount));
+		let (compensated_staking, burned_liquid) = match T::DEX::swap_with_exact_supply(
+			&module_account,
+			&[T::LiquidCurrencyId::get(), T::StakingCurrencyId::get()],
+			liquid_amount,
+			min_target_amount,
+		) {
+			Ok(staking_amount) => {
+				StakingPoolLedger::<T>::mutate(|ledger| {
+					ledger.free_pool = ledger.free_pool.saturating_add(staking_amount);
+				});
+				(staking_amount, Zero::zero())
+			}
+			Err(_) => {
+				T::Currency::withdraw(T::LiquidCurrencyId::get(), &module_account, liquid_amount)?;
+				(Zero::zero(), liquid_amount)
+			}
+		};
+
+		let era = Self::current_era();
+		SlashCompensations::<T>::mutate(era, |compensation| {
+			compensation.confiscated_liquid = compensation.confiscated_liquid.saturating_add(liquid_amount);
+			compensation.compensated_staking = compensation.compensated_staking.saturating_add(compensated_staking);
+			compensation.burned_liquid = compensation.burned_liquid.saturating_add(burned_liquid);
+		});
+		Self::deposit_event(Event::SlashCompensated(
+			era,
+			liquid_amount,
+			compensated_staking,
+			burned_liquid,
+		));
+		Ok(())
+	}
+
 	/// Get how much available unbonded of `who` in current era.
 	pub fn get_available_unbonded(who: &T::AccountId) -> Balance {
 		Unbondings::<T>::iter_prefix(who)
@@ -978,9 +1062,15 @@ impl<T: Config> HomaProtocol<T::AccountId, Balance, EraIndex> for Pallet<T> {
 }
 
 pub struct OnSlash<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> Happened<Balance> for OnSlash<T> {
-	fn happened(_amount: &Balance) {
-		// TODO: should reduce debit when homa_validator_list module burn
-		// insurance to compensate LDOT holders.
+impl<T: Config> Happened<(T::AccountId, Balance)> for OnSlash<T> {
+	fn happened((source, amount): &(T::AccountId, Balance)) {
+		if let Err(e) = Pallet::<T>::compensate_slash(source, *amount) {
+			log::warn!(
+				target: "staking-pool",
+				"compensate_slash: failed to compensate {:?} slashed insurance from {:?}: {:?}",
+				amount, source, e
+			);
+			Pallet::<T>::deposit_event(Event::SlashCompensationFailed(source.clone(), *amount));
+		}
 	}
 }
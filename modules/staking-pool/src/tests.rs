@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	BondingDuration, CurrenciesModule, Event, ExtBuilder, One, Origin, Runtime, StakingPoolModule, Status, System,
-	ALICE, BOB, BRIDGE_STATUS, DOT, LDOT,
+	BondingDuration, CurrenciesModule, Event, ExtBuilder, MockSwapRate, One, Origin, Runtime, StakingPoolModule,
+	Status, System, ALICE, BOB, BRIDGE_STATUS, DOT, LDOT,
 };
 use sp_runtime::traits::BadOrigin;
 
@@ -1378,3 +1378,72 @@ fn rebalance_work() {
 		assert_eq!(StakingPoolModule::unbondings(&ALICE, 13), 212);
 	});
 }
+
+#[test]
+fn compensate_slash_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		CurrentEra::<Runtime>::put(1);
+		StakingPoolLedger::<Runtime>::mutate(|ledger| ledger.free_pool = 1000);
+		assert_ok!(CurrenciesModule::deposit(LDOT, &ALICE, 9900));
+		assert_ok!(CurrenciesModule::deposit(LDOT, &StakingPoolModule::account_id(), 100));
+		assert_eq!(
+			StakingPoolModule::liquid_exchange_rate(),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+
+		// swap the confiscated LDOT to DOT by DEX
+		MockSwapRate::set(Some(Ratio::saturating_from_rational(1, 10)));
+		assert_ok!(StakingPoolModule::compensate_slash(
+			&StakingPoolModule::account_id(),
+			100
+		));
+		System::assert_last_event(Event::StakingPoolModule(crate::Event::SlashCompensated(1, 100, 10, 0)));
+		assert_eq!(StakingPoolModule::staking_pool_ledger().free_pool, 1010);
+		assert_eq!(
+			CurrenciesModule::free_balance(LDOT, &StakingPoolModule::account_id()),
+			0
+		);
+		assert_eq!(
+			CurrenciesModule::free_balance(DOT, &StakingPoolModule::account_id()),
+			10
+		);
+		assert_eq!(
+			StakingPoolModule::liquid_exchange_rate(),
+			ExchangeRate::saturating_from_rational(1010, 10000)
+		);
+
+		// burn the confiscated LDOT if the DEX price is beyond the max slippage
+		assert_ok!(CurrenciesModule::deposit(LDOT, &BOB, 100));
+		MockSwapRate::set(Some(Ratio::saturating_from_rational(5, 100)));
+		OnSlash::<Runtime>::happened(&(BOB, 100));
+		System::assert_last_event(Event::StakingPoolModule(crate::Event::SlashCompensated(1, 100, 0, 100)));
+		assert_eq!(StakingPoolModule::staking_pool_ledger().free_pool, 1010);
+		assert_eq!(CurrenciesModule::free_balance(LDOT, &BOB), 0);
+		assert_eq!(
+			CurrenciesModule::free_balance(LDOT, &StakingPoolModule::account_id()),
+			0
+		);
+		assert_eq!(CurrenciesModule::total_issuance(LDOT), 10000);
+		assert_eq!(
+			StakingPoolModule::liquid_exchange_rate(),
+			ExchangeRate::saturating_from_rational(1010, 10000)
+		);
+
+		// the confiscated LDOT is kept by the source account if the compensation fails
+		OnSlash::<Runtime>::happened(&(BOB, 100));
+		System::assert_last_event(Event::StakingPoolModule(crate::Event::SlashCompensationFailed(
+			BOB, 100,
+		)));
+
+		assert_eq!(
+			StakingPoolModule::slash_compensations(1),
+			SlashCompensation {
+				confiscated_liquid: 200,
+				compensated_staking: 10,
+				burned_liquid: 100,
+			}
+		);
+		assert_eq!(StakingPoolModule::slash_compensations(0), Default::default());
+	});
+}
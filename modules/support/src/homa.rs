@@ -32,6 +32,32 @@ pub trait NomineesProvider<AccountId> {
 	fn nominees() -> Vec<AccountId>;
 }
 
+pub trait NomineeVotesProvider<AccountId, Balance> {
+	fn votes(nominee: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Default> NomineeVotesProvider<AccountId, Balance> for () {
+	fn votes(_nominee: &AccountId) -> Balance {
+		Default::default()
+	}
+}
+
+/// Insurance status of a relaychain validator
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, Default)]
+pub struct ValidatorInsurance<Balance> {
+	/// Total insurance from all guarantors
+	pub total_insurance: Balance,
+	pub is_frozen: bool,
+	/// The number of slashes recorded for the validator
+	pub slash_count: u32,
+}
+
+pub trait ValidatorInsuranceProvider<AccountId, Balance> {
+	/// At most `max_validators` of the validators whose insurance reaches the insurance
+	/// threshold, the most insured first.
+	fn validators(max_validators: u32) -> Vec<(AccountId, ValidatorInsurance<Balance>)>;
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 pub struct PolkadotUnlockChunk<Balance, EraIndex> {
 	pub value: Balance,
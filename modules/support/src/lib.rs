@@ -43,8 +43,9 @@ use xcm::latest::prelude::*;
 pub mod homa;
 pub mod mocks;
 pub use homa::{
-	HomaProtocol, NomineesProvider, OnCommission, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState,
-	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk,
+	HomaProtocol, NomineeVotesProvider, NomineesProvider, OnCommission, OnNewEra, PolkadotBridge, PolkadotBridgeCall,
+	PolkadotBridgeState, PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, ValidatorInsurance,
+	ValidatorInsuranceProvider,
 };
 
 pub type Price = FixedU128;
@@ -55,6 +56,8 @@ pub type Rate = FixedU128;
 pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	fn get_bad_debt_value(currency_id: CurrencyId, debit_balance: DebitBalance) -> Balance;
 
+	fn get_liquidation_penalty(currency_id: CurrencyId) -> Rate;
+
 	fn check_position_valid(
 		currency_id: CurrencyId,
 		collateral_balance: Balance,
@@ -63,6 +66,16 @@ pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	) -> DispatchResult;
 
 	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: DebitBalance) -> DispatchResult;
+
+	fn get_basket_debit_value(debit_balance: DebitBalance) -> Balance;
+
+	fn check_basket_position_valid(
+		collaterals: &[(CurrencyId, Balance)],
+		debit_balance: DebitBalance,
+		check_required_ratio: bool,
+	) -> DispatchResult;
+
+	fn check_basket_debit_cap(total_debit_balance: DebitBalance) -> DispatchResult;
 }
 
 #[cfg(feature = "std")]
@@ -73,6 +86,10 @@ impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountI
 		Default::default()
 	}
 
+	fn get_liquidation_penalty(_currency_id: CurrencyId) -> Rate {
+		Default::default()
+	}
+
 	fn check_position_valid(
 		_currency_id: CurrencyId,
 		_collateral_balance: Balance,
@@ -85,6 +102,22 @@ impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountI
 	fn check_debit_cap(_currency_id: CurrencyId, _total_debit_balance: DebitBalance) -> DispatchResult {
 		Ok(())
 	}
+
+	fn get_basket_debit_value(_debit_balance: DebitBalance) -> Balance {
+		Default::default()
+	}
+
+	fn check_basket_position_valid(
+		_collaterals: &[(CurrencyId, Balance)],
+		_debit_balance: DebitBalance,
+		_check_required_ratio: bool,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_basket_debit_cap(_total_debit_balance: DebitBalance) -> DispatchResult {
+		Ok(())
+	}
 }
 
 pub trait AuctionManager<AccountId> {
@@ -98,9 +131,19 @@ pub trait AuctionManager<AccountId> {
 		amount: Self::Balance,
 		target: Self::Balance,
 	) -> DispatchResult;
+	fn new_dutch_collateral_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+	) -> DispatchResult;
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult;
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult;
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult;
 	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance;
 	fn get_total_target_in_auction() -> Self::Balance;
+	fn get_total_surplus_in_auction() -> Self::Balance;
+	fn get_total_debit_in_auction() -> Self::Balance;
 }
 
 pub trait DEXManager<AccountId, CurrencyId, Balance> {
@@ -112,6 +155,23 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 
 	fn get_swap_supply_amount(path: &[CurrencyId], target_amount: Balance) -> Option<Balance>;
 
+	fn get_best_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>>;
+
+	fn get_best_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Vec<CurrencyId>>;
+
+	/// Get open limit orders of `who`, returns a list of (order_id,
+	/// supply_currency_id, target_currency_id, supply_amount,
+	/// min_target_amount).
+	fn get_limit_orders(who: &AccountId) -> Vec<(u64, CurrencyId, CurrencyId, Balance, Balance)>;
+
 	fn swap_with_exact_supply(
 		who: &AccountId,
 		path: &[CurrencyId],
@@ -168,6 +228,26 @@ where
 		Some(Default::default())
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		None
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		None
+	}
+
+	fn get_limit_orders(_who: &AccountId) -> Vec<(u64, CurrencyId, CurrencyId, Balance, Balance)> {
+		vec![]
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -245,6 +325,9 @@ pub trait CDPTreasury<AccountId> {
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
 
+	/// withdraw surplus(stable currency) of cdp treasury to `to`
+	fn withdraw_surplus(to: &AccountId, surplus: Self::Balance) -> DispatchResult;
+
 	/// deposit collateral assets to cdp treasury by `who`
 	fn deposit_collateral(from: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
@@ -280,6 +363,41 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 	fn max_auction() -> u32;
 }
 
+/// A strategy to liquidate the collateral of unsafe CDP.
+pub trait LiquidationStrategy<AccountId, CurrencyId, Balance> {
+	/// The parameters of the strategy, such as the address of the
+	/// liquidation contract.
+	type Params;
+	/// The outcome of the liquidation, such as the count of created auctions.
+	type Outcome;
+
+	/// Liquidate `amount` of `currency_id` collateral held by CDP treasury,
+	/// which is confiscated from the CDP of `who`, to get
+	/// `target_stable_amount` stable currency for CDP treasury.
+	fn liquidate(
+		params: Self::Params,
+		who: &AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> Result<Self::Outcome, DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> LiquidationStrategy<AccountId, CurrencyId, Balance> for () {
+	type Params = ();
+	type Outcome = ();
+
+	fn liquidate(
+		_params: (),
+		_who: &AccountId,
+		_currency_id: CurrencyId,
+		_amount: Balance,
+		_target_stable_amount: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented liquidation strategy"))
+	}
+}
+
 pub trait PriceProvider<CurrencyId> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price>;
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
@@ -291,6 +409,13 @@ pub trait PriceProvider<CurrencyId> {
 	}
 }
 
+#[cfg(feature = "std")]
+impl<CurrencyId> PriceProvider<CurrencyId> for () {
+	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
+		None
+	}
+}
+
 pub trait LockablePrice<CurrencyId> {
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult;
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
@@ -304,6 +429,35 @@ pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
 }
 
+/// The freeze state of collateral types, a frozen collateral can not issue new
+/// debit or be liquidated, and its auctions are paused.
+pub trait CollateralFreeze<CurrencyId> {
+	fn is_frozen(currency_id: CurrencyId) -> bool;
+
+	/// The total blocks the collateral has been frozen for, including the
+	/// ongoing freeze. It's used to pause the clock of time-based processes.
+	fn frozen_blocks(_currency_id: CurrencyId) -> u32 {
+		0
+	}
+}
+
+#[cfg(feature = "std")]
+impl<CurrencyId> CollateralFreeze<CurrencyId> for () {
+	fn is_frozen(_currency_id: CurrencyId) -> bool {
+		false
+	}
+}
+
+/// Settle the CDPs after emergency shutdown.
+pub trait CDPSettlement<AccountId, CurrencyId> {
+	/// Settle the CDP of `who` which has debit, confiscate the collateral to
+	/// cover the debit at the locked price.
+	fn settle_cdp_has_debit(who: AccountId, currency_id: CurrencyId) -> DispatchResult;
+	/// Settle the basket CDP of `who` which has debit, confiscate the
+	/// collaterals by order to cover the debit at the locked prices.
+	fn settle_basket_cdp_has_debit(who: AccountId) -> DispatchResult;
+}
+
 pub trait DEXIncentives<AccountId, CurrencyId, Balance> {
 	fn do_deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
 	fn do_withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
@@ -320,6 +474,22 @@ impl<AccountId, CurrencyId, Balance> DEXIncentives<AccountId, CurrencyId, Balanc
 	}
 }
 
+pub trait StabilityPoolIncentives<AccountId, CurrencyId, Balance> {
+	/// Update the rewards share of `who` in the stability pool.
+	fn set_stability_pool_share(who: &AccountId, share_amount: Balance);
+	/// Distribute the collateral absorbed by the stability pool to the depositors as rewards.
+	fn distribute_collateral_gain(from: &AccountId, currency_id: CurrencyId, amount: Balance) -> DispatchResult;
+}
+
+#[cfg(feature = "std")]
+impl<AccountId, CurrencyId, Balance> StabilityPoolIncentives<AccountId, CurrencyId, Balance> for () {
+	fn set_stability_pool_share(_: &AccountId, _: Balance) {}
+
+	fn distribute_collateral_gain(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
 /// Return true if the call of EVM precompile contract is allowed.
 pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
@@ -409,6 +579,34 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	fn set_origin(_origin: AccountId) {}
 }
 
+/// An abstraction of liquidation contracts in EVM
+pub trait LiquidationEvmBridge<Balance> {
+	/// Execute liquidate(address,uint256,uint256) of the liquidation contract
+	/// with `gas_limit` to liquidate `amount` of `collateral` the contract
+	/// received, the contract must hold at least `min_repayment` stable
+	/// currency after that.
+	fn liquidate(
+		context: InvokeContext,
+		collateral: EvmAddress,
+		amount: Balance,
+		min_repayment: Balance,
+		gas_limit: u64,
+	) -> DispatchResult;
+}
+
+#[cfg(feature = "std")]
+impl<Balance> LiquidationEvmBridge<Balance> for () {
+	fn liquidate(
+		_context: InvokeContext,
+		_collateral: EvmAddress,
+		_amount: Balance,
+		_min_repayment: Balance,
+		_gas_limit: u64,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+}
+
 /// An abstraction of EVMStateRentTrait
 pub trait EVMStateRentTrait<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -602,6 +800,11 @@ pub trait CallBuilder {
 	/// - num_slashing_spans: The number of slashing spans to withdraw from.
 	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> Self::RelayChainCall;
 
+	/// Nominate validators on the relay-chain.
+	///  params:
+	/// - targets: The validators to be nominated.
+	fn staking_nominate(targets: Vec<Self::AccountId>) -> Self::RelayChainCall;
+
 	/// Transfer Staking currency to another account, disallowing "death".
 	///  params:
 	/// - to: The destination for the transfer
@@ -226,7 +226,8 @@ pub mod module {
 		#[pallet::constant]
 		type NativeCurrencyId: Get<CurrencyId>;
 
-		/// Default fee swap path list
+		/// Default fee swap path list. Only the supply currency of the path is
+		/// fixed, DEX will try to find a better trading path for it.
 		#[pallet::constant]
 		type DefaultFeeSwapPathList: Get<Vec<Vec<CurrencyId>>>;
 
@@ -259,6 +260,11 @@ pub mod module {
 		/// DEX to exchange currencies.
 		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
 
+		/// The weight of searching the best trading path by DEX and swapping
+		/// through it, which is charged for every default fee swap path tried.
+		#[pallet::constant]
+		type DefaultFeeSwapWeight: Get<Weight>;
+
 		/// When swap with DEX, the acceptable max slippage for the price from oracle.
 		#[pallet::constant]
 		type MaxSwapSlippageCompareToOracle: Get<Ratio>;
@@ -586,25 +592,56 @@ where
 
 		// native is not enough, try swap native to pay fee and gap
 		if !native_is_enough {
-			// add extra gap to keep alive after swap
-			let amount = fee.saturating_add(native_existential_deposit.saturating_sub(total_native));
 			let native_currency_id = T::NativeCurrencyId::get();
-			let default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
-			let fee_swap_path_list: Vec<Vec<CurrencyId>> =
-				if let Some(trading_path) = AlternativeFeeSwapPath::<T>::get(who) {
-					vec![vec![trading_path.into_inner()], default_fee_swap_path_list].concat()
-				} else {
-					default_fee_swap_path_list
-				};
-
-			for trading_path in fee_swap_path_list {
-				match trading_path.last() {
-					Some(target_currency_id) if *target_currency_id == native_currency_id => {
-						let supply_currency_id = *trading_path.first().expect("these's first guaranteed by match");
+			// the alternative fee swap path is used as it is, the default fee swap paths decide which
+			// currencies can be supplied and swap through the best trading path found by DEX if
+			// there's one. Searching the best trading path is expensive, so only search for the
+			// default fee swap paths which are actually tried, and charge the weight of them.
+			let default_fee_swap_weight = T::DefaultFeeSwapWeight::get();
+			let mut default_fee_swap_fee: PalletBalanceOf<T> = Zero::zero();
+			let fee_swap_path_list = AlternativeFeeSwapPath::<T>::get(who)
+				.map(|trading_path| (trading_path.into_inner(), false))
+				.into_iter()
+				.chain(
+					T::DefaultFeeSwapPathList::get()
+						.into_iter()
+						.map(|trading_path| (trading_path, true)),
+				);
+
+			for (trading_path, search_best_path) in fee_swap_path_list {
+				match (trading_path.first().copied(), trading_path.last().copied()) {
+					(Some(supply_currency_id), Some(target_currency_id))
+						if target_currency_id == native_currency_id =>
+					{
+						if search_best_path {
+							frame_system::Pallet::<T>::register_extra_weight_unchecked(
+								default_fee_swap_weight,
+								DispatchClass::Normal,
+							);
+							default_fee_swap_fee =
+								default_fee_swap_fee.saturating_add(Self::weight_to_fee(default_fee_swap_weight));
+						}
+
+						// add extra gap to keep alive after swap
+						let amount = fee
+							.saturating_add(default_fee_swap_fee)
+							.saturating_add(native_existential_deposit.saturating_sub(total_native));
+
+						let trading_path = if search_best_path {
+							T::DEX::get_best_swap_path_with_exact_target(
+								supply_currency_id,
+								target_currency_id,
+								amount.unique_saturated_into(),
+							)
+							.unwrap_or(trading_path)
+						} else {
+							trading_path
+						};
+
 						// calculate the supply limit according to oracle price and the slippage limit,
 						// if oracle price is not avalible, do not limit
 						let max_supply_limit = if let Some(target_price) =
-							T::PriceSource::get_relative_price(*target_currency_id, supply_currency_id)
+							T::PriceSource::get_relative_price(target_currency_id, supply_currency_id)
 						{
 							Ratio::one()
 								.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
@@ -631,6 +668,17 @@ where
 					_ => {}
 				}
 			}
+
+			if !default_fee_swap_fee.is_zero() {
+				if let Ok(imbalance) = <T as Config>::Currency::withdraw(
+					who,
+					default_fee_swap_fee,
+					reason,
+					ExistenceRequirement::KeepAlive,
+				) {
+					T::OnTransactionPayment::on_unbalanced(imbalance);
+				}
+			}
 		}
 	}
 }
@@ -33,7 +33,11 @@ use orml_traits::parameter_type_with_key;
 use primitives::{Amount, ReserveIdentifier, TokenSymbol, TradingPair};
 use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H256};
-use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::IdentityLookup,
+	Perbill,
+};
 use sp_std::cell::RefCell;
 use support::{mocks::MockAddressMapping, Price};
 
@@ -158,6 +162,15 @@ parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 4;
+	pub const DexUnsignedPriority: u64 = 1 << 20;
+	pub const SwapSplitParts: u32 = 10;
+	pub const MaxSearchedTradingPairs: u32 = 10;
+	pub const MaxSearchedTradingPaths: u32 = 10;
+	pub const LimitOrderDeposit: Balance = 100;
+	pub const MaxLimitOrdersPerAccount: u32 = 10;
+	pub const TwapWindow: BlockNumber = 10;
+	pub const TwapMaxAge: BlockNumber = 20;
+	pub const DexProtocolFeeReceiver: AccountId = AccountId::new([255u8; 32]);
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -169,17 +182,29 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = frame_system::EnsureSignedBy<Zero, AccountId>;
+	type UnsignedPriority = DexUnsignedPriority;
+	type ProtocolFeeReceiver = DexProtocolFeeReceiver;
+	type Call = Call;
 }
 
 parameter_types! {
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(1, 2);
 	pub static TransactionByteFee: u128 = 1;
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![AUSD, ACA], vec![DOT, AUSD, ACA]];
+	pub static DefaultFeeSwapWeight: Weight = 0;
 }
 
 thread_local! {
@@ -229,6 +254,7 @@ impl Config for Runtime {
 	type WeightToFee = WeightToFee;
 	type FeeMultiplierUpdate = ();
 	type DEX = DEXModule;
+	type DefaultFeeSwapWeight = DefaultFeeSwapWeight;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
@@ -271,6 +297,16 @@ construct_runtime!(
 	}
 );
 
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
 pub struct ExtBuilder {
 	balances: Vec<(AccountId, CurrencyId, Balance)>,
 	base_weight: u64,
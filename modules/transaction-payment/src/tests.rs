@@ -26,8 +26,9 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime,
-	TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Call, Currencies, DEXModule, DefaultFeeSwapWeight, ExtBuilder, MockPriceSource, Origin,
+	Runtime, System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT,
+	TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::MultiCurrency;
 use sp_runtime::{testing::TestXt, traits::One};
@@ -381,6 +382,116 @@ fn charge_fee_by_default_swap_path() {
 		});
 }
 
+#[test]
+fn charge_fee_by_best_swap_path() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX, DOT-ACA is cheaper than the default fee swap path DOT-AUSD-ACA
+			assert_ok!(DEXModule::enable_trading_pair(
+				Origin::signed(mock::Zero::get()),
+				DOT,
+				ACA,
+				module_dex::SwapCurve::ConstantProduct
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				ACA,
+				100,
+				20000,
+				0,
+				false
+			));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, ACA), (100, 20000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+			assert_eq!(TransactionPayment::alternative_fee_swap_path(&BOB), None);
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			// swap through DOT-ACA rather than the default fee swap path
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100 - 12);
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, ACA), (100 + 12, 20000 - 2000 - 10));
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000, 1000));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100, 1000));
+		});
+}
+
+#[test]
+fn charge_fee_by_best_swap_path_charges_default_fee_swap_weight() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DEXModule::enable_trading_pair(
+				Origin::signed(mock::Zero::get()),
+				DOT,
+				ACA,
+				module_dex::SwapCurve::ConstantProduct
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				ACA,
+				100,
+				20000,
+				0,
+				false
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 100));
+			DefaultFeeSwapWeight::set(1000);
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			// both default fee swap paths are tried, and the swap through DOT-ACA covers the fee of their
+			// weight
+			assert_eq!(
+				*System::block_weight().get(DispatchClass::Normal),
+				2 * DefaultFeeSwapWeight::get()
+			);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 100 - 26);
+			assert_eq!(
+				DEXModule::get_liquidity_pool(DOT, ACA),
+				(100 + 26, 20000 - 2000 - 2000 - 10)
+			);
+		});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
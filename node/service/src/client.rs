@@ -40,6 +40,9 @@ pub trait RuntimeApiCollection:
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 	+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
+	+ module_dex_rpc_runtime_api::DexApi<Block, Balance, BlockNumber>
+	+ module_emergency_shutdown_rpc_runtime_api::EmergencyShutdownApi<Block, Balance>
+	+ module_homa_lite_rpc_runtime_api::HomaLiteApi<Block, Balance>
 	+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
@@ -58,6 +61,9 @@ where
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 		+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
+		+ module_dex_rpc_runtime_api::DexApi<Block, Balance, BlockNumber>
+		+ module_emergency_shutdown_rpc_runtime_api::EmergencyShutdownApi<Block, Balance>
+		+ module_homa_lite_rpc_runtime_api::HomaLiteApi<Block, Balance>
 		+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
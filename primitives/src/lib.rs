@@ -169,6 +169,7 @@ pub enum ReserveIdentifier {
 	Honzon,
 	Nft,
 	TransactionPayment,
+	HonzonAutoDeleverage,
 
 	// always the last, indicate number of variants
 	Count,
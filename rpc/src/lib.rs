@@ -20,7 +20,7 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, Balance, Block, CurrencyId, DataProviderId, Hash, Nonce};
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Nonce};
 use sc_client_api::light::{Fetcher, RemoteBlockchain};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -32,6 +32,9 @@ use std::sync::Arc;
 pub use sc_rpc::SubscriptionTaskExecutor;
 
 pub use evm_rpc::{EVMApi, EVMApiServer, EVMRuntimeRPCApi};
+pub use module_dex_rpc::{Dex, DexApi, DexRuntimeApi};
+pub use module_emergency_shutdown_rpc::{EmergencyShutdown, EmergencyShutdownApi, EmergencyShutdownRuntimeApi};
+pub use module_homa_lite_rpc::{HomaLite, HomaLiteApi, HomaLiteRuntimeApi};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpc_core::IoHandler<sc_rpc::Metadata>;
@@ -68,6 +71,9 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, runtime_common::TimeStampedPrice>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+	C::Api: DexRuntimeApi<Block, Balance, BlockNumber>,
+	C::Api: EmergencyShutdownRuntimeApi<Block, Balance>,
+	C::Api: HomaLiteRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
@@ -94,6 +100,11 @@ where
 	// more context: https://github.com/paritytech/substrate/pull/3480
 	// These RPCs should use an asynchronous caller instead.
 	io.extend_with(OracleApi::to_delegate(Oracle::new(client.clone())));
+	io.extend_with(DexApi::to_delegate(Dex::new(client.clone())));
+	io.extend_with(EmergencyShutdownApi::to_delegate(EmergencyShutdown::new(
+		client.clone(),
+	)));
+	io.extend_with(HomaLiteApi::to_delegate(HomaLite::new(client.clone())));
 	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client, deny_unsafe)));
 
 	io
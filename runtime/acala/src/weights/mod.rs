@@ -30,6 +30,7 @@ pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
 pub mod module_homa_lite;
+pub mod module_homa_validator_selection;
 pub mod module_honzon;
 pub mod module_incentives;
 pub mod module_nft;
@@ -76,9 +76,49 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(30 as Weight))
 			.saturating_add(T::DbWeight::get().writes(16 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_evm_contract() -> Weight {
+		(455316000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(33 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn liquidate_by_stability_pool() -> Weight {
+		(352904000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(29 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
 	fn settle() -> Weight {
 		(170_663_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_liquidation_strategies() -> Weight {
+		(17_420_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_basket_params() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn settle_basket() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_interest_rate_controller() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_global_interest_rate() -> Weight {
+		(31_274_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
@@ -47,15 +47,17 @@ use sp_std::marker::PhantomData;
 /// Weight functions for module_dex.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
+	// Estimated, to be replaced by the benchmarked weight.
 	fn enable_trading_pair() -> Weight {
 		(25_348_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn disable_trading_pair() -> Weight {
 		(26_137_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn list_provisioning() -> Weight {
 		(36_503_000 as Weight)
@@ -67,10 +69,11 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
 	fn end_provisioning() -> Weight {
 		(81_372_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	fn add_provision() -> Weight {
 		(132_852_000 as Weight)
@@ -116,4 +119,76 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_best_path(p: u32, ) -> Weight {
+		(157_286_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_target_by_best_path(p: u32, ) -> Weight {
+		(157_512_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn swap_with_exact_supply_by_split_paths(u: u32, p: u32, ) -> Weight {
+		(181_262_000 as Weight)
+			.saturating_add((64_918_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((21_406_000 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn place_limit_order(p: u32, ) -> Weight {
+		(101_412_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn cancel_limit_order() -> Weight {
+		(58_208_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fill_limit_order(p: u32, ) -> Weight {
+		(497_554_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(135 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_expired_limit_order() -> Weight {
+		(58_351_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_trading_pair_fee() -> Weight {
+		(18_630_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_protocol_fee_share() -> Weight {
+		(12_410_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn flash_swap(u: u32, ) -> Weight {
+		(75_520_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
 }
@@ -88,4 +88,50 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(29 as Weight))
 			.saturating_add(T::DbWeight::get().writes(15 as Weight))
 	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn adjust_basket_loan(c: u32, ) -> Weight {
+		(251_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn redeem(c: u32, ) -> Weight {
+		(98_451_000 as Weight)
+			.saturating_add((41_358_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(c as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn authorize_with_limits() -> Weight {
+		(55_372_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn check_authorization() -> Weight {
+		(27_916_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn set_auto_deleverage() -> Weight {
+		(39_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn remove_auto_deleverage() -> Weight {
+		(24_718_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn trigger_auto_deleverage() -> Weight {
+		(412_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(38 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
 }
@@ -33,16 +33,16 @@ use frame_support::{
 };
 use frame_system::{limits, EnsureOneOf, EnsureRoot};
 pub use module_support::{ExchangeRate, PrecompileCallerFilter, Price, Rate, Ratio};
-use primitives::{evm::is_system_contract, Balance, BlockNumber, CurrencyId};
+use primitives::{evm::is_system_contract, Balance, BlockNumber, CurrencyId, Moment};
 use scale_info::TypeInfo;
 use sp_core::{
 	u32_trait::{_1, _2, _3, _4},
-	H160,
+	H160, H256,
 };
 use sp_runtime::{
 	traits::{BlockNumberProvider, Convert},
 	transaction_validity::TransactionPriority,
-	Perbill,
+	DispatchResult, Perbill,
 };
 use static_assertions::const_assert;
 
@@ -50,7 +50,7 @@ mod homa;
 pub use homa::*;
 
 pub mod precompile;
-use orml_traits::GetByKey;
+use orml_traits::{DataFeeder, DataProvider, DataProviderExtended, GetByKey};
 pub use precompile::{
 	AllPrecompiles, DexPrecompile, MultiCurrencyPrecompile, NFTPrecompile, OraclePrecompile, ScheduleCallPrecompile,
 	StateRentPrecompile,
@@ -66,11 +66,40 @@ pub use xcm_executor::{traits::DropAssets, Assets};
 
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, primitives::Moment>;
 
+/// The data provider which treats the prices of `Source` not updated within
+/// `MaxPriceAge` as unavailable, so that the price consumers fall back to
+/// their other price sources.
+pub struct FreshPriceDataProvider<Source, Time, MaxPriceAge>(PhantomData<(Source, Time, MaxPriceAge)>);
+impl<Source, Time, MaxPriceAge> DataProvider<CurrencyId, Price> for FreshPriceDataProvider<Source, Time, MaxPriceAge>
+where
+	Source: DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Time: frame_support::traits::Time<Moment = Moment>,
+	MaxPriceAge: Get<Moment>,
+{
+	fn get(key: &CurrencyId) -> Option<Price> {
+		let TimeStampedPrice { value, timestamp } = Source::get_no_op(key)?;
+		if Time::now().saturating_sub(timestamp) > MaxPriceAge::get() {
+			return None;
+		}
+		Some(value)
+	}
+}
+impl<Source, Time, MaxPriceAge, AccountId> DataFeeder<CurrencyId, Price, AccountId>
+	for FreshPriceDataProvider<Source, Time, MaxPriceAge>
+where
+	Source: DataFeeder<CurrencyId, Price, AccountId>,
+{
+	fn feed_value(who: AccountId, key: CurrencyId, value: Price) -> DispatchResult {
+		Source::feed_value(who, key, value)
+	}
+}
+
 // Priority of unsigned transactions
 parameter_types! {
 	// Operational is 3/4 of TransactionPriority::max_value().
 	// Ensure Inherent -> Operational tx -> Unsigned tx -> Signed normal tx
 	pub const CdpEngineUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;      // 50%
+	pub const DexUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 4;            // 25%
 	pub const AuctionManagerUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 5; // 20%
 	pub const RenvmBridgeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 10;   // 10%
 }
@@ -178,6 +207,14 @@ impl<T: cumulus_pallet_parachain_system::Config> BlockNumberProvider for RelayCh
 	}
 }
 
+pub struct RelayChainStateRootProvider<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: cumulus_pallet_parachain_system::Config> Get<Option<H256>> for RelayChainStateRootProvider<T> {
+	fn get() -> Option<H256> {
+		cumulus_pallet_parachain_system::Pallet::<T>::validation_data().map(|d| d.relay_parent_storage_root)
+	}
+}
+
 pub type GeneralCouncilInstance = pallet_collective::Instance1;
 pub type FinancialCouncilInstance = pallet_collective::Instance2;
 pub type HomaCouncilInstance = pallet_collective::Instance3;
@@ -408,6 +445,7 @@ where
 mod tests {
 	use super::*;
 	use primitives::evm::SYSTEM_CONTRACT_ADDRESS_PREFIX;
+	use sp_runtime::traits::One;
 
 	#[test]
 	fn system_contracts_filter_works() {
@@ -421,4 +459,50 @@ mod tests {
 		min_blocked_addr[SYSTEM_CONTRACT_ADDRESS_PREFIX.len() - 1] = 1u8;
 		assert!(!SystemContractsFilter::is_allowed(min_blocked_addr.into()));
 	}
+
+	parameter_types! {
+		pub static MockNow: Moment = 0;
+		pub const MaxPriceAge: Moment = 100;
+	}
+
+	pub struct MockTime;
+	impl frame_support::traits::Time for MockTime {
+		type Moment = Moment;
+
+		fn now() -> Moment {
+			MockNow::get()
+		}
+	}
+
+	pub struct MockSource;
+	impl DataProviderExtended<CurrencyId, TimeStampedPrice> for MockSource {
+		fn get_no_op(key: &CurrencyId) -> Option<TimeStampedPrice> {
+			if *key == DOT {
+				Some(TimeStampedPrice {
+					value: Price::one(),
+					timestamp: 10,
+				})
+			} else {
+				None
+			}
+		}
+
+		fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
+			vec![]
+		}
+	}
+
+	#[test]
+	fn fresh_price_data_provider_works() {
+		type Provider = FreshPriceDataProvider<MockSource, MockTime, MaxPriceAge>;
+
+		assert_eq!(Provider::get(&DOT), Some(Price::one()));
+		assert_eq!(Provider::get(&KSM), None);
+
+		MockNow::set(110);
+		assert_eq!(Provider::get(&DOT), Some(Price::one()));
+
+		MockNow::set(111);
+		assert_eq!(Provider::get(&DOT), None);
+	}
 }
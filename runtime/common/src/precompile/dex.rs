@@ -17,8 +17,9 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::input::{Input, InputT, Output};
-use crate::precompile::PrecompileOutput;
-use frame_support::log;
+use crate::{precompile::PrecompileOutput, WEIGHT_PER_GAS};
+use frame_support::{log, traits::Get, weights::Weight};
+use module_dex::WeightInfo;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use module_support::DEXManager;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -35,8 +36,27 @@ use sp_std::{marker::PhantomData, prelude::*, result};
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
+/// - Swap with exact supply by best path. Rest `input` bytes: `who`, `supply_currency_id`,
+///   `target_currency_id`, `supply_amount`, `min_target_amount`.
+/// - Swap with exact target by best path. Rest `input` bytes: `who`, `supply_currency_id`,
+///   `target_currency_id`, `target_amount`, `max_supply_amount`.
+///
+/// The swaps by best path cost the gas of searching the trading paths and evaluating up to
+/// `MaxSearchedTradingPaths` of them.
 pub struct DexPrecompile<R>(PhantomData<R>);
 
+/// Convert the weight of swap by best path to gas, and ensure it's within `target_gas` before
+/// searching the trading paths.
+fn swap_by_best_path_cost(weight: Weight, target_gas: Option<u64>) -> result::Result<u64, ExitError> {
+	let cost = weight / WEIGHT_PER_GAS;
+	if let Some(target_gas) = target_gas {
+		if cost > target_gas {
+			return Err(ExitError::OutOfGas);
+		}
+	}
+	Ok(cost)
+}
+
 #[module_evm_utiltity_macro::generate_function_selector]
 #[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u32)]
@@ -49,16 +69,18 @@ pub enum Action {
 	SwapWithExactTarget = "swapWithExactTarget(address,address[],uint256,uint256)",
 	AddLiquidity = "addLiquidity(address,address,address,uint256,uint256,uint256)",
 	RemoveLiquidity = "removeLiquidity(address,address,address,uint256,uint256,uint256)",
+	SwapWithExactSupplyByBestPath = "swapWithExactSupplyByBestPath(address,address,address,uint256,uint256)",
+	SwapWithExactTargetByBestPath = "swapWithExactTargetByBestPath(address,address,address,uint256,uint256)",
 }
 
 impl<Runtime> Precompile for DexPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_dex::Config,
 	module_dex::Pallet<Runtime>: DEXManager<Runtime::AccountId, CurrencyId, Balance>,
 {
 	fn execute(
 		input: &[u8],
-		_target_gas: Option<u64>,
+		target_gas: Option<u64>,
 		_context: &Context,
 	) -> result::Result<PrecompileOutput, ExitError> {
 		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(input);
@@ -283,6 +305,76 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::SwapWithExactSupplyByBestPath => {
+				let who = input.account_id_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let supply_amount = input.balance_at(4)?;
+				let min_target_amount = input.balance_at(5)?;
+
+				log::debug!(
+					target: "evm",
+					"dex: swap_with_exact_supply_by_best_path who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, supply_amount: {:?}, min_target_amount: {:?}",
+					who, supply_currency_id, target_currency_id, supply_amount, min_target_amount
+				);
+
+				let cost = swap_by_best_path_cost(
+					<Runtime as module_dex::Config>::WeightInfo::swap_with_exact_supply_by_best_path(
+						<Runtime as module_dex::Config>::MaxSearchedTradingPaths::get(),
+					),
+					target_gas,
+				)?;
+				let path = <module_dex::Pallet<Runtime> as DEXManager<Runtime::AccountId, CurrencyId, Balance>>::get_best_swap_path_with_exact_supply(supply_currency_id, target_currency_id, supply_amount)
+					.ok_or_else(|| ExitError::Other("Dex get_best_swap_path_with_exact_supply failed".into()))?;
+
+				let value =
+					<module_dex::Pallet<Runtime> as DEXManager<Runtime::AccountId, CurrencyId, Balance>>::swap_with_exact_supply(&who, &path, supply_amount, min_target_amount).map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost,
+					output: Output::default().encode_u128(value),
+					logs: Default::default(),
+				})
+			}
+			Action::SwapWithExactTargetByBestPath => {
+				let who = input.account_id_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let target_amount = input.balance_at(4)?;
+				let max_supply_amount = input.balance_at(5)?;
+
+				log::debug!(
+					target: "evm",
+					"dex: swap_with_exact_target_by_best_path who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, target_amount: {:?}, max_supply_amount: {:?}",
+					who, supply_currency_id, target_currency_id, target_amount, max_supply_amount
+				);
+
+				let cost = swap_by_best_path_cost(
+					<Runtime as module_dex::Config>::WeightInfo::swap_with_exact_target_by_best_path(
+						<Runtime as module_dex::Config>::MaxSearchedTradingPaths::get(),
+					),
+					target_gas,
+				)?;
+				let path = <module_dex::Pallet<Runtime> as DEXManager<Runtime::AccountId, CurrencyId, Balance>>::get_best_swap_path_with_exact_target(supply_currency_id, target_currency_id, target_amount)
+					.ok_or_else(|| ExitError::Other("Dex get_best_swap_path_with_exact_target failed".into()))?;
+
+				let value =
+					<module_dex::Pallet<Runtime> as DEXManager<Runtime::AccountId, CurrencyId, Balance>>::swap_with_exact_target(&who, &path, target_amount, max_supply_amount).map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost,
+					output: Output::default().encode_u128(value),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -23,13 +23,16 @@ use crate::precompile::{
 	mock::{
 		aca_evm_address, alice, alice_evm_addr, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
 		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, DexModule,
-		Event as TestEvent, Oracle, Origin, Price, System, Test, ALICE, AUSD, INITIAL_BALANCE, RENBTC,
+		Event as TestEvent, MaxSearchedTradingPaths, Oracle, Origin, Price, System, Test, ALICE, AUSD, INITIAL_BALANCE,
+		RENBTC,
 	},
 	schedule_call::TaskInfo,
 };
+use crate::WEIGHT_PER_GAS;
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
+use module_dex::{SwapCurve, WeightInfo};
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use module_support::AddressMapping;
 use orml_traits::DataFeeder;
@@ -581,7 +584,12 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 fn dex_precompile_get_liquidity_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -623,7 +631,12 @@ fn dex_precompile_get_liquidity_should_work() {
 fn dex_precompile_get_liquidity_token_address_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -675,7 +688,12 @@ fn dex_precompile_get_liquidity_token_address_should_work() {
 fn dex_precompile_get_swap_target_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -722,7 +740,12 @@ fn dex_precompile_get_swap_target_amount_should_work() {
 fn dex_precompile_get_swap_supply_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -769,7 +792,12 @@ fn dex_precompile_get_swap_supply_amount_should_work() {
 fn dex_precompile_swap_with_exact_supply_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -820,7 +848,12 @@ fn dex_precompile_swap_with_exact_supply_should_work() {
 fn dex_precompile_swap_with_exact_target_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -867,6 +900,132 @@ fn dex_precompile_swap_with_exact_target_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_swap_with_exact_supply_by_best_path_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + supply_currency_id + target_currency_id +
+		// supply_amount + min_target_amount
+		let mut input = [0u8; 6 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(dex::Action::SwapWithExactSupplyByBestPath).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// supply_amount
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// min_target_amount
+		U256::from(0).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(989).to_big_endian(&mut expected_output[..32]);
+
+		let expected_cost = <Test as module_dex::Config>::WeightInfo::swap_with_exact_supply_by_best_path(
+			MaxSearchedTradingPaths::get(),
+		) / WEIGHT_PER_GAS;
+		assert_noop!(
+			DexPrecompile::execute(&input, Some(expected_cost - 1), &context),
+			ExitError::OutOfGas
+		);
+
+		let resp = DexPrecompile::execute(&input, Some(expected_cost), &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, expected_cost);
+	});
+}
+
+#[test]
+fn dex_precompile_swap_with_exact_target_by_best_path_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + supply_currency_id + target_currency_id +
+		// target_amount + max_supply_amount
+		let mut input = [0u8; 6 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(dex::Action::SwapWithExactTargetByBestPath).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// target_amount
+		U256::from(1).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// max_supply_amount
+		U256::from(1).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(1).to_big_endian(&mut expected_output[..32]);
+
+		let resp = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(
+			resp.cost,
+			<Test as module_dex::Config>::WeightInfo::swap_with_exact_target_by_best_path(
+				MaxSearchedTradingPaths::get()
+			) / WEIGHT_PER_GAS
+		);
+
+		// there's no trading path from RENBTC to ACA
+		U256::from_big_endian(aca_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			DexPrecompile::execute(&input, None, &context),
+			ExitError::Other("Dex get_best_swap_path_with_exact_target failed".into())
+		);
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
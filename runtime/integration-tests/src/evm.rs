@@ -19,6 +19,7 @@
 use crate::setup::*;
 
 use frame_support::assert_ok;
+use module_dex::SwapCurve;
 use module_evm_accounts::EvmAddressMapping;
 use module_support::{EVMBridge as EVMBridgeT, Erc20InfoMapping, EVM as EVMTrait};
 use primitives::evm::EvmAddress;
@@ -167,6 +168,7 @@ fn dex_module_works_with_evm_contract() {
 				100,
 				1000,
 				0,
+				SwapCurve::ConstantProduct,
 			));
 
 			<EVM as EVMTrait<AccountId>>::set_origin(MockAddressMapping::get_account_id(&alice_evm_addr()));
@@ -366,6 +368,7 @@ fn test_multicurrency_precompile_module() {
 				100,
 				1000,
 				0,
+				SwapCurve::ConstantProduct,
 			));
 
 			// CurrencyId::DexShare(Erc20, Erc20)
@@ -133,6 +133,7 @@ fn liquidate_cdp() {
 				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
 				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 
 			assert_ok!(CdpEngine::adjust_position(
@@ -176,6 +177,7 @@ fn liquidate_cdp() {
 				Change::NoChange,
 				Change::NewValue(Some(Ratio::saturating_from_rational(400, 100))),
 				Change::NoChange,
+				Change::NoChange,
 			));
 
 			assert_ok!(CdpEngine::liquidate_unsafe_cdp(
@@ -249,6 +251,7 @@ fn test_honzon_module() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 			assert_ok!(CdpEngine::adjust_position(
 				&AccountId::from(ALICE),
@@ -289,6 +292,7 @@ fn test_honzon_module() {
 				Change::NoChange,
 				Change::NoChange,
 				Change::NoChange,
+				Change::NoChange,
 			));
 			assert_ok!(CdpEngine::liquidate(
 				Origin::none(),
@@ -333,6 +337,7 @@ fn test_cdp_engine_module() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 
 			let new_collateral_params = CdpEngine::collateral_params(RELAY_CHAIN_CURRENCY);
@@ -468,6 +473,7 @@ fn cdp_treasury_handles_honzon_surplus_correctly() {
 				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
 				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 			assert_ok!(Dex::add_liquidity(
 				Origin::signed(AccountId::from(BOB)),
@@ -51,6 +51,9 @@ pub mod evm {
 pub mod evm_accounts {
 	include!("../../../mandala/src/benchmarking/evm_accounts.rs");
 }
+pub mod homa_validator_selection {
+	include!("../../../mandala/src/benchmarking/homa_validator_selection.rs");
+}
 pub mod honzon {
 	include!("../../../mandala/src/benchmarking/honzon.rs");
 }
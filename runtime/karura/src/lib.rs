@@ -52,8 +52,10 @@ use sp_version::RuntimeVersion;
 use frame_system::{EnsureRoot, RawOrigin};
 use module_asset_registry::{EvmErc20InfoMapping, FixedRateOfForeignAsset, XcmForeignAssetIdMapping};
 use module_currencies::BasicCurrencyAdapter;
+use module_emergency_shutdown::EmergencyShutdownTask;
 use module_evm::{CallInfo, CreateInfo, EvmTask, Runner};
 use module_evm_accounts::EvmAddressMapping;
+use module_honzon::HonzonTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{DispatchableTask, ForeignAssetIdMapping};
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -113,8 +115,9 @@ pub use runtime_common::{
 	FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance,
 	GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
 	OperatorMembershipInstanceAcala, Price, ProxyType, Rate, Ratio, RelayChainBlockNumberProvider,
-	RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, TimeStampedPrice, BNC, KAR, KSM, KUSD, LKSM, PHA, RENBTC, VSKSM,
+	RelayChainStateRootProvider, RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights,
+	SystemContractsFilter, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, BNC,
+	KAR, KSM, KUSD, LKSM, PHA, RENBTC, VSKSM,
 };
 
 mod authority;
@@ -155,6 +158,7 @@ parameter_types! {
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
@@ -170,6 +174,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 	vec![
 		LoansPalletId::get().into_account(),
 		CDPTreasuryPalletId::get().into_account(),
+		CDPEnginePalletId::get().into_account(),
 		CollatorPotId::get().into_account(),
 		DEXPalletId::get().into_account(),
 		HomaTreasuryPalletId::get().into_account(),
@@ -810,11 +815,12 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxOraclePriceAge: Moment = 1000 * 60 * 60 * 2; // 2 hours
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
-	type Source = AggregatedDataProvider;
+	type Source = runtime_common::FreshPriceDataProvider<AggregatedDataProvider, Timestamp, MaxOraclePriceAge>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -824,6 +830,7 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type CollateralFreeze = EmergencyShutdown;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -914,6 +921,9 @@ parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(20, 100);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 1000);
+	pub const DutchAuctionResetDuration: BlockNumber = 2 * HOURS;
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![KUSD],
 		vec![KSM, KUSD],
@@ -928,12 +938,17 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type CollateralFreeze = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type DutchAuctionResetDuration = DutchAuctionResetDuration;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1013,11 +1028,20 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(8, 100);
 	pub MinimumDebitValue: Balance = 20 * dollar(KUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub const LiquidationContractGasLimit: u64 = 2_100_000;
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type FallbackPriceSource = module_dex::DexTwapPriceProvider<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>;
+	type StablePriceSource = module_dex::DexStableCurrencyPriceProvider<
+		Runtime,
+		GetStableCurrencyId,
+		GetStakingCurrencyId,
+		module_prices::RealTimePriceProvider<Runtime>,
+		StableCurrencyTwapMaxAge,
+	>;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1029,19 +1053,38 @@ impl module_cdp_engine::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type CollateralFreeze = EmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type PalletId = CDPEnginePalletId;
+	type LiquidationEvmBridge = EVMBridge;
+	type LiquidationContractGasLimit = LiquidationContractGasLimit;
+	type GasToWeight = GasToWeight;
+	type EvmAddressMapping = EvmAddressMapping<Runtime>;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type StabilityPool = ();
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = deposit(1, 64);
+	pub DepositPerAutoDeleverageRule: Balance = deposit(1, 64);
+	pub RedemptionFeeFloor: Rate = Rate::saturating_from_rational(5, 1000);
+	// half-life of redemption base rate is about 12 hours
+	pub RedemptionBaseRateDecay: Rate = Rate::saturating_from_rational(999_807, 1_000_000);
+	pub const MaxRedemptionCandidates: u32 = 20;
 }
 
 impl module_honzon::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type RedemptionFeeFloor = RedemptionFeeFloor;
+	type RedemptionBaseRateDecay = RedemptionBaseRateDecay;
+	type MaxRedemptionCandidates = MaxRedemptionCandidates;
+	type DepositPerAutoDeleverageRule = DepositPerAutoDeleverageRule;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1052,12 +1095,23 @@ impl module_emergency_shutdown::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
+	type CDPSettlement = CdpEngine;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const TradingPathLimit: u32 = 4;
+	pub const SwapSplitParts: u32 = 10;
+	pub const MaxSearchedTradingPairs: u32 = 30;
+	pub const MaxSearchedTradingPaths: u32 = 10;
+	pub LimitOrderDeposit: Balance = dollar(KAR);
+	pub const MaxLimitOrdersPerAccount: u32 = 20;
+	pub const TwapWindow: BlockNumber = 30 * MINUTES;
+	pub const TwapMaxAge: BlockNumber = 2 * HOURS;
+	pub const StableCurrencyTwapMaxAge: BlockNumber = HOURS;
 }
 
 impl module_dex::Config for Runtime {
@@ -1065,11 +1119,22 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type UnsignedPriority = runtime_common::DexUnsignedPriority;
+	type ProtocolFeeReceiver = KaruraTreasuryAccount;
+	type Call = Call;
 }
 
 parameter_types! {
@@ -1104,6 +1169,8 @@ parameter_types! {
 		vec![LKSM, KSM, KAR],
 		vec![BNC, KUSD, KSM, KAR],
 	];
+	pub DefaultFeeSwapWeight: Weight =
+		<weights::module_dex::WeightInfo<Runtime> as module_dex::WeightInfo>::swap_with_exact_target_by_best_path(MaxSearchedTradingPaths::get());
 }
 
 type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
@@ -1130,6 +1197,7 @@ impl module_transaction_payment::Config for Runtime {
 	type WeightToFee = WeightToFee;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 	type DEX = Dex;
+	type DefaultFeeSwapWeight = DefaultFeeSwapWeight;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
@@ -1588,6 +1656,20 @@ pub fn create_x2_parachain_multilocation(index: u16) -> MultiLocation {
 	)
 }
 
+pub struct SubAccountIndexMultiLocationConvertor;
+impl Convert<u16, MultiLocation> for SubAccountIndexMultiLocationConvertor {
+	fn convert(sub_account_index: u16) -> MultiLocation {
+		create_x2_parachain_multilocation(sub_account_index)
+	}
+}
+
+pub struct SubAccountIndexAccountIdConvertor;
+impl Convert<u16, AccountId> for SubAccountIndexAccountIdConvertor {
+	fn convert(sub_account_index: u16) -> AccountId {
+		Utility::derivative_account_id(ParachainInfo::get().into_account(), sub_account_index)
+	}
+}
+
 parameter_types! {
 	pub const KSMCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 	pub const LKSMCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
@@ -1603,13 +1685,19 @@ parameter_types! {
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub BaseWithdrawFee: Permill = Permill::from_rational(35u32, 10_000u32); // 20% yield per year, unbonding period = 7 days. 1.2^(7 / 365) = 1.00350
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
+	pub MaximumRedeemRequestsForFastMatch: u32 = 20;
 	pub RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 14;
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
-	pub SubAccountIndex: u16 = RelayChainSubAccountId::HomaLite as u16;
+	pub HomaLiteSubAccountIndexes: Vec<u16> = vec![RelayChainSubAccountId::HomaLite as u16];
 	// Calculated from polkadot/xcm/xcm-builder: fn buy_weight
 	// We must charge higher than what Kusama required (533_333_300, obtained from integration test)
 	pub XcmUnbondFee: Balance = 60 * millicent(KSM);
+	pub MaxStakingLedgerSyncChange: Permill = Permill::from_percent(1);
+	pub const StakingLedgerSyncPeriod: BlockNumber = 3_600; // one era of Kusama in relaychain blocks
+	pub const HomaLiteXcmExecutionDelay: u32 = 10;
+	pub const HomaLiteMaxRelayChainStateRootAge: u32 = 50;
+	pub const HomaLiteMaxSlashCompensationSlippage: Permill = Permill::from_percent(5);
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1621,8 +1709,8 @@ impl module_homa_lite::Config for Runtime {
 	type MinimumMintThreshold = MinimumMintThreshold;
 	type MinimumRedeemThreshold = MinimumRedeemThreshold;
 	type XcmTransfer = XTokens;
-	type SovereignSubAccountLocation = RelayChainSovereignSubAccount;
-	type SubAccountIndex = SubAccountIndex;
+	type SovereignSubAccountLocation = SubAccountIndexMultiLocationConvertor;
+	type SubAccountIndexes = HomaLiteSubAccountIndexes;
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
@@ -1632,9 +1720,75 @@ impl module_homa_lite::Config for Runtime {
 	type RelayChainBlockNumber = RelayChainBlockNumberProvider<Runtime>;
 	type ParachainAccount = ParachainAccount;
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type MaximumRedeemRequestsForFastMatch = MaximumRedeemRequestsForFastMatch;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
 	type StakingUpdateFrequency = OneDay;
+	type SovereignSubAccountId = SubAccountIndexAccountIdConvertor;
+	type RelayChainStateRoot = RelayChainStateRootProvider<Runtime>;
+	type MaxRelayChainStateRootAge = HomaLiteMaxRelayChainStateRootAge;
+	type MaxStakingLedgerSyncChange = MaxStakingLedgerSyncChange;
+	type StakingLedgerSyncPeriod = StakingLedgerSyncPeriod;
+	type XcmExecutionDelay = HomaLiteXcmExecutionDelay;
+	type DEX = Dex;
+	type MaxSlashCompensationSlippage = HomaLiteMaxSlashCompensationSlippage;
+}
+
+parameter_types! {
+	pub MinGuaranteeAmount: Balance = dollar(LKSM);
+	pub const ValidatorInsuranceThreshold: Balance = 0;
+	pub const ValidatorBondingDuration: BlockNumber = 100_800; // 7 days of Kusama in relaychain blocks
+	pub HomaTreasuryAccount: AccountId = HomaTreasuryPalletId::get().into_account();
+}
+
+impl module_homa_validator_list::Config for Runtime {
+	type Event = Event;
+	type RelaychainAccountId = AccountId;
+	type LiquidTokenCurrency = module_currencies::Currency<Runtime, GetLiquidCurrencyId>;
+	type MinBondAmount = MinGuaranteeAmount;
+	type BondingDuration = ValidatorBondingDuration;
+	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
+	// All insured validators are the candidates of the validator selection.
+	type MaxInsuredValidators = MaxValidatorCandidates;
+	type FreezeOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashOrigin = EnsureRootOrHalfHomaCouncil;
+	type OnSlash = module_homa_lite::OnSlash<Runtime>;
+	type LiquidStakingExchangeRateProvider = HomaLite;
+	type WeightInfo = ();
+	type OnIncreaseGuarantee = ();
+	type OnDecreaseGuarantee = ();
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type InsuranceCompensationAccount = HomaTreasuryAccount;
+}
+
+parameter_types! {
+	pub ValidatorSelectionInsuranceWeight: Permill = Permill::one();
+	pub const ValidatorSelectionVoteWeight: Permill = Permill::from_percent(50);
+	pub const ValidatorSelectionSlashPenalty: Permill = Permill::from_percent(50);
+	pub const MaxNominations: u32 = 24;
+	pub const MaxValidatorCandidates: u32 = 100;
+	// Calculated from polkadot/xcm/xcm-builder: fn buy_weight, the same as `XcmUnbondFee` for the same dest weight
+	pub XcmNominateFee: Balance = 60 * millicent(KSM);
+	pub const XcmNominateWeight: Weight = 10_000_000_000;
+}
+
+// There is no relaychain era source on this chain yet, the nominations are refreshed by `force_nominate`.
+impl module_homa_validator_selection::Config for Runtime {
+	type Event = Event;
+	type RelaychainAccountId = AccountId;
+	type ValidatorInsurance = HomaValidatorListModule;
+	type NomineeVotes = ();
+	type InsuranceWeight = ValidatorSelectionInsuranceWeight;
+	type VoteWeight = ValidatorSelectionVoteWeight;
+	type SlashPenalty = ValidatorSelectionSlashPenalty;
+	type MaxNominations = MaxNominations;
+	type MaxValidatorCandidates = MaxValidatorCandidates;
+	type SubAccountIndexes = HomaLiteSubAccountIndexes;
+	type RelayChainCallBuilder = RelayChainCallBuilder<Runtime, ParachainInfo>;
+	type XcmNominateFee = XcmNominateFee;
+	type XcmNominateWeight = XcmNominateWeight;
+	type GovernanceOrigin = EnsureRootOrHalfHomaCouncil;
+	type WeightInfo = weights::module_homa_validator_selection::WeightInfo<Runtime>;
 }
 
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
@@ -1789,6 +1943,8 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		HonzonTask(HonzonTask<Runtime>),
+		EmergencyShutdownTask(EmergencyShutdownTask<Runtime>),
 	}
 }
 
@@ -1878,7 +2034,7 @@ construct_runtime!(
 
 		// Karura Core
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 90,
-		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 91,
+		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>, ValidateUnsigned} = 91,
 
 		// Honzon
 		AuctionManager: module_auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 100,
@@ -1890,6 +2046,8 @@ construct_runtime!(
 
 		// Homa
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 115,
+		HomaValidatorListModule: module_homa_validator_list::{Pallet, Call, Storage, Event<T>} = 116,
+		HomaValidatorSelection: module_homa_validator_selection::{Pallet, Call, Storage, Event<T>} = 117,
 
 		// Karura Other
 		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 120,
@@ -2056,6 +2214,97 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_emergency_shutdown_rpc_runtime_api::EmergencyShutdownApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_settlement_report(
+			currency_id: CurrencyId,
+		) -> module_emergency_shutdown_rpc_runtime_api::SettlementReportInfo<Balance> {
+			EmergencyShutdown::get_settlement_report(currency_id)
+		}
+
+		fn get_settlement_reports() -> Vec<(CurrencyId, module_emergency_shutdown_rpc_runtime_api::SettlementReportInfo<Balance>)> {
+			EmergencyShutdown::get_settlement_reports()
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_redeem_liquidity() -> Vec<module_homa_lite_rpc_runtime_api::RedeemLiquidityInfo<Balance>> {
+			HomaLite::get_redeem_liquidity()
+				.into_iter()
+				.map(|(additional_fee, liquid_amount, num_requests)| {
+					module_homa_lite_rpc_runtime_api::RedeemLiquidityInfo {
+						additional_fee,
+						liquid_amount,
+						num_requests,
+					}
+				})
+				.collect()
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		Balance,
+		BlockNumber,
+	> for Runtime {
+		fn get_swap_target_amount(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::BalanceInfo<Balance>> {
+			Dex::swap_target_amount_info(&path, supply_amount)
+		}
+
+		fn get_swap_supply_amount(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::BalanceInfo<Balance>> {
+			Dex::swap_supply_amount_info(&path, target_amount)
+		}
+
+		fn get_best_swap_with_exact_supply(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPathInfo<Balance>> {
+			Dex::best_swap_with_exact_supply_info(supply_currency_id, target_currency_id, supply_amount)
+		}
+
+		fn get_best_swap_with_exact_target(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPathInfo<Balance>> {
+			Dex::best_swap_with_exact_target_info(supply_currency_id, target_currency_id, target_amount)
+		}
+
+		fn get_liquidity_pool(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> module_dex_rpc_runtime_api::LiquidityPoolInfo<Balance> {
+			Dex::liquidity_pool_info(currency_id_a, currency_id_b)
+		}
+
+		fn get_provisioning_status(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> Option<module_dex_rpc_runtime_api::ProvisioningInfo<Balance, BlockNumber>> {
+			Dex::provisioning_info(currency_id_a, currency_id_b)
+		}
+
+		fn get_liquidity_share_value(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			share_amount: Balance,
+		) -> module_dex_rpc_runtime_api::LiquidityPoolInfo<Balance> {
+			Dex::liquidity_share_value_info(currency_id_a, currency_id_b, share_amount)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -2196,6 +2445,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_cdp_engine, benchmarking::cdp_engine);
 			orml_list_benchmark!(list, extra, module_emergency_shutdown, benchmarking::emergency_shutdown);
 			orml_list_benchmark!(list, extra, module_evm, benchmarking::evm);
+			orml_list_benchmark!(list, extra, module_homa_validator_selection, benchmarking::homa_validator_selection);
 			orml_list_benchmark!(list, extra, module_honzon, benchmarking::honzon);
 			orml_list_benchmark!(list, extra, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_list_benchmark!(list, extra, module_collator_selection, benchmarking::collator_selection);
@@ -2255,6 +2505,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_cdp_engine, benchmarking::cdp_engine);
 			orml_add_benchmark!(params, batches, module_emergency_shutdown, benchmarking::emergency_shutdown);
 			orml_add_benchmark!(params, batches, module_evm, benchmarking::evm);
+			orml_add_benchmark!(params, batches, module_homa_validator_selection, benchmarking::homa_validator_selection);
 			orml_add_benchmark!(params, batches, module_honzon, benchmarking::honzon);
 			orml_add_benchmark!(params, batches, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_add_benchmark!(params, batches, module_collator_selection, benchmarking::collator_selection);
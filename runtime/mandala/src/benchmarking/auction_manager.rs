@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, CurrencyId, EmergencyShutdown,
-	GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime,
+	dollar, AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, CurrencyId, DutchAuctionResetDuration,
+	EmergencyShutdown, GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime, System,
 };
 
 use super::utils::feed_price;
@@ -27,7 +27,7 @@ use frame_system::RawOrigin;
 use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{traits::Bounded, FixedPointNumber};
 use sp_std::vec;
 
 const SEED: u32 = 0;
@@ -62,6 +62,27 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	// `take_collateral` of a dutch collateral auction, worst case:
+	// the price is reset and the remain collateral is refunded
+	take_collateral {
+		let taker: AccountId = account("taker", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &taker, 200 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// create dutch collateral auction
+		AuctionManager::new_dutch_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+
+		System::set_block_number(System::block_number() + DutchAuctionResetDuration::get());
+	}: _(RawOrigin::Signed(taker), auction_id, dollar(STAKING), Price::max_value())
 }
 
 #[cfg(test)]
@@ -17,28 +17,33 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Address, Amount, Balance, CdpEngine, CdpTreasury, CollateralCurrencyIds, CurrencyId,
-	DefaultDebitExchangeRate, DefaultSwapParitalPathList, Dex, EmergencyShutdown, ExistentialDeposits,
-	GetLiquidCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, MaxAuctionsCount, MinimumDebitValue, Price, Rate,
-	Ratio, Runtime, Timestamp, MILLISECS_PER_BLOCK,
+	dollar, AccountId, Address, Amount, Balance, BlockNumber, CdpEngine, CdpTreasury, CollateralCurrencyIds,
+	CurrencyId, DefaultDebitExchangeRate, DefaultSwapParitalPathList, Dex, EmergencyShutdown, Event,
+	ExistentialDeposits, GetLiquidCurrencyId, GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId,
+	MaxAuctionsCount, MinimumDebitValue, Origin, Price, Rate, Ratio, Runtime, System, Timestamp, TwapWindow, EVM,
+	MILLISECS_PER_BLOCK,
 };
 
 use super::utils::{feed_price, set_balance};
 use core::convert::TryInto;
 use frame_benchmarking::account;
-use frame_support::traits::OnInitialize;
+use frame_support::{dispatch::DispatchError, traits::OnInitialize};
 use frame_system::RawOrigin;
-use module_support::DEXManager;
+use module_cdp_engine::{InterestRateControllerParams, LiquidationStrategyKind};
+use module_dex::SwapCurve;
+use module_support::{AddressMapping, DEXManager};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
+use primitives::evm::EvmAddress;
 use sp_runtime::{
-	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
+	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto, Zero},
 	FixedPointNumber,
 };
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
@@ -54,7 +59,12 @@ fn inject_liquidity(
 	set_balance(currency_id_a, &maker, amount_a.unique_saturated_into());
 	set_balance(currency_id_b, &maker, amount_b.unique_saturated_into());
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		SwapCurve::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -69,6 +79,100 @@ fn inject_liquidity(
 	Ok(())
 }
 
+// deploy a liquidation contract which does nothing, the contract account is funded with the
+// stable currency to repay in advance.
+fn deploy_liquidation_contract(maker: AccountId) -> Result<EvmAddress, DispatchError> {
+	// liquidate(address,uint256,uint256) calls `balanceOf(address(this))` of the collateral
+	// ERC20 passed in, and reverts if the call fails:
+	// PUSH4 0x70a08231 PUSH1 0xe0 SHL PUSH1 0 MSTORE ADDRESS PUSH1 4 MSTORE
+	// PUSH1 0x20 PUSH1 0 PUSH1 0x24 PUSH1 0 PUSH1 4 CALLDATALOAD GAS STATICCALL
+	// PUSH1 0x24 JUMPI PUSH1 0 PUSH1 0 REVERT JUMPDEST STOP
+	let contract = hex_literal::hex!(
+		"6026600c60003960266000f36370a0823160e01b6000523060045260206000602460006004355afa60245760006000fd5b00"
+	)
+	.to_vec();
+
+	set_balance(NATIVE, &maker, 1_000 * dollar(NATIVE));
+	EVM::create(Origin::signed(maker), contract, 0, 1_000_000, 1_000).map_or_else(|e| Err(e.error), |_| Ok(()))?;
+	let address = System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			Event::EVM(module_evm::Event::Created(_, address, _)) => Some(address),
+			_ => None,
+		})
+		.ok_or("liquidation contract is not created")?;
+	EVM::deploy_free(RawOrigin::Root.into(), address).map_err(|e| e.error)?;
+
+	Ok(address)
+}
+
+// deposit stable currency to the stability pool, only mandala has the stability pool
+#[cfg(feature = "with-mandala-runtime")]
+fn deposit_stability_pool(depositor: AccountId, amount: Balance) -> Result<(), DispatchError> {
+	set_balance(STABLECOIN, &depositor, amount);
+	crate::StabilityPool::deposit(RawOrigin::Signed(depositor).into(), amount)
+}
+
+#[cfg(not(feature = "with-mandala-runtime"))]
+fn deposit_stability_pool(_depositor: AccountId, _amount: Balance) -> Result<(), DispatchError> {
+	Ok(())
+}
+
+// open a CDP of STAKING and make it unsafe, return the stable amount to repay by liquidation
+fn open_unsafe_cdp(owner: &AccountId) -> Result<Balance, DispatchError> {
+	let min_debit_value = MinimumDebitValue::get();
+	let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+	let min_debit_amount = debit_exchange_rate
+		.reciprocal()
+		.unwrap()
+		.saturating_mul_int(min_debit_value);
+	let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+	let collateral_value = 2 * min_debit_value;
+	let collateral_amount =
+		Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+	set_balance(STAKING, owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+	feed_price(vec![(STAKING, Price::one())])?;
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		STAKING,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(min_debit_value * 100),
+		Change::NoChange,
+	)?;
+	CdpEngine::adjust_position(owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+
+	// modify liquidation rate to make the cdp unsafe
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		STAKING,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	)?;
+
+	Ok(Rate::saturating_from_rational(110, 100).saturating_mul_int(min_debit_value))
+}
+
+fn interest_rate_controller_params() -> InterestRateControllerParams<BlockNumber> {
+	InterestRateControllerParams {
+		period: 10,
+		proportional_gain: Rate::saturating_from_rational(1, 1000),
+		integral_gain: Rate::saturating_from_rational(1, 10000),
+		derivative_gain: Rate::saturating_from_rational(1, 10000),
+		min_interest_rate_per_sec: Rate::zero(),
+		max_interest_rate_per_sec: Rate::saturating_from_rational(1, 100000000),
+		max_step: Rate::saturating_from_rational(1, 10000000000u64),
+	}
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_cdp_engine }
 
@@ -107,6 +211,7 @@ runtime_benchmarks! {
 				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(0, 100))),
 				Change::NewValue(min_debit_value * 100),
+				Change::NoChange,
 			)?;
 
 			// adjust position
@@ -128,12 +233,47 @@ runtime_benchmarks! {
 		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 		Change::NewValue(Some(Ratio::saturating_from_rational(180, 100))),
-		Change::NewValue(100_000 * dollar(STABLECOIN))
+		Change::NewValue(100_000 * dollar(STABLECOIN)),
+		Change::NewValue(Some(Ratio::saturating_from_rational(50, 100)))
 	)
 
 	set_global_params {
 	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1000000))
 
+	set_basket_params {
+	}: _(
+		RawOrigin::Root,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 1000000))),
+		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+		Change::NewValue(100_000 * dollar(STABLECOIN))
+	)
+
+	set_liquidation_strategies {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		vec![LiquidationStrategyKind::Exchange, LiquidationStrategyKind::StabilityPool, LiquidationStrategyKind::Auction]
+	)
+
+	set_interest_rate_controller {
+	}: _(RawOrigin::Root, Some(interest_rate_controller_params()))
+
+	adjust_global_interest_rate {
+		let maker: AccountId = account("maker", 0, SEED);
+		let params = interest_rate_controller_params();
+
+		// the market price of stable currency is derived from the TWAP of DEX
+		inject_liquidity(maker.clone(), STAKING, STABLECOIN, 100 * dollar(STAKING), 1_100 * dollar(STABLECOIN))?;
+		System::set_block_number(System::block_number() + TwapWindow::get());
+		inject_liquidity(maker, STAKING, STABLECOIN, 100 * dollar(STAKING), 1_100 * dollar(STABLECOIN))?;
+		feed_price(vec![(STAKING, Price::saturating_from_rational(10, 1))])?;
+
+		CdpEngine::set_interest_rate_controller(RawOrigin::Root.into(), Some(params.clone()))?;
+		Timestamp::set_timestamp(MILLISECS_PER_BLOCK);
+	}: {
+		CdpEngine::on_initialize(params.period);
+	}
+
 	// `liquidate` by_auction
 	liquidate_by_auction {
 		let b in 1 .. MaxAuctionsCount::get();
@@ -163,6 +303,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		let auction_size = collateral_amount / b as u128;
@@ -180,6 +321,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 
@@ -219,6 +361,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -233,6 +376,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, LIQUID, owner_lookup)
 	verify {
@@ -246,6 +390,42 @@ runtime_benchmarks! {
 		assert!(stable_amount_mandala < 10_000 * dollar(STABLECOIN));
 	}
 
+	// `liquidate` by the liquidation contract in EVM
+	liquidate_by_evm_contract {
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let maker: AccountId = account("maker", 0, SEED);
+
+		let contract = deploy_liquidation_contract(maker)?;
+		let target_stable_amount = open_unsafe_cdp(&owner)?;
+		CdpEngine::set_liquidation_strategies(
+			RawOrigin::Root.into(),
+			STAKING,
+			vec![LiquidationStrategyKind::EvmContract(contract)],
+		)?;
+
+		// the contract holds the stable currency to repay, and CDP engine pays the storage deposit
+		let contract_account = <Runtime as module_cdp_engine::Config>::EvmAddressMapping::get_account_id(&contract);
+		set_balance(STABLECOIN, &contract_account, target_stable_amount);
+		set_balance(NATIVE, &CdpEngine::account_id(), 1_000 * dollar(NATIVE));
+	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
+
+	// `liquidate` by the stability pool
+	liquidate_by_stability_pool {
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let depositor: AccountId = account("depositor", 0, SEED);
+
+		deposit_stability_pool(depositor, 1_000 * dollar(STABLECOIN))?;
+		open_unsafe_cdp(&owner)?;
+		// runtimes without the stability pool fall back to auction
+		CdpEngine::set_liquidation_strategies(
+			RawOrigin::Root.into(),
+			STAKING,
+			vec![LiquidationStrategyKind::StabilityPool, LiquidationStrategyKind::Auction],
+		)?;
+	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
+
 	settle {
 		let owner: AccountId = account("owner", 0, SEED);
 		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
@@ -272,6 +452,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -280,6 +461,46 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, STAKING, owner_lookup)
+
+	settle_basket {
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let currency_ids = CollateralCurrencyIds::get();
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_basket_debit_exchange_rate();
+		let collateral_price = Price::one();		// 1 USD
+		let debit_value = min_debit_value * currency_ids.len() as Balance;
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		for currency_id in currency_ids {
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &owner, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// feed price
+			feed_price(vec![(currency_id, collateral_price)])?;
+
+			collateral_adjustments.push((currency_id, collateral_amount.try_into().unwrap()));
+		}
+
+		// set risk params
+		CdpEngine::set_basket_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// adjust basket position
+		CdpEngine::adjust_basket_position(&owner, &collateral_adjustments, debit_amount)?;
+
+		// shutdown
+		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+	}: _(RawOrigin::None, owner_lookup)
 }
 
 #[cfg(test)]
@@ -17,20 +17,33 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, CdpTreasury, Currencies, CurrencyId, GetStableCurrencyId, GetStakingCurrencyId, MaxAuctionsCount, Runtime,
+	dollar, CdpTreasury, Currencies, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId,
+	MaxAuctionsCount, Runtime,
 };
 
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_cdp_treasury::CollateralAuctionMode;
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 
 runtime_benchmarks! {
 	{ Runtime, module_cdp_treasury }
 
+	on_initialize {
+		let c in 0 .. MaxAuctionsCount::get();
+
+		CdpTreasury::set_debit_and_surplus_handle_params(RawOrigin::Root.into(), None, None, Some(100 * dollar(STABLECOIN)), Some(200 * dollar(NATIVE)))?;
+		CdpTreasury::on_system_debit(c as u128 * 100 * dollar(STABLECOIN))?;
+	}: {
+		CdpTreasury::on_initialize(1);
+	}
+
 	auction_collateral {
 		let b in 1 .. MaxAuctionsCount::get();
 
@@ -43,6 +56,12 @@ runtime_benchmarks! {
 	set_expected_collateral_auction_size {
 	}: _(RawOrigin::Root, STAKING, 200 * dollar(STAKING))
 
+	set_collateral_auction_mode {
+	}: _(RawOrigin::Root, STAKING, CollateralAuctionMode::Dutch)
+
+	set_debit_and_surplus_handle_params {
+	}: _(RawOrigin::Root, Some(100 * dollar(STABLECOIN)), Some(1_000 * dollar(STABLECOIN)), Some(1_000 * dollar(STABLECOIN)), Some(200 * dollar(NATIVE)))
+
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
@@ -17,18 +17,23 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, Event, GetLiquidCurrencyId, GetNativeCurrencyId,
-	GetStableCurrencyId, GetStakingCurrencyId, Runtime, System, TradingPathLimit,
+	dollar, AccountId, Balance, Call, Currencies, CurrencyId, Dex, Event, GetLiquidCurrencyId, GetNativeCurrencyId,
+	GetStableCurrencyId, GetStakingCurrencyId, MaxSearchedTradingPairs, MaxSearchedTradingPaths, Runtime,
+	SwapSplitParts, System, TradingPathLimit,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{SwapCurve, TradingPairStatus};
+use module_support::Ratio;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use primitives::TradingPair;
+use primitives::{evm::EvmAddress, TradingPair};
 use runtime_common::{BNC, RENBTC, VSKSM};
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+	traits::{AccountIdLookup, StaticLookup, UniqueSaturatedInto},
+	FixedPointNumber,
+};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -64,7 +69,12 @@ fn inject_liquidity(
 		max_amount_b.unique_saturated_into(),
 	)?;
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		SwapCurve::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -79,6 +89,33 @@ fn inject_liquidity(
 	Ok(())
 }
 
+fn inject_enabled_trading_pair(trading_pair: TradingPair) {
+	let _ = module_dex::EnabledTradingPairs::<Runtime>::try_mutate(|enabled_trading_pairs| {
+		enabled_trading_pairs.try_push(trading_pair)
+	});
+	module_dex::TradingPairStatuses::<Runtime>::insert(trading_pair, TradingPairStatus::Enabled);
+	module_dex::LiquidityPool::<Runtime>::insert(trading_pair, (dollar(NATIVE), dollar(NATIVE)));
+}
+
+// enable the trading pairs which connect `supply_currency_id` and `target_currency_id` through
+// `count` ERC20 currencies, so that the router finds `count` more trading paths between them. The
+// rest of `MaxSearchedTradingPairs` is filled with trading pairs which start from
+// `supply_currency_id` but lead nowhere, they're all read and extended during the search.
+fn inject_searched_trading_paths(supply_currency_id: CurrencyId, target_currency_id: CurrencyId, count: u32) {
+	for i in 0..count {
+		let erc20 = CurrencyId::Erc20(EvmAddress::from_low_u64_be((i + 1) as u64));
+		inject_enabled_trading_pair(TradingPair::from_currency_ids(supply_currency_id, erc20).unwrap());
+		inject_enabled_trading_pair(TradingPair::from_currency_ids(erc20, target_currency_id).unwrap());
+	}
+
+	let mut i = count;
+	while (Dex::enabled_trading_pairs().len() as u32) < MaxSearchedTradingPairs::get() {
+		i += 1;
+		let erc20 = CurrencyId::Erc20(EvmAddress::from_low_u64_be(i as u64));
+		inject_enabled_trading_pair(TradingPair::from_currency_ids(supply_currency_id, erc20).unwrap());
+	}
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_dex }
 
@@ -88,7 +125,7 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), SwapCurve::ConstantProduct)
 	verify {
 		assert_last_event(module_dex::Event::EnableTradingPair(trading_pair).into());
 	}
@@ -97,7 +134,7 @@ runtime_benchmarks! {
 	disable_trading_pair {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
 		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
-			Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+			Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), SwapCurve::ConstantProduct)?;
 		}
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
 	verify {
@@ -110,7 +147,7 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10)
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10, SwapCurve::ConstantProduct)
 	verify {
 		assert_last_event(module_dex::Event::ListProvisioning(trading_pair).into());
 	}
@@ -129,7 +166,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			100
+			100,
+			SwapCurve::ConstantProduct,
 		)?;
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), 2 * dollar(trading_pair.first()), 2 * dollar(trading_pair.second()), 10 * dollar(trading_pair.first()), 100 * dollar(trading_pair.second()), 200)
 
@@ -148,7 +186,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			100 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -182,7 +221,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -207,7 +247,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			10 * dollar(trading_pair.first()),
 			10 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -330,6 +371,155 @@ runtime_benchmarks! {
 		// would panic the benchmark anyways, must add new currencies to CURRENCY_LIST for benchmarking to work
 		assert!(TradingPathLimit::get() < CURRENCY_LIST.len() as u32);
 	}
+
+	swap_with_exact_supply_by_best_path {
+		let p in 1 .. MaxSearchedTradingPaths::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), STAKING, RENBTC, 10_000 * dollar(STAKING), 10_000 * dollar(RENBTC), false)?;
+		inject_searched_trading_paths(STAKING, RENBTC, p - 1);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+	}: swap_with_exact_supply_by_best_path(RawOrigin::Signed(taker.clone()), STAKING, RENBTC, 100 * dollar(STAKING), 0)
+
+	swap_with_exact_target_by_best_path {
+		let p in 1 .. MaxSearchedTradingPaths::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), STAKING, RENBTC, 10_000 * dollar(STAKING), 10_000 * dollar(RENBTC), false)?;
+		inject_searched_trading_paths(STAKING, RENBTC, p - 1);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+	}: swap_with_exact_target_by_best_path(RawOrigin::Signed(taker.clone()), STAKING, RENBTC, 10 * dollar(RENBTC), 100 * dollar(STAKING))
+
+	swap_with_exact_supply_by_split_paths {
+		let u in 1 .. SwapSplitParts::get();
+		let p in 1 .. MaxSearchedTradingPaths::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), STAKING, RENBTC, 10_000 * dollar(STAKING), 10_000 * dollar(RENBTC), false)?;
+		inject_searched_trading_paths(STAKING, RENBTC, p - 1);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+	}: {
+		Dex::do_swap_with_exact_supply_by_split_paths(&taker, STAKING, RENBTC, 1_000 * dollar(STAKING), 0, u)?;
+	}
+
+	place_limit_order {
+		let p in 1 .. MaxSearchedTradingPaths::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), STAKING, RENBTC, 10_000 * dollar(STAKING), 10_000 * dollar(RENBTC), false)?;
+		inject_searched_trading_paths(STAKING, RENBTC, p - 1);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &taker, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(taker.clone()), STAKING, RENBTC, 100 * dollar(STAKING), 1_000 * dollar(RENBTC), 100)
+	verify {
+		assert!(Dex::limit_orders(&taker, 0).is_some());
+	}
+
+	cancel_limit_order {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), NATIVE, STAKING, 10_000 * dollar(NATIVE), 10_000 * dollar(STAKING), false)?;
+		inject_liquidity(maker.clone(), NATIVE, RENBTC, 10_000 * dollar(NATIVE), 10_000 * dollar(RENBTC), false)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &taker, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		Dex::place_limit_order(RawOrigin::Signed(taker.clone()).into(), STAKING, RENBTC, 100 * dollar(STAKING), 1_000 * dollar(RENBTC), 100)?;
+	}: _(RawOrigin::Signed(taker.clone()), 0)
+	verify {
+		assert!(Dex::limit_orders(&taker, 0).is_none());
+	}
+
+	fill_limit_order {
+		let p in 1 .. MaxSearchedTradingPaths::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		// the best trading path is the longest one, and the router evaluates p trading paths to
+		// find it.
+		let path: Vec<CurrencyId> = vec![STAKING, NATIVE, STABLECOIN, RENBTC];
+		for i in 1 .. path.len() {
+			inject_liquidity(maker.clone(), path[i - 1], path[i], 10_000 * dollar(path[i - 1]), 10_000 * dollar(path[i]), false)?;
+		}
+		inject_searched_trading_paths(STAKING, RENBTC, p - 1);
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &taker, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		Dex::place_limit_order(RawOrigin::Signed(taker.clone()).into(), STAKING, RENBTC, 100 * dollar(STAKING), dollar(RENBTC), 100)?;
+		let taker_lookup = AccountIdLookup::unlookup(taker.clone());
+	}: _(RawOrigin::None, taker_lookup, 0)
+	verify {
+		// would panic the benchmark anyways, the longest trading path must be searched
+		assert_eq!(TradingPathLimit::get(), path.len() as u32);
+		assert!(Dex::limit_orders(&taker, 0).is_none());
+	}
+
+	remove_expired_limit_order {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		inject_liquidity(maker.clone(), NATIVE, STAKING, 10_000 * dollar(NATIVE), 10_000 * dollar(STAKING), false)?;
+		inject_liquidity(maker.clone(), NATIVE, RENBTC, 10_000 * dollar(NATIVE), 10_000 * dollar(RENBTC), false)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STAKING, &taker, (10_000 * dollar(STAKING)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &taker, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		Dex::place_limit_order(RawOrigin::Signed(taker.clone()).into(), STAKING, RENBTC, 100 * dollar(STAKING), 1_000 * dollar(RENBTC), 100)?;
+		System::set_block_number(101);
+		let taker_lookup = AccountIdLookup::unlookup(taker.clone());
+	}: _(RawOrigin::None, taker_lookup, 0)
+	verify {
+		assert!(Dex::limit_orders(&taker, 0).is_none());
+	}
+
+	set_trading_pair_fee {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((3, 1000)))
+	verify {
+		assert_eq!(Dex::trading_pair_fees(trading_pair), Some((3, 1000)));
+	}
+
+	flash_swap {
+		let u in 2 .. TradingPathLimit::get() as u32;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		let mut path: Vec<CurrencyId> = vec![];
+		for i in 1 .. u {
+			if i == 1 {
+				let cur0 = CURRENCY_LIST[0];
+				let cur1 = CURRENCY_LIST[1];
+				path.push(cur0);
+				path.push(cur1);
+				inject_liquidity(maker.clone(), cur0, cur1, 10_000 * dollar(cur0), 10_000 * dollar(cur1), false)?;
+			} else {
+				path.push(CURRENCY_LIST[i as usize]);
+				inject_liquidity(maker.clone(), CURRENCY_LIST[i as usize - 1], CURRENCY_LIST[i as usize], 10_000 * dollar(CURRENCY_LIST[i as usize - 1]), 10_000 * dollar(CURRENCY_LIST[i as usize]), false)?;
+			}
+		}
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
+		let call = Box::new(Call::System(frame_system::Call::remark { remark: vec![] }));
+	}: _(RawOrigin::Signed(taker.clone()), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]), call)
+
+	set_protocol_fee_share {
+	}: _(RawOrigin::Root, Ratio::saturating_from_rational(1, 5))
+	verify {
+		assert_eq!(Dex::protocol_fee_share(), Ratio::saturating_from_rational(1, 5));
+	}
 }
 
 #[cfg(test)]
@@ -72,6 +72,17 @@ runtime_benchmarks! {
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
 	}: _(RawOrigin::Signed(caller),  1_000 * dollar(STABLECOIN))
+
+	freeze_collateral {
+		let currency_id = CollateralCurrencyIds::get()[0];
+		feed_price(vec![(currency_id, Price::one())])?;
+	}: _(RawOrigin::Root, currency_id)
+
+	unfreeze_collateral {
+		let currency_id = CollateralCurrencyIds::get()[0];
+		feed_price(vec![(currency_id, Price::one())])?;
+		EmergencyShutdown::freeze_collateral(RawOrigin::Root.into(), currency_id)?;
+	}: _(RawOrigin::Root, currency_id)
 }
 
 #[cfg(test)]
@@ -0,0 +1,75 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	AccountId, CurrencyId, GetLiquidCurrencyId, HomaValidatorListModule, HomaValidatorSelection,
+	MaxValidatorCandidates, MinGuaranteeAmount, Runtime,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use module_support::OnNewEra;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
+
+fn bond_validators(c: u32) {
+	for i in 0..c {
+		let guarantor: AccountId = account("guarantor", i, SEED);
+		let validator: AccountId = account("validator", i, SEED);
+		set_balance(LIQUID, &guarantor, 2 * MinGuaranteeAmount::get());
+		let _ = HomaValidatorListModule::bond(
+			RawOrigin::Signed(guarantor).into(),
+			validator,
+			MinGuaranteeAmount::get(),
+		);
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_homa_validator_selection }
+
+	force_nominate {
+		let c in 1 .. MaxValidatorCandidates::get();
+
+		bond_validators(c);
+	}: {
+		let _ = HomaValidatorSelection::force_nominate(RawOrigin::Root.into());
+	}
+
+	on_new_era {
+		let c in 1 .. MaxValidatorCandidates::get();
+
+		bond_validators(c);
+	}: {
+		HomaValidatorSelection::on_new_era(1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -18,15 +18,16 @@
 
 use crate::{
 	dollar, AccountId, Amount, Balance, CdpEngine, CollateralCurrencyIds, Currencies, CurrencyId,
-	DefaultSwapParitalPathList, DepositPerAuthorization, Dex, ExistentialDeposits, GetLiquidCurrencyId,
-	GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Honzon, Price, Rate, Ratio, Runtime,
-	TradingPathLimit,
+	DefaultSwapParitalPathList, DepositPerAuthorization, DepositPerAutoDeleverageRule, Dex, ExistentialDeposits,
+	GetLiquidCurrencyId, GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Honzon,
+	MaxRedemptionCandidates, Price, Rate, Ratio, Runtime, TradingPathLimit,
 };
 
 use super::utils::{feed_price, set_balance};
 use core::convert::TryInto;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
+use module_dex::SwapCurve;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey, MultiCurrencyExtended};
 use runtime_common::{BNC, RENBTC, VSKSM};
@@ -65,7 +66,12 @@ fn inject_liquidity(
 		max_amount_b.unique_saturated_into(),
 	)?;
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		SwapCurve::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -125,6 +131,45 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Signed(caller))
 
+	authorize_with_limits {
+		let caller: AccountId = whitelisted_caller();
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = AccountIdLookup::unlookup(to);
+
+		// set balance
+		set_balance(NATIVE, &caller, DepositPerAuthorization::get());
+	}: _(RawOrigin::Signed(caller), STAKING, to_lookup, module_honzon::Permissions::all(), Some(100 * dollar(STABLECOIN)), Some(100u32))
+
+	// `check_authorization`, worst case:
+	// authorization has limits and spending value need the price
+	check_authorization {
+		let caller: AccountId = whitelisted_caller();
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = AccountIdLookup::unlookup(to.clone());
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+
+		// set balance and feed price
+		set_balance(NATIVE, &caller, DepositPerAuthorization::get());
+		feed_price(vec![(currency_id, Price::one())])?;
+		Honzon::authorize_with_limits(
+			RawOrigin::Signed(caller.clone()).into(),
+			currency_id,
+			to_lookup,
+			module_honzon::Permissions::all(),
+			Some(1_000 * dollar(STABLECOIN)),
+			Some(100u32),
+		)?;
+	}: {
+		Honzon::check_authorization(
+			&caller,
+			&to,
+			currency_id,
+			module_honzon::Permissions::all(),
+			100 * dollar(currency_id),
+			100 * dollar(STABLECOIN),
+		)?;
+	}
+
 	// `adjust_loan`, best case:
 	// adjust both collateral and debit
 	adjust_loan {
@@ -153,9 +198,44 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 	}: _(RawOrigin::Signed(caller), currency_id, collateral_amount.try_into().unwrap(), debit_amount)
 
+	adjust_basket_loan {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+
+		let caller: AccountId = whitelisted_caller();
+		let currency_ids: Vec<CurrencyId> = CollateralCurrencyIds::get().into_iter().take(c as usize).collect();
+		let collateral_price = Price::one();		// 1 USD
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_basket_debit_exchange_rate();
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+
+		let mut collateral_adjustments: Vec<(CurrencyId, Amount)> = vec![];
+		for currency_id in currency_ids {
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &caller, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// feed price
+			feed_price(vec![(currency_id, collateral_price)])?;
+
+			collateral_adjustments.push((currency_id, collateral_amount.try_into().unwrap()));
+		}
+
+		// set risk params
+		CdpEngine::set_basket_params(
+			RawOrigin::Root.into(),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(debit_value * 100),
+		)?;
+	}: _(RawOrigin::Signed(caller), collateral_adjustments, debit_amount)
+
 	transfer_loan_from {
 		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
 		let sender: AccountId = account("sender", 0, SEED);
@@ -186,6 +266,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -253,6 +334,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -297,6 +379,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -308,6 +391,166 @@ runtime_benchmarks! {
 		)?;
 
 	}: close_loan_has_debit_by_dex(RawOrigin::Signed(sender), currency_id, collateral_amount, None)
+
+	// `redeem`, worst case:
+	// redeem the whole debit of `c` CDPs with the lowest collateral ratio
+	redeem {
+		let c in 1 .. MaxRedemptionCandidates::get();
+
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let redeemer: AccountId = whitelisted_caller();
+		let collateral_price = Price::one();		// 1 USD
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// feed price
+		feed_price(vec![(currency_id, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+			Change::NoChange,
+		)?;
+
+		// initialize the loans of candidates
+		for i in 0 .. c {
+			let owner: AccountId = account("owner", i, SEED);
+			set_balance(currency_id, &owner, collateral_amount + ExistentialDeposits::get(&currency_id));
+			Honzon::adjust_loan(
+				RawOrigin::Signed(owner.clone()).into(),
+				currency_id,
+				collateral_amount.try_into().unwrap(),
+				debit_amount,
+			)?;
+		}
+
+		let stable_amount = debit_value * c as Balance;
+		set_balance(STABLECOIN, &redeemer, stable_amount + ExistentialDeposits::get(&STABLECOIN));
+	}: _(RawOrigin::Signed(redeemer), currency_id, stable_amount, Rate::one())
+
+	set_auto_deleverage {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let collateral_amount = 100 * dollar(currency_id);
+
+		// set balance and open the position of caller
+		set_balance(currency_id, &caller, collateral_amount + ExistentialDeposits::get(&currency_id));
+		set_balance(NATIVE, &caller, DepositPerAutoDeleverageRule::get());
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(100 * dollar(STABLECOIN)),
+			Change::NoChange,
+		)?;
+		Honzon::adjust_loan(
+			RawOrigin::Signed(caller.clone()).into(),
+			currency_id,
+			collateral_amount.try_into().unwrap(),
+			0,
+		)?;
+	}: _(RawOrigin::Signed(caller), currency_id, Ratio::saturating_from_integer(2), Ratio::saturating_from_integer(3))
+
+	remove_auto_deleverage {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let collateral_amount = 100 * dollar(currency_id);
+
+		// set balance and open the position of caller
+		set_balance(currency_id, &caller, collateral_amount + ExistentialDeposits::get(&currency_id));
+		set_balance(NATIVE, &caller, DepositPerAutoDeleverageRule::get());
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(100 * dollar(STABLECOIN)),
+			Change::NoChange,
+		)?;
+		Honzon::adjust_loan(
+			RawOrigin::Signed(caller.clone()).into(),
+			currency_id,
+			collateral_amount.try_into().unwrap(),
+			0,
+		)?;
+		Honzon::set_auto_deleverage(
+			RawOrigin::Signed(caller.clone()).into(),
+			currency_id,
+			Ratio::saturating_from_integer(2),
+			Ratio::saturating_from_integer(3),
+		)?;
+	}: _(RawOrigin::Signed(caller), currency_id)
+
+	// `trigger_auto_deleverage`, worst case:
+	// swap collateral by the longest default path
+	trigger_auto_deleverage {
+		let currency_id: CurrencyId = LIQUID;
+		let mut default_path: Vec<CurrencyId> = DefaultSwapParitalPathList::get().last().unwrap().clone();
+
+		let caller: AccountId = whitelisted_caller();
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let maker: AccountId = account("maker", 0, SEED);
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+		// set balance and trading path
+		set_balance(currency_id, &owner, collateral_amount + ExistentialDeposits::get(&currency_id));
+		set_balance(NATIVE, &owner, DepositPerAutoDeleverageRule::get());
+
+		default_path.insert(0, currency_id);
+		for i in 0..default_path.len() {
+			if i != 0 {
+				inject_liquidity(maker.clone(), default_path[i], default_path[i-1], 10_000 * dollar(default_path[i]), 10_000 * dollar(default_path[i-1]), false)?;
+			}
+		}
+
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+			Change::NoChange,
+		)?;
+
+		// initialize owner's loan and the auto-deleverage rule which is triggered
+		Honzon::adjust_loan(
+			RawOrigin::Signed(owner.clone()).into(),
+			currency_id,
+			collateral_amount.try_into().unwrap(),
+			debit_amount,
+		)?;
+		Honzon::set_auto_deleverage(
+			RawOrigin::Signed(owner).into(),
+			currency_id,
+			Ratio::saturating_from_integer(15),
+			Ratio::saturating_from_integer(20),
+		)?;
+	}: _(RawOrigin::Signed(caller), owner_lookup, currency_id)
 }
 
 #[cfg(test)]
@@ -34,12 +34,14 @@ pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
 pub mod homa;
+pub mod homa_validator_selection;
 pub mod honzon;
 pub mod incentives;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
 pub mod prices;
 pub mod session_manager;
+pub mod stability_pool;
 pub mod transaction_pause;
 pub mod transaction_payment;
 
@@ -49,8 +49,10 @@ use frame_system::{EnsureRoot, RawOrigin};
 use hex_literal::hex;
 use module_asset_registry::{EvmErc20InfoMapping, FixedRateOfForeignAsset, XcmForeignAssetIdMapping};
 use module_currencies::{BasicCurrencyAdapter, Currency};
+use module_emergency_shutdown::EmergencyShutdownTask;
 use module_evm::{CallInfo, CreateInfo, EvmTask, Runner};
 use module_evm_accounts::EvmAddressMapping;
+use module_honzon::HonzonTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{DispatchableTask, ExchangeRateProvider, ForeignAssetIdMapping};
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -120,9 +122,9 @@ pub use runtime_common::{
 	EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate, FinancialCouncilInstance,
 	FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance, GeneralCouncilMembershipInstance,
 	HomaCouncilInstance, HomaCouncilMembershipInstance, OffchainSolutionWeightLimit, OperatorMembershipInstanceAcala,
-	Price, ProxyType, Rate, Ratio, RelayChainBlockNumberProvider, RelayChainSubAccountId, RuntimeBlockLength,
-	RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance,
-	TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
+	Price, ProxyType, Rate, Ratio, RelayChainBlockNumberProvider, RelayChainStateRootProvider, RelayChainSubAccountId,
+	RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance,
+	TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
 };
 
 /// Import the stable_asset pallet.
@@ -167,10 +169,12 @@ parameter_types! {
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const StakingPoolPalletId: PalletId = PalletId(*b"aca/stkp");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const StabilityPoolPalletId: PalletId = PalletId(*b"aca/stbp");
 	pub const CollatorPotId: PalletId = PalletId(*b"aca/cpot");
 	// Treasury reserve
 	pub const TreasuryReservePalletId: PalletId = PalletId(*b"aca/reve");
@@ -189,10 +193,12 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		LoansPalletId::get().into_account(),
 		DEXPalletId::get().into_account(),
 		CDPTreasuryPalletId::get().into_account(),
+		CDPEnginePalletId::get().into_account(),
 		StakingPoolPalletId::get().into_account(),
 		HonzonTreasuryPalletId::get().into_account(),
 		HomaTreasuryPalletId::get().into_account(),
 		IncentivesPalletId::get().into_account(),
+		StabilityPoolPalletId::get().into_account(),
 		TreasuryReservePalletId::get().into_account(),
 		CollatorPotId::get().into_account(),
 		StarportPalletId::get().into_account(),
@@ -830,11 +836,12 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const MaxOraclePriceAge: Moment = 1000 * 60 * 60 * 2; // 2 hours
 }
 
 impl module_prices::Config for Runtime {
 	type Event = Event;
-	type Source = AggregatedDataProvider;
+	type Source = runtime_common::FreshPriceDataProvider<AggregatedDataProvider, Timestamp, MaxOraclePriceAge>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -844,6 +851,7 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type CollateralFreeze = EmergencyShutdown;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -930,6 +938,9 @@ parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(20, 100);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 1000);
+	pub const DutchAuctionResetDuration: BlockNumber = 2 * HOURS;
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStableCurrencyId::get()],
 	];
@@ -943,12 +954,17 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type CollateralFreeze = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type DutchAuctionResetDuration = DutchAuctionResetDuration;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1028,11 +1044,20 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub const LiquidationContractGasLimit: u64 = 2_100_000;
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type FallbackPriceSource = module_dex::DexTwapPriceProvider<Runtime, GetStableCurrencyId, StableCurrencyFixedPrice>;
+	type StablePriceSource = module_dex::DexStableCurrencyPriceProvider<
+		Runtime,
+		GetStableCurrencyId,
+		GetStakingCurrencyId,
+		module_prices::RealTimePriceProvider<Runtime>,
+		StableCurrencyTwapMaxAge,
+	>;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1044,22 +1069,58 @@ impl module_cdp_engine::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type CollateralFreeze = EmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type PalletId = CDPEnginePalletId;
+	type LiquidationEvmBridge = EVMBridge;
+	type LiquidationContractGasLimit = LiquidationContractGasLimit;
+	type GasToWeight = GasToWeight;
+	type EvmAddressMapping = EvmAddressMapping<Runtime>;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type StabilityPool = StabilityPool;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = dollar(ACA);
+	pub DepositPerAutoDeleverageRule: Balance = dollar(ACA);
+	pub RedemptionFeeFloor: Rate = Rate::saturating_from_rational(5, 1000);
+	// half-life of redemption base rate is about 12 hours
+	pub RedemptionBaseRateDecay: Rate = Rate::saturating_from_rational(999_807, 1_000_000);
+	pub const MaxRedemptionCandidates: u32 = 20;
 }
 
 impl module_honzon::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type RedemptionFeeFloor = RedemptionFeeFloor;
+	type RedemptionBaseRateDecay = RedemptionBaseRateDecay;
+	type MaxRedemptionCandidates = MaxRedemptionCandidates;
+	type DepositPerAutoDeleverageRule = DepositPerAutoDeleverageRule;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub MinimumStabilityPoolDeposit: Balance = 10 * dollar(AUSD);
+}
+
+impl module_stability_pool::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type Incentives = Incentives;
+	type MinimumDeposit = MinimumStabilityPoolDeposit;
+	type EmergencyShutdown = EmergencyShutdown;
+	type PalletId = StabilityPoolPalletId;
+	type WeightInfo = weights::module_stability_pool::WeightInfo<Runtime>;
+}
+
 impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
@@ -1067,12 +1128,23 @@ impl module_emergency_shutdown::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
+	type CDPSettlement = CdpEngine;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub const TradingPathLimit: u32 = 4;
+	pub const SwapSplitParts: u32 = 10;
+	pub const MaxSearchedTradingPairs: u32 = 30;
+	pub const MaxSearchedTradingPaths: u32 = 10;
+	pub LimitOrderDeposit: Balance = dollar(ACA);
+	pub const MaxLimitOrdersPerAccount: u32 = 20;
+	pub const TwapWindow: BlockNumber = 30 * MINUTES;
+	pub const TwapMaxAge: BlockNumber = 2 * HOURS;
+	pub const StableCurrencyTwapMaxAge: BlockNumber = HOURS;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1086,11 +1158,22 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type SwapSplitParts = SwapSplitParts;
+	type MaxSearchedTradingPairs = MaxSearchedTradingPairs;
+	type MaxSearchedTradingPaths = MaxSearchedTradingPaths;
+	type TwapWindow = TwapWindow;
+	type TwapMaxAge = TwapMaxAge;
 	type PalletId = DEXPalletId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type LimitOrderDeposit = LimitOrderDeposit;
+	type MaxLimitOrdersPerAccount = MaxLimitOrdersPerAccount;
 	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type UnsignedPriority = runtime_common::DexUnsignedPriority;
+	type ProtocolFeeReceiver = TreasuryAccount;
+	type Call = Call;
 }
 
 parameter_types! {
@@ -1120,6 +1203,8 @@ impl module_transaction_pause::Config for Runtime {
 parameter_types! {
 	// Sort by fee charge order
 	pub DefaultFeeSwapPathList: Vec<Vec<CurrencyId>> = vec![vec![AUSD, ACA], vec![AUSD, LDOT], vec![AUSD, DOT], vec![AUSD, RENBTC]];
+	pub DefaultFeeSwapWeight: Weight =
+		<weights::module_dex::WeightInfo<Runtime> as module_dex::WeightInfo>::swap_with_exact_target_by_best_path(MaxSearchedTradingPaths::get());
 }
 
 type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
@@ -1153,6 +1238,7 @@ impl module_transaction_payment::Config for Runtime {
 	type WeightToFee = WeightToFee;
 	type FeeMultiplierUpdate = TargetedFeeAdjustment<Self, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
 	type DEX = Dex;
+	type DefaultFeeSwapWeight = DefaultFeeSwapWeight;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
@@ -1209,7 +1295,7 @@ parameter_types! {
 
 impl module_polkadot_bridge::Config for Runtime {
 	type DOTCurrency = Currency<Runtime, GetStakingCurrencyId>;
-	type OnNewEra = (NomineesElection, StakingPool);
+	type OnNewEra = (NomineesElection, StakingPool, HomaValidatorSelection);
 	type BondingDuration = PolkadotBondingDuration;
 	type EraLength = EraLength;
 	type PolkadotAccountId = AccountId;
@@ -1221,6 +1307,7 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 100);	// 1 : 10
 	pub PoolAccountIndexes: Vec<u32> = vec![1, 2, 3, 4];
+	pub MaxSlashCompensationSlippage: Ratio = Ratio::saturating_from_rational(5, 100);
 }
 
 impl module_staking_pool::Config for Runtime {
@@ -1235,6 +1322,8 @@ impl module_staking_pool::Config for Runtime {
 	type Nominees = NomineesElection;
 	type Bridge = PolkadotBridge;
 	type Currency = Currencies;
+	type DEX = Dex;
+	type MaxSlashCompensationSlippage = MaxSlashCompensationSlippage;
 }
 
 impl module_homa::Config for Runtime {
@@ -1252,6 +1341,20 @@ pub fn create_x2_parachain_multilocation(index: u16) -> MultiLocation {
 	)
 }
 
+pub struct SubAccountIndexMultiLocationConvertor;
+impl Convert<u16, MultiLocation> for SubAccountIndexMultiLocationConvertor {
+	fn convert(sub_account_index: u16) -> MultiLocation {
+		create_x2_parachain_multilocation(sub_account_index)
+	}
+}
+
+pub struct SubAccountIndexAccountIdConvertor;
+impl Convert<u16, AccountId> for SubAccountIndexAccountIdConvertor {
+	fn convert(sub_account_index: u16) -> AccountId {
+		Utility::derivative_account_id(ParachainInfo::get().into_account(), sub_account_index)
+	}
+}
+
 parameter_types! {
 	pub MinimumMintThreshold: Balance = 5 * dollar(DOT);
 	pub MinimumRedeemThreshold: Balance = 50 * dollar(LDOT);
@@ -1264,13 +1367,19 @@ parameter_types! {
 	pub MintFee: Balance = 20 * millicent(DOT);
 	pub BaseWithdrawFee: Permill = Permill::from_rational(14_085u32, 1_000_000u32); // 20% yield per year, unbounding period = 28 days. 1.2^(28/365) = 1.014085
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
+	pub MaximumRedeemRequestsForFastMatch: u32 = 20;
 	pub RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 35;
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
-	pub SubAccountIndex: u16 = RelayChainSubAccountId::HomaLite as u16;
+	pub HomaLiteSubAccountIndexes: Vec<u16> = vec![RelayChainSubAccountId::HomaLite as u16];
 	// Calculated from polkadot/xcm/xcm-builder: fn buy_weight
 	// This is a place holder value since XCM is not tested for Mandala yet.
 	pub XcmUnbondFee: Balance = 60 * millicent(DOT);
+	pub MaxStakingLedgerSyncChange: Permill = Permill::from_percent(1);
+	pub const StakingLedgerSyncPeriod: BlockNumber = 3_600; // one era of Kusama in relaychain blocks
+	pub const HomaLiteXcmExecutionDelay: u32 = 10;
+	pub const HomaLiteMaxRelayChainStateRootAge: u32 = 50;
+	pub const HomaLiteMaxSlashCompensationSlippage: Permill = Permill::from_percent(5);
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1282,8 +1391,8 @@ impl module_homa_lite::Config for Runtime {
 	type MinimumMintThreshold = MinimumMintThreshold;
 	type MinimumRedeemThreshold = MinimumRedeemThreshold;
 	type XcmTransfer = XTokens;
-	type SovereignSubAccountLocation = RelayChainSovereignSubAccount;
-	type SubAccountIndex = SubAccountIndex;
+	type SovereignSubAccountLocation = SubAccountIndexMultiLocationConvertor;
+	type SubAccountIndexes = HomaLiteSubAccountIndexes;
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
@@ -1293,9 +1402,18 @@ impl module_homa_lite::Config for Runtime {
 	type RelayChainBlockNumber = RelayChainBlockNumberProvider<Runtime>;
 	type ParachainAccount = ParachainAccount;
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type MaximumRedeemRequestsForFastMatch = MaximumRedeemRequestsForFastMatch;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
 	type StakingUpdateFrequency = OneDay;
+	type SovereignSubAccountId = SubAccountIndexAccountIdConvertor;
+	type RelayChainStateRoot = RelayChainStateRootProvider<Runtime>;
+	type MaxRelayChainStateRootAge = HomaLiteMaxRelayChainStateRootAge;
+	type MaxStakingLedgerSyncChange = MaxStakingLedgerSyncChange;
+	type StakingLedgerSyncPeriod = StakingLedgerSyncPeriod;
+	type XcmExecutionDelay = HomaLiteXcmExecutionDelay;
+	type DEX = Dex;
+	type MaxSlashCompensationSlippage = HomaLiteMaxSlashCompensationSlippage;
 }
 
 parameter_types! {
@@ -1321,6 +1439,7 @@ impl module_nominees_election::Config for Runtime {
 parameter_types! {
 	pub MinGuaranteeAmount: Balance = dollar(LDOT);
 	pub const ValidatorInsuranceThreshold: Balance = 0;
+	pub StakingPoolAccount: AccountId = StakingPoolPalletId::get().into_account();
 }
 
 impl module_homa_validator_list::Config for Runtime {
@@ -1330,6 +1449,8 @@ impl module_homa_validator_list::Config for Runtime {
 	type MinBondAmount = MinGuaranteeAmount;
 	type BondingDuration = PolkadotBondingDuration;
 	type ValidatorInsuranceThreshold = ValidatorInsuranceThreshold;
+	// All insured validators are the candidates of the validator selection.
+	type MaxInsuredValidators = MaxValidatorCandidates;
 	type FreezeOrigin = EnsureRootOrHalfHomaCouncil;
 	type SlashOrigin = EnsureRootOrHalfHomaCouncil;
 	type OnSlash = module_staking_pool::OnSlash<Runtime>;
@@ -1338,6 +1459,36 @@ impl module_homa_validator_list::Config for Runtime {
 	type OnIncreaseGuarantee = ();
 	type OnDecreaseGuarantee = ();
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type InsuranceCompensationAccount = StakingPoolAccount;
+}
+
+parameter_types! {
+	pub ValidatorSelectionInsuranceWeight: Permill = Permill::one();
+	pub const ValidatorSelectionVoteWeight: Permill = Permill::from_percent(50);
+	pub const ValidatorSelectionSlashPenalty: Permill = Permill::from_percent(50);
+	pub const MaxNominations: u32 = 16;
+	pub const MaxValidatorCandidates: u32 = 100;
+	// Calculated from polkadot/xcm/xcm-builder: fn buy_weight, to buy `XcmNominateWeight` on Polkadot
+	pub XcmNominateFee: Balance = 20 * cent(DOT);
+	pub const XcmNominateWeight: Weight = 10_000_000_000;
+}
+
+impl module_homa_validator_selection::Config for Runtime {
+	type Event = Event;
+	type RelaychainAccountId = AccountId;
+	type ValidatorInsurance = HomaValidatorListModule;
+	type NomineeVotes = NomineesElection;
+	type InsuranceWeight = ValidatorSelectionInsuranceWeight;
+	type VoteWeight = ValidatorSelectionVoteWeight;
+	type SlashPenalty = ValidatorSelectionSlashPenalty;
+	type MaxNominations = MaxNominations;
+	type MaxValidatorCandidates = MaxValidatorCandidates;
+	type SubAccountIndexes = HomaLiteSubAccountIndexes;
+	type RelayChainCallBuilder = RelayChainCallBuilder<Runtime, ParachainInfo>;
+	type XcmNominateFee = XcmNominateFee;
+	type XcmNominateWeight = XcmNominateWeight;
+	type GovernanceOrigin = EnsureRootOrHalfHomaCouncil;
+	type WeightInfo = weights::module_homa_validator_selection::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -1931,6 +2082,8 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		HonzonTask(HonzonTask<Runtime>),
+		EmergencyShutdownTask(EmergencyShutdownTask<Runtime>),
 	}
 }
 
@@ -2086,7 +2239,7 @@ construct_runtime! {
 
 		// Acala Core
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 110,
-		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 111,
+		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>, ValidateUnsigned} = 111,
 
 		// Honzon
 		AuctionManager: module_auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 120,
@@ -2095,6 +2248,7 @@ construct_runtime! {
 		CdpTreasury: module_cdp_treasury::{Pallet, Storage, Call, Config, Event<T>} = 123,
 		CdpEngine: module_cdp_engine::{Pallet, Storage, Call, Event<T>, Config, ValidateUnsigned} = 124,
 		EmergencyShutdown: module_emergency_shutdown::{Pallet, Storage, Call, Event<T>} = 125,
+		StabilityPool: module_stability_pool::{Pallet, Storage, Call, Event<T>} = 126,
 
 		// Homa
 		Homa: module_homa::{Pallet, Call} = 130,
@@ -2103,6 +2257,7 @@ construct_runtime! {
 		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage} = 133,
 		HomaValidatorListModule: module_homa_validator_list::{Pallet, Call, Storage, Event<T>} = 134,
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 135,
+		HomaValidatorSelection: module_homa_validator_selection::{Pallet, Call, Storage, Event<T>} = 136,
 
 		// Acala Other
 		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 140,
@@ -2282,6 +2437,106 @@ impl_runtime_apis! {
 		fn get_liquid_staking_exchange_rate() -> ExchangeRate {
 			StakingPool::liquid_exchange_rate()
 		}
+
+		fn get_slash_compensation(era: EraIndex) -> module_staking_pool_rpc_runtime_api::SlashCompensationInfo<Balance> {
+			let compensation = StakingPool::slash_compensations(era);
+			module_staking_pool_rpc_runtime_api::SlashCompensationInfo {
+				confiscated_liquid: compensation.confiscated_liquid,
+				compensated_staking: compensation.compensated_staking,
+				burned_liquid: compensation.burned_liquid,
+			}
+		}
+	}
+
+	impl module_emergency_shutdown_rpc_runtime_api::EmergencyShutdownApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_settlement_report(
+			currency_id: CurrencyId,
+		) -> module_emergency_shutdown_rpc_runtime_api::SettlementReportInfo<Balance> {
+			EmergencyShutdown::get_settlement_report(currency_id)
+		}
+
+		fn get_settlement_reports() -> Vec<(CurrencyId, module_emergency_shutdown_rpc_runtime_api::SettlementReportInfo<Balance>)> {
+			EmergencyShutdown::get_settlement_reports()
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn get_redeem_liquidity() -> Vec<module_homa_lite_rpc_runtime_api::RedeemLiquidityInfo<Balance>> {
+			HomaLite::get_redeem_liquidity()
+				.into_iter()
+				.map(|(additional_fee, liquid_amount, num_requests)| {
+					module_homa_lite_rpc_runtime_api::RedeemLiquidityInfo {
+						additional_fee,
+						liquid_amount,
+						num_requests,
+					}
+				})
+				.collect()
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		Balance,
+		BlockNumber,
+	> for Runtime {
+		fn get_swap_target_amount(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::BalanceInfo<Balance>> {
+			Dex::swap_target_amount_info(&path, supply_amount)
+		}
+
+		fn get_swap_supply_amount(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::BalanceInfo<Balance>> {
+			Dex::swap_supply_amount_info(&path, target_amount)
+		}
+
+		fn get_best_swap_with_exact_supply(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPathInfo<Balance>> {
+			Dex::best_swap_with_exact_supply_info(supply_currency_id, target_currency_id, supply_amount)
+		}
+
+		fn get_best_swap_with_exact_target(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapPathInfo<Balance>> {
+			Dex::best_swap_with_exact_target_info(supply_currency_id, target_currency_id, target_amount)
+		}
+
+		fn get_liquidity_pool(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> module_dex_rpc_runtime_api::LiquidityPoolInfo<Balance> {
+			Dex::liquidity_pool_info(currency_id_a, currency_id_b)
+		}
+
+		fn get_provisioning_status(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> Option<module_dex_rpc_runtime_api::ProvisioningInfo<Balance, BlockNumber>> {
+			Dex::provisioning_info(currency_id_a, currency_id_b)
+		}
+
+		fn get_liquidity_share_value(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			share_amount: Balance,
+		) -> module_dex_rpc_runtime_api::LiquidityPoolInfo<Balance> {
+			Dex::liquidity_share_value_info(currency_id_a, currency_id_b, share_amount)
+		}
 	}
 
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
@@ -2426,7 +2681,9 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_nominees_election, benchmarking::nominees_election);
 			orml_list_benchmark!(list, extra, module_emergency_shutdown, benchmarking::emergency_shutdown);
 			orml_list_benchmark!(list, extra, module_evm, benchmarking::evm);
+			orml_list_benchmark!(list, extra, module_homa_validator_selection, benchmarking::homa_validator_selection);
 			orml_list_benchmark!(list, extra, module_honzon, benchmarking::honzon);
+			orml_list_benchmark!(list, extra, module_stability_pool, benchmarking::stability_pool);
 			orml_list_benchmark!(list, extra, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
@@ -2489,7 +2746,9 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_nominees_election, benchmarking::nominees_election);
 			orml_add_benchmark!(params, batches, module_emergency_shutdown, benchmarking::emergency_shutdown);
 			orml_add_benchmark!(params, batches, module_evm, benchmarking::evm);
+			orml_add_benchmark!(params, batches, module_homa_validator_selection, benchmarking::homa_validator_selection);
 			orml_add_benchmark!(params, batches, module_honzon, benchmarking::honzon);
+			orml_add_benchmark!(params, batches, module_stability_pool, benchmarking::stability_pool);
 			orml_add_benchmark!(params, batches, module_cdp_treasury, benchmarking::cdp_treasury);
 			orml_add_benchmark!(params, batches, module_transaction_pause, benchmarking::transaction_pause);
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
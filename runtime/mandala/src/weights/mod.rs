@@ -31,12 +31,14 @@ pub mod module_evm;
 pub mod module_evm_accounts;
 pub mod module_homa;
 pub mod module_homa_lite;
+pub mod module_homa_validator_selection;
 pub mod module_honzon;
 pub mod module_incentives;
 pub mod module_nft;
 pub mod module_nominees_election;
 pub mod module_prices;
 pub mod module_session_manager;
+pub mod module_stability_pool;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
 
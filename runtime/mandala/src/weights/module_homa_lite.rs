@@ -94,10 +94,14 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
-	fn schedule_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn schedule_unbond(s: u32, ) -> Weight {
 		(22_654_000 as Weight)
+			.saturating_add((3_512_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
 	fn replace_schedule_unbond() -> Weight {
 		(20_146_000 as Weight)
@@ -112,9 +116,33 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn xcm_unbond() -> Weight {
+	// Estimated, to be replaced by the benchmarked weight.
+	fn xcm_unbond(s: u32, ) -> Weight {
 		(42_612_000 as Weight)
+			.saturating_add((6_268_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn sync_staking_ledger(s: u32, d: u32) -> Weight {
+		(62_413_000 as Weight)
+			.saturating_add((4_126_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((1_842_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Estimated, to be replaced by the benchmarked weight.
+	fn fast_match_redeems(n: u32, ) -> Weight {
+		(132_000_000 as Weight)
+			.saturating_add((47_728_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
 	}
 }